@@ -1,3 +1,4 @@
+use shikicrate::date::Locale;
 use shikicrate::{ShikicrateClient, queries::*};
 
 #[tokio::main]
@@ -11,12 +12,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Поиск: 'bakemono', лимит: 3, исключить спешлы\n");
 
     let animes = client
-        .animes(AnimeSearchParams {
-            search: Some("bakemono".to_string()),
-            limit: Some(3),
-            kind: Some("!special".to_string()),
-            ..Default::default()
-        })
+        .animes(
+            AnimeSearchParams::builder()
+                .search("bakemono")
+                .limit(3)
+                .exclude_kind(AnimeKind::Special),
+        )
         .await?;
 
     println!("Найдено аниме: {}\n", animes.len());
@@ -40,10 +41,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Лимит: 5\n");
 
     let mangas = client
-        .mangas(MangaSearchParams {
-            limit: Some(5),
-            ..Default::default()
-        })
+        .mangas(MangaSearchParams::builder().limit(5))
         .await?;
 
     println!("Найдено манги: {}\n", mangas.len());
@@ -64,11 +62,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Страница: 1, лимит: 5\n");
 
     let characters = client
-        .characters(CharacterSearchParams {
-            page: Some(1),
-            limit: Some(5),
-            ..Default::default()
-        })
+        .characters(CharacterSearchParams::builder().page(1).limit(5))
         .await?;
 
     println!("Найдено персонажей: {}\n", characters.len());
@@ -86,10 +80,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("ID: [1, 2, 3]\n");
 
     let characters_by_ids = client
-        .characters(CharacterSearchParams {
-            ids: Some(vec!["1".to_string(), "2".to_string(), "3".to_string()]),
-            ..Default::default()
-        })
+        .characters(CharacterSearchParams::builder().ids([1, 2, 3]))
         .await?;
 
     println!("Найдено персонажей: {}\n", characters_by_ids.len());
@@ -104,10 +95,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Лимит: 3\n");
 
     let people = client
-        .people(PeopleSearchParams {
-            limit: Some(3),
-            ..Default::default()
-        })
+        .people(PeopleSearchParams::builder().limit(3))
         .await?;
 
     println!("Найдено людей: {}\n", people.len());
@@ -155,10 +143,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!();
         }
 
-        if let Some(aired_on) = &first_anime.aired_on
-            && let Some(date) = &aired_on.date
-        {
-            println!("  Дата выхода: {}", date);
+        if let Some(aired_on) = first_anime.aired_on.filter(|date| !date.is_empty()) {
+            println!("  Дата выхода: {}", aired_on.format(Locale::Ru));
         }
 
         if let Some(description) = &first_anime.description {
@@ -0,0 +1,527 @@
+//! OAuth2 авторизация в Shikimori.
+//!
+//! Модуль покрывает authorization code flow: генерацию ссылки на авторизацию,
+//! обмен кода на токен, обновление токена и хранение токенов через [`TokenStore`]
+//! ([`MemoryTokenStore`] или [`FileTokenStore`] с шифрованием).
+//!
+//! Для CLI-приложений есть [`OAuth::authorize_oob`], который использует
+//! out-of-band redirect (`urn:ietf:wg:oauth:2.0:oob`) — пользователь
+//! копирует код из браузера в терминал, локальный сервер для redirect не нужен.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const OAUTH_BASE_URL: &str = "https://shikimori.io/oauth";
+
+/// Redirect URI для out-of-band авторизации (код показывается пользователю в браузере).
+pub const OOB_REDIRECT_URI: &str = "urn:ietf:wg:oauth:2.0:oob";
+
+/// Настройки OAuth2 приложения, зарегистрированного на Shikimori.
+#[derive(Debug, Clone)]
+pub struct OAuthConfig {
+    /// Client ID приложения.
+    pub client_id: String,
+
+    /// Client secret приложения.
+    pub client_secret: String,
+
+    /// Redirect URI, указанный при регистрации приложения.
+    pub redirect_uri: String,
+
+    /// Запрашиваемые права доступа (например, `"user_rates"`).
+    pub scopes: Vec<String>,
+}
+
+impl OAuthConfig {
+    /// Создает конфигурацию для out-of-band авторизации (CLI-приложения).
+    pub fn oob(client_id: String, client_secret: String) -> Self {
+        Self {
+            client_id,
+            client_secret,
+            redirect_uri: OOB_REDIRECT_URI.to_string(),
+            scopes: Vec::new(),
+        }
+    }
+}
+
+/// OAuth2 токен Shikimori.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthToken {
+    /// Access token для заголовка `Authorization: Bearer`.
+    pub access_token: String,
+
+    /// Refresh token для получения нового access token.
+    pub refresh_token: Option<String>,
+
+    /// Тип токена (обычно `"Bearer"`).
+    pub token_type: Option<String>,
+
+    /// Время жизни access token в секундах.
+    pub expires_in: Option<u64>,
+
+    /// Время выдачи токена (Unix timestamp).
+    pub created_at: Option<u64>,
+
+    /// Выданные права доступа.
+    pub scope: Option<String>,
+}
+
+impl OAuthToken {
+    /// Проверяет, истек ли access token.
+    ///
+    /// Если сервер не вернул `created_at` или `expires_in`, токен считается действующим.
+    pub fn is_expired(&self) -> bool {
+        match (self.created_at, self.expires_in) {
+            (Some(created_at), Some(expires_in)) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                now >= created_at + expires_in
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Хранилище OAuth2 токенов.
+///
+/// Позволяет сохранять токены между запусками приложения.
+pub trait TokenStore: Send + Sync {
+    /// Загружает сохраненный токен (если есть).
+    fn load(&self) -> Result<Option<OAuthToken>>;
+
+    /// Сохраняет токен.
+    fn save(&self, token: &OAuthToken) -> Result<()>;
+
+    /// Удаляет сохраненный токен.
+    fn clear(&self) -> Result<()>;
+}
+
+/// Хранилище токенов в памяти (токен теряется после завершения процесса).
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<OAuthToken>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<OAuthToken>> {
+        Ok(self.token.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
+    fn save(&self, token: &OAuthToken) -> Result<()> {
+        *self.token.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+        *self.token.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        Ok(())
+    }
+}
+
+/// Шифрование токенов перед записью на диск.
+///
+/// Реализуется приложением (например, поверх ключа из системного keychain),
+/// чтобы refresh token не хранился в открытом виде.
+pub trait TokenCipher: Send + Sync {
+    /// Шифрует сериализованный токен.
+    fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>>;
+
+    /// Расшифровывает данные, записанные `encrypt`.
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Хранилище токенов в файле.
+///
+/// Содержимое файла шифруется переданным [`TokenCipher`], чтобы refresh token
+/// не лежал на диске в открытом виде. Запись без шифрования нужно выбрать явно
+/// через [`FileTokenStore::plaintext`]. На Unix файл создается с правами `0600`.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::auth::{FileTokenStore, TokenCipher, TokenStore};
+///
+/// // Шифрование поверх ключа приложения (например, из системного keychain)
+/// struct KeychainCipher;
+///
+/// impl TokenCipher for KeychainCipher {
+///     fn encrypt(&self, plain: &[u8]) -> shikicrate::Result<Vec<u8>> {
+///         # Ok(plain.to_vec())
+///         // ...
+///     }
+///
+///     fn decrypt(&self, data: &[u8]) -> shikicrate::Result<Vec<u8>> {
+///         # Ok(data.to_vec())
+///         // ...
+///     }
+/// }
+///
+/// # fn example() -> shikicrate::Result<()> {
+/// let store = FileTokenStore::new("shikimori_token.bin", KeychainCipher);
+/// if let Some(token) = store.load()? {
+///     println!("Сохраненный токен истек: {}", token.is_expired());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileTokenStore {
+    path: PathBuf,
+    cipher: Option<Box<dyn TokenCipher>>,
+}
+
+impl FileTokenStore {
+    /// Хранилище, которое шифрует токен через `cipher`.
+    pub fn new(path: impl Into<PathBuf>, cipher: impl TokenCipher + 'static) -> Self {
+        Self {
+            path: path.into(),
+            cipher: Some(Box::new(cipher)),
+        }
+    }
+
+    /// Хранилище, которое пишет токен как открытый JSON.
+    ///
+    /// Подходит для тестов и окружений, где файл уже защищен другими средствами.
+    pub fn plaintext(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cipher: None,
+        }
+    }
+
+    fn write_private(&self, data: &[u8]) -> Result<()> {
+        let tmp_path = self.path.with_extension("tmp");
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&tmp_path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<OAuthToken>> {
+        let data = match std::fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let plain = match &self.cipher {
+            Some(cipher) => cipher.decrypt(&data)?,
+            None => data,
+        };
+
+        serde_json::from_slice(&plain).map(Some).map_err(ShikicrateError::Serialization)
+    }
+
+    fn save(&self, token: &OAuthToken) -> Result<()> {
+        let plain = serde_json::to_vec(token)?;
+        let data = match &self.cipher {
+            Some(cipher) => cipher.encrypt(&plain)?,
+            None => plain,
+        };
+        self.write_private(&data)
+    }
+
+    fn clear(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Клиент OAuth2 авторизации Shikimori.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::auth::{MemoryTokenStore, OAuth, OAuthConfig};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let oauth = OAuth::new(OAuthConfig::oob("client_id".to_string(), "secret".to_string()))?;
+/// let store = MemoryTokenStore::new();
+///
+/// let token = oauth.authorize_cli(&store).await?;
+/// println!("Токен получен: {}", token.access_token);
+/// # Ok(())
+/// # }
+/// ```
+pub struct OAuth {
+    client: Client,
+    config: OAuthConfig,
+}
+
+impl OAuth {
+    pub fn new(config: OAuthConfig) -> Result<Self> {
+        let client = Client::builder()
+            .user_agent(concat!("shikicrate/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(ShikicrateError::Http)?;
+
+        Ok(Self { client, config })
+    }
+
+    /// Возвращает ссылку на страницу авторизации приложения.
+    pub fn authorize_url(&self) -> String {
+        let mut url = url::Url::parse(&format!("{}/authorize", OAUTH_BASE_URL)).expect("valid OAuth URL");
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("scope", &self.config.scopes.join(" "));
+        url.to_string()
+    }
+
+    /// Обменивает код авторизации на токен.
+    pub async fn exchange_code(&self, code: &str) -> Result<OAuthToken> {
+        let code = code.trim();
+        if code.is_empty() {
+            return Err(ValidationError::new("code", Constraint::NotEmpty, "Код авторизации не должен быть пустым").into());
+        }
+
+        self.request_token(&[
+            ("grant_type", "authorization_code"),
+            ("client_id", &self.config.client_id),
+            ("client_secret", &self.config.client_secret),
+            ("code", code),
+            ("redirect_uri", &self.config.redirect_uri),
+        ])
+        .await
+    }
+
+    /// Получает новый access token по refresh token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<OAuthToken> {
+        self.request_token(&[
+            ("grant_type", "refresh_token"),
+            ("client_id", &self.config.client_id),
+            ("client_secret", &self.config.client_secret),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    /// Out-of-band авторизация для CLI: выводит ссылку, читает вставленный код,
+    /// обменивает его на токен и сохраняет токен в `store`.
+    ///
+    /// Ввод и вывод передаются явно, что позволяет использовать метод в тестах
+    /// и нестандартных терминалах. Для stdin/stdout используйте [`OAuth::authorize_cli`].
+    /// Чтение кода блокирующее, поэтому выполняется в `spawn_blocking` и не занимает
+    /// поток рантайма.
+    pub async fn authorize_oob<R, W>(&self, store: &dyn TokenStore, input: R, output: W) -> Result<OAuthToken>
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        if self.config.redirect_uri != OOB_REDIRECT_URI {
+            return Err(ValidationError::new(
+                "redirect_uri",
+                Constraint::OneOf(vec![OOB_REDIRECT_URI.to_string()]),
+                format!("Для out-of-band авторизации redirect_uri должен быть {}", OOB_REDIRECT_URI),
+            )
+            .value(&self.config.redirect_uri)
+            .into());
+        }
+
+        let url = self.authorize_url();
+        let code = tokio::task::spawn_blocking(move || Self::prompt_code(&url, input, output))
+            .await
+            .map_err(|e| ShikicrateError::Auth(format!("Ошибка чтения кода: {}", e)))??;
+
+        let token = self.exchange_code(&code).await?;
+        store.save(&token)?;
+        Ok(token)
+    }
+
+    fn prompt_code(url: &str, mut input: impl BufRead, mut output: impl Write) -> Result<String> {
+        writeln!(output, "Откройте ссылку в браузере и разрешите доступ:\n{}", url)
+            .and_then(|_| write!(output, "Вставьте код авторизации: "))
+            .and_then(|_| output.flush())
+            .map_err(|e| ShikicrateError::Auth(format!("Ошибка вывода: {}", e)))?;
+
+        let mut code = String::new();
+        input
+            .read_line(&mut code)
+            .map_err(|e| ShikicrateError::Auth(format!("Ошибка чтения кода: {}", e)))?;
+        Ok(code)
+    }
+
+    /// Out-of-band авторизация через stdin/stdout.
+    ///
+    /// Если в `store` уже есть действующий токен, он возвращается без запроса кода.
+    /// Истекший токен обновляется через refresh token.
+    pub async fn authorize_cli(&self, store: &dyn TokenStore) -> Result<OAuthToken> {
+        if let Some(token) = store.load()? {
+            if !token.is_expired() {
+                return Ok(token);
+            }
+            if let Some(refresh_token) = &token.refresh_token {
+                let token = self.refresh(refresh_token).await?;
+                store.save(&token)?;
+                return Ok(token);
+            }
+        }
+
+        let stdin = std::io::BufReader::new(std::io::stdin());
+        self.authorize_oob(store, stdin, std::io::stdout()).await
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<OAuthToken> {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+
+        let response = self
+            .client
+            .post(format!("{}/token", OAUTH_BASE_URL))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+
+        let response = match ShikicrateClient::ensure_success(response, None, "OAuth HTTP").await {
+            Ok(response) => response,
+            Err(ShikicrateError::Api { message, .. }) => return Err(ShikicrateError::Auth(message)),
+            Err(e) => return Err(e),
+        };
+
+        let text = response.text().await?;
+        serde_json::from_str(&text).map_err(ShikicrateError::Serialization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oauth() -> OAuth {
+        OAuth::new(OAuthConfig::oob("id".to_string(), "secret".to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_authorize_url_oob() {
+        let url = oauth().authorize_url();
+        assert!(url.starts_with("https://shikimori.io/oauth/authorize?"));
+        assert!(url.contains("client_id=id"));
+        assert!(url.contains("redirect_uri=urn%3Aietf%3Awg%3Aoauth%3A2.0%3Aoob"));
+        assert!(url.contains("response_type=code"));
+    }
+
+    #[test]
+    fn test_token_expiration() {
+        let mut token = OAuthToken {
+            access_token: "a".to_string(),
+            refresh_token: None,
+            token_type: None,
+            expires_in: None,
+            created_at: None,
+            scope: None,
+        };
+        assert!(!token.is_expired());
+
+        token.created_at = Some(0);
+        token.expires_in = Some(60);
+        assert!(token.is_expired());
+    }
+
+    struct XorCipher(u8);
+
+    impl TokenCipher for XorCipher {
+        fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>> {
+            Ok(plain.iter().map(|b| b ^ self.0).collect())
+        }
+
+        fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+            self.encrypt(data)
+        }
+    }
+
+    #[test]
+    fn test_file_token_store_roundtrip_encrypted() {
+        let path = std::env::temp_dir().join(format!("shikicrate_token_{}.bin", std::process::id()));
+        let store = FileTokenStore::new(&path, XorCipher(0x5a));
+        assert!(store.load().unwrap().is_none());
+
+        let token = OAuthToken {
+            access_token: "access".to_string(),
+            refresh_token: Some("refresh".to_string()),
+            token_type: Some("Bearer".to_string()),
+            expires_in: Some(86400),
+            created_at: Some(1),
+            scope: None,
+        };
+        store.save(&token).unwrap();
+
+        let raw = std::fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("refresh"));
+
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.refresh_token.as_deref(), Some("refresh"));
+
+        let plain = FileTokenStore::plaintext(&path);
+        plain.save(&token).unwrap();
+        assert!(String::from_utf8_lossy(&std::fs::read(&path).unwrap()).contains("refresh"));
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_authorize_oob_empty_code() {
+        let store = MemoryTokenStore::new();
+        let output = SharedOutput::default();
+        let result = oauth().authorize_oob(&store, "\n".as_bytes(), output.clone()).await;
+
+        assert!(matches!(result, Err(ShikicrateError::Validation(_))));
+        let printed = output.0.lock().unwrap().clone();
+        assert!(String::from_utf8(printed).unwrap().contains("oauth/authorize"));
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_authorize_futures_are_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let oauth = oauth();
+        let store = MemoryTokenStore::new();
+        assert_send(oauth.authorize_cli(&store));
+        assert_send(oauth.authorize_oob(&store, std::io::empty(), std::io::sink()));
+    }
+
+    /// Вывод, который остается доступен тесту после передачи в `spawn_blocking`.
+    #[derive(Clone, Default)]
+    struct SharedOutput(std::sync::Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+}
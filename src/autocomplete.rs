@@ -0,0 +1,138 @@
+//! Быстрый поиск для автодополнения.
+//!
+//! Один GraphQL запрос ищет аниме, мангу, персонажей и людей одновременно и
+//! запрашивает только ID, названия и URL, поэтому подходит для поиска по мере
+//! ввода, где полный `animes()` избыточен.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use crate::queries::AUTOCOMPLETE_QUERY;
+use crate::types::EntryRef;
+use serde_json::json;
+
+/// Количество подсказок каждого типа.
+const AUTOCOMPLETE_LIMIT: i32 = 5;
+
+/// Подсказка автодополнения.
+#[derive(Debug, Clone)]
+pub enum Suggestion {
+    Anime(EntryRef),
+    Manga(EntryRef),
+    Character(EntryRef),
+    Person(EntryRef),
+}
+
+impl Suggestion {
+    /// Краткая информация о найденной записи.
+    pub fn entry(&self) -> &EntryRef {
+        match self {
+            Suggestion::Anime(entry)
+            | Suggestion::Manga(entry)
+            | Suggestion::Character(entry)
+            | Suggestion::Person(entry) => entry,
+        }
+    }
+
+    /// ID найденной записи.
+    pub fn id(&self) -> i64 {
+        self.entry().id
+    }
+
+    /// Название для отображения: русское, если есть, иначе основное.
+    pub fn title(&self) -> &str {
+        let entry = self.entry();
+        entry.russian.as_deref().filter(|r| !r.is_empty()).unwrap_or(&entry.name)
+    }
+}
+
+impl ShikicrateClient {
+    /// Поиск подсказок по строке: до 5 аниме, манги, персонажей и людей за один запрос.
+    ///
+    /// Подсказки возвращаются сгруппированными по типу в порядке: аниме, манга,
+    /// персонажи, люди.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use shikicrate::autocomplete::Suggestion;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    ///
+    /// for suggestion in client.autocomplete("bebop").await? {
+    ///     let kind = match suggestion {
+    ///         Suggestion::Anime(_) => "аниме",
+    ///         Suggestion::Manga(_) => "манга",
+    ///         Suggestion::Character(_) => "персонаж",
+    ///         Suggestion::Person(_) => "человек",
+    ///     };
+    ///     println!("[{}] {}", kind, suggestion.title());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn autocomplete(&self, query: &str) -> Result<Vec<Suggestion>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(ValidationError::new("search", Constraint::NotEmpty, "Строка поиска не должна быть пустой").into());
+        }
+
+        let response: serde_json::Value = self
+            .execute_query(
+                AUTOCOMPLETE_QUERY,
+                Some(json!({ "search": query, "limit": AUTOCOMPLETE_LIMIT })),
+            )
+            .await?;
+
+        parse_suggestions(response)
+    }
+}
+
+fn parse_suggestions(mut response: serde_json::Value) -> Result<Vec<Suggestion>> {
+    let groups = [
+        ("animes", Suggestion::Anime as fn(EntryRef) -> Suggestion),
+        ("mangas", Suggestion::Manga),
+        ("characters", Suggestion::Character),
+        ("people", Suggestion::Person),
+    ];
+
+    let mut suggestions = Vec::new();
+    for (key, wrap) in groups {
+        let items = match response.get_mut(key).map(serde_json::Value::take) {
+            Some(items) if !items.is_null() => items,
+            _ => continue,
+        };
+        let entries: Vec<EntryRef> = serde_json::from_value(items).map_err(ShikicrateError::Serialization)?;
+        suggestions.extend(entries.into_iter().map(wrap));
+    }
+    Ok(suggestions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_suggestions() {
+        let suggestions = parse_suggestions(json!({
+            "animes": [{ "id": "1", "name": "Cowboy Bebop", "russian": "Ковбой Бибоп", "kind": "tv" }],
+            "mangas": [],
+            "characters": [{ "id": "1", "name": "Spike Spiegel", "russian": "" }],
+            "people": null
+        }))
+        .unwrap();
+
+        assert_eq!(suggestions.len(), 2);
+        assert!(matches!(suggestions[0], Suggestion::Anime(_)));
+        assert_eq!(suggestions[0].title(), "Ковбой Бибоп");
+        assert!(matches!(suggestions[1], Suggestion::Character(_)));
+        assert_eq!(suggestions[1].title(), "Spike Spiegel");
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_empty_query() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(client.autocomplete("  ").await, Err(ShikicrateError::Validation(_))));
+    }
+}
@@ -0,0 +1,133 @@
+//! Публичный журнал банов модерации.
+//!
+//! Данные загружаются через REST API Shikimori (`/api/bans`).
+
+use crate::client::ShikicrateClient;
+use crate::comments::Comment;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::{User, deser_id, deser_opt_id};
+use serde::{Deserialize, Serialize};
+
+/// Максимальный `limit` для `/api/bans`.
+const BANS_MAX_LIMIT: i32 = 30;
+
+/// Запись журнала банов.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ban {
+    /// ID записи.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// ID забаненного пользователя.
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub user_id: Option<i64>,
+
+    /// ID модератора.
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub moderator_id: Option<i64>,
+
+    /// Комментарий, за который выдан бан (если есть).
+    pub comment: Option<Comment>,
+
+    /// Причина бана.
+    pub reason: Option<String>,
+
+    /// Длительность бана в минутах (0 для предупреждения).
+    pub duration_minutes: Option<i64>,
+
+    /// Дата выдачи бана.
+    pub created_at: Option<String>,
+
+    /// Забаненный пользователь.
+    pub user: Option<User>,
+
+    /// Модератор.
+    pub moderator: Option<User>,
+}
+
+crate::types::identity_by_id!(Ban);
+crate::types::utc_accessors!(Ban { created_at_utc => created_at });
+
+impl Ban {
+    /// Запись является предупреждением, а не баном.
+    pub fn is_warning(&self) -> bool {
+        self.duration_minutes == Some(0)
+    }
+}
+
+impl ShikicrateClient {
+    /// Получение журнала банов модерации (новые записи первыми).
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    ///
+    /// for ban in client.bans(Some(1), Some(30)).await? {
+    ///     println!("{:?}: {}", ban.reason, ban.duration_minutes.unwrap_or(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bans(&self, page: Option<i32>, limit: Option<i32>) -> Result<Vec<Ban>> {
+        if page.is_some_and(|page| page < 1) {
+            return Err(ValidationError::new("page", Constraint::Min(1), "Страница должна быть не меньше 1").into());
+        }
+        if limit.is_some_and(|limit| !(1..=BANS_MAX_LIMIT).contains(&limit)) {
+            return Err(ValidationError::new(
+                "limit",
+                Constraint::Range { min: 1, max: BANS_MAX_LIMIT as i64 },
+                format!("Лимит банов должен быть от 1 до {}", BANS_MAX_LIMIT),
+            )
+            .value(limit.unwrap_or_default())
+            .into());
+        }
+
+        let mut query = Vec::new();
+        if let Some(page) = page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        self.get_rest("bans", Some(query)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use serde_json::json;
+
+    #[test]
+    fn test_ban_deserialize() {
+        let ban: Ban = serde_json::from_value(json!({
+            "id": 1,
+            "user_id": 10,
+            "moderator_id": 2,
+            "comment": { "id": 100, "body": "[b]спам[/b]", "commentable_id": 5, "commentable_type": "Topic" },
+            "reason": "спам",
+            "created_at": "2024-01-01T00:00:00.000+03:00",
+            "duration_minutes": 0,
+            "user": { "id": 10, "nickname": "spammer" },
+            "moderator": { "id": 2, "nickname": "moder" }
+        }))
+        .unwrap();
+
+        assert!(ban.is_warning());
+        assert_eq!(ban.comment.unwrap().commentable_id, Some(5));
+        assert_eq!(ban.moderator.unwrap().nickname, "moder");
+    }
+
+    #[tokio::test]
+    async fn test_bans_validation() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(client.bans(Some(0), None).await, Err(ShikicrateError::Validation(_))));
+        assert!(matches!(client.bans(None, Some(31)).await, Err(ShikicrateError::Validation(_))));
+    }
+}
@@ -0,0 +1,276 @@
+//! Объединение нескольких GraphQL запросов в один HTTP запрос.
+//!
+//! [`BatchRequest`] собирает запросы со списочным корневым полем и объединяет
+//! их через алиасы: корневое поле каждого запроса получает алиас `b0`, `b1`, ...,
+//! а его переменные — такой же префикс. Сервер выполняет все части за один
+//! HTTP запрос, что сильно сокращает число обращений при «гидрации» данных.
+//!
+//! Поддерживаются только запросы (`query`) с одним корневым полем и без фрагментов.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClient, queries::*};
+//! use shikicrate::batch::BatchRequest;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ShikicrateClient::new()?;
+//!
+//! let mut batch = BatchRequest::new();
+//! let animes = batch.animes(AnimeSearchParams::builder().ids([1, 5081]))?;
+//! let mangas = batch.mangas(MangaSearchParams::builder().search("berserk").limit(5))?;
+//!
+//! let response = client.batch(&batch).await?;
+//! println!("Аниме: {}", response.get(&animes)?.len());
+//! println!("Манга: {}", response.get(&mangas)?.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use crate::queries::{AnimeSearchParams, MangaSearchParams};
+use crate::types::{Anime, Manga};
+use crate::warnings::Warning;
+use std::marker::PhantomData;
+
+struct Part {
+    query: String,
+    variables: serde_json::Value,
+    response_key: String,
+}
+
+/// Набор запросов для выполнения одним HTTP запросом.
+#[derive(Default)]
+pub struct BatchRequest {
+    parts: Vec<Part>,
+}
+
+/// Ключ для получения результата части пакета из [`BatchResponse`].
+pub struct BatchKey<T> {
+    alias: String,
+    response_key: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> BatchKey<T> {
+    /// Корневое поле запроса, к которому относится ключ.
+    pub fn response_key(&self) -> &str {
+        &self.response_key
+    }
+}
+
+impl BatchRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Количество запросов в пакете.
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Добавляет произвольный запрос, корневое поле `response_key` которого возвращает список
+    /// (аналог `ShikicrateClient::fetch_list`).
+    pub fn list<T>(&mut self, query: &str, variables: serde_json::Value, response_key: &str) -> Result<BatchKey<T>> {
+        ShikicrateClient::val_vars(&variables)?;
+        split_operation(query, response_key)?;
+
+        let alias = format!("b{}", self.parts.len());
+        self.parts.push(Part {
+            query: query.to_string(),
+            variables,
+            response_key: response_key.to_string(),
+        });
+        Ok(BatchKey {
+            alias,
+            response_key: response_key.to_string(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Добавляет поиск аниме (параметры как у `ShikicrateClient::animes`).
+    pub fn animes(&mut self, params: AnimeSearchParams) -> Result<BatchKey<Anime>> {
+        let (query, variables) = ShikicrateClient::anime_request(&params)?;
+        self.list(query, variables, "animes")
+    }
+
+    /// Добавляет поиск манги (параметры как у `ShikicrateClient::mangas`).
+    pub fn mangas(&mut self, params: MangaSearchParams) -> Result<BatchKey<Manga>> {
+        let (query, variables) = ShikicrateClient::manga_request(&params)?;
+        self.list(query, variables, "mangas")
+    }
+
+    /// Собирает объединенный запрос и его переменные.
+    fn build(&self, client: &ShikicrateClient) -> Result<(String, serde_json::Value)> {
+        let mut declarations = Vec::new();
+        let mut selections = Vec::new();
+        let mut variables = serde_json::Map::new();
+
+        for (index, part) in self.parts.iter().enumerate() {
+            let alias = format!("b{}", index);
+            let prefix = format!("{}_", alias);
+            let (decls, body) = split_operation(&part.query, &part.response_key)?;
+
+            if !decls.trim().is_empty() {
+                declarations.push(prefix_variables(decls.trim(), &prefix));
+            }
+            selections.push(format!("{}: {}", alias, prefix_variables(body, &prefix)));
+
+            let mut part_vars = part.variables.clone();
+            client.clamp_limit(&mut part_vars);
+            if let serde_json::Value::Object(map) = part_vars {
+                for (name, value) in map {
+                    variables.insert(format!("{}{}", prefix, name), value);
+                }
+            }
+        }
+
+        let header = if declarations.is_empty() {
+            "query Batch".to_string()
+        } else {
+            format!("query Batch({})", declarations.join(", "))
+        };
+        Ok((format!("{} {{\n{}\n}}", header, selections.join("\n")), variables.into()))
+    }
+}
+
+/// Результат выполнения [`BatchRequest`].
+#[derive(Debug, Clone)]
+pub struct BatchResponse {
+    data: serde_json::Value,
+}
+
+impl BatchResponse {
+    /// Результат части пакета. Если поле отсутствует в ответе, возвращается пустой список.
+    pub fn get<T>(&self, key: &BatchKey<T>) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        match self.data.get(&key.alias) {
+            Some(value) if !value.is_null() => Ok(serde_json::from_value(value.clone())?),
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Разбирает запрос на объявления переменных и корневое поле `response_key` с выборкой.
+fn split_operation<'a>(query: &'a str, response_key: &str) -> Result<(&'a str, &'a str)> {
+    let invalid = |reason: &str| {
+        ShikicrateError::from(ValidationError::new(
+            "query",
+            Constraint::Format,
+            format!("Запрос нельзя добавить в пакет: {}", reason),
+        ))
+    };
+
+    let (Some(open), Some(close)) = (query.find('{'), query.rfind('}')) else {
+        return Err(invalid("нет блока выборки"));
+    };
+    let header = query[..open].trim();
+    if !(header.is_empty() || header.starts_with("query")) {
+        return Err(invalid("поддерживаются только query операции"));
+    }
+    if query.contains("fragment ") || query.contains("...") {
+        return Err(invalid("фрагменты не поддерживаются"));
+    }
+
+    let decls = match (header.find('('), header.rfind(')')) {
+        (Some(start), Some(end)) if start < end => &header[start + 1..end],
+        _ => "",
+    };
+
+    let body = query[open + 1..close].trim();
+    let starts_with_key = body
+        .strip_prefix(response_key)
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| !(c.is_alphanumeric() || c == '_'));
+    if !starts_with_key {
+        return Err(invalid(&format!("корневое поле должно быть {}", response_key)));
+    }
+    Ok((decls, body))
+}
+
+/// Добавляет префикс ко всем переменным (`$name` -> `$b0_name`).
+fn prefix_variables(text: &str, prefix: &str) -> String {
+    text.replace('$', &format!("${}", prefix))
+}
+
+impl ShikicrateClient {
+    /// Выполнение пакета запросов одним HTTP запросом (см. [`BatchRequest`]).
+    pub async fn batch(&self, batch: &BatchRequest) -> Result<BatchResponse> {
+        if batch.is_empty() {
+            return Err(ValidationError::new("batch", Constraint::NotEmpty, "Пакет запросов пуст").into());
+        }
+
+        let (query, variables) = batch.build(self)?;
+        let data: serde_json::Value = self.execute_query(&query, Some(variables)).await?;
+
+        for (index, part) in batch.parts.iter().enumerate() {
+            if data.get(format!("b{}", index)).is_none() {
+                self.warn(Warning::MissingResponseField {
+                    field: part.response_key.clone(),
+                });
+            }
+        }
+        Ok(BatchResponse { data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_batch() {
+        let mut batch = BatchRequest::new();
+        batch
+            .animes(AnimeSearchParams {
+                ids: Some(vec![1]),
+                ..Default::default()
+            })
+            .unwrap();
+        batch
+            .list::<serde_json::Value>(
+                "query($limit: Int) { clubs(limit: $limit) { id } }",
+                json!({ "limit": 100 }),
+                "clubs",
+            )
+            .unwrap();
+
+        let client = ShikicrateClient::new().unwrap();
+        let (query, variables) = batch.build(&client).unwrap();
+
+        assert!(query.starts_with("query Batch($b0_search: String"));
+        assert!(query.contains(", $b1_limit: Int)"));
+        assert!(query.contains("b0: animes(search: $b0_search, ids: $b0_ids"));
+        assert!(query.contains("b1: clubs(limit: $b1_limit) { id }"));
+        assert_eq!(variables, json!({ "b0_ids": "1", "b1_limit": 50 }));
+    }
+
+    #[test]
+    fn test_split_operation_rejects() {
+        assert!(split_operation("mutation { animes { id } }", "animes").is_err());
+        assert!(split_operation("{ animesLite { id } }", "animes").is_err());
+        assert!(split_operation("{ animes { ...Fields } }", "animes").is_err());
+        assert!(split_operation("{ animes { id } }", "animes").is_ok());
+    }
+
+    #[test]
+    fn test_batch_response_get() {
+        let mut batch = BatchRequest::new();
+        let clubs = batch.list::<serde_json::Value>("{ clubs { id } }", json!({}), "clubs").unwrap();
+        let users = batch.list::<serde_json::Value>("{ users { id } }", json!({}), "users").unwrap();
+
+        let response = BatchResponse {
+            data: json!({ "b0": [{ "id": "1" }] }),
+        };
+        assert_eq!(response.get(&clubs).unwrap().len(), 1);
+        assert!(response.get(&users).unwrap().is_empty());
+    }
+}
@@ -5,11 +5,12 @@ fn main() {
     let cfg = ts_rs::Config::new().with_large_int("bigint");
 
     let types: Vec<String> = vec![
-        Date::export_to_string(&cfg).unwrap(),
+        PartialDate::export_to_string(&cfg).unwrap(),
         Poster::export_to_string(&cfg).unwrap(),
         Genre::export_to_string(&cfg).unwrap(),
         Studio::export_to_string(&cfg).unwrap(),
         Publisher::export_to_string(&cfg).unwrap(),
+        ExternalLinkKind::export_to_string(&cfg).unwrap(),
         ExternalLink::export_to_string(&cfg).unwrap(),
         Person::export_to_string(&cfg).unwrap(),
         PersonRole::export_to_string(&cfg).unwrap(),
@@ -19,15 +20,23 @@ fn main() {
         RelatedManga::export_to_string(&cfg).unwrap(),
         SimilarAnime::export_to_string(&cfg).unwrap(),
         SimilarAnimeImage::export_to_string(&cfg).unwrap(),
+        RelationKind::export_to_string(&cfg).unwrap(),
         Related::export_to_string(&cfg).unwrap(),
         Video::export_to_string(&cfg).unwrap(),
         Screenshot::export_to_string(&cfg).unwrap(),
         ScoreStat::export_to_string(&cfg).unwrap(),
+        WatchStatus::export_to_string(&cfg).unwrap(),
+        AnimeStatus::export_to_string(&cfg).unwrap(),
+        AgeRating::export_to_string(&cfg).unwrap(),
         StatusStat::export_to_string(&cfg).unwrap(),
         Anime::export_to_string(&cfg).unwrap(),
         Manga::export_to_string(&cfg).unwrap(),
         CharacterFull::export_to_string(&cfg).unwrap(),
+        EntryRef::export_to_string(&cfg).unwrap(),
+        PersonWork::export_to_string(&cfg).unwrap(),
+        PersonCharacterRoles::export_to_string(&cfg).unwrap(),
         PersonFull::export_to_string(&cfg).unwrap(),
+        SearchAllResult::export_to_string(&cfg).unwrap(),
     ];
 
     let output = types.join("\n\n");
@@ -0,0 +1,93 @@
+//! Сериализация тела GraphQL запросов.
+//!
+//! По умолчанию клиент отправляет JSON вида `{"query": ..., "variables": ...}`
+//! ([`JsonBody`]). Если прокси требует другой формат (блок `extensions`,
+//! `application/graphql` и т.п.), реализуйте [`BodySerializer`] и передайте его в
+//! [`ShikicrateClientBuilder::body_serializer`](crate::ShikicrateClientBuilder::body_serializer).
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::body::JsonBody;
+//! use serde_json::json;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .body_serializer(JsonBody::with_extensions(json!({ "persistedQuery": { "version": 1 } })))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use serde_json::json;
+
+/// Готовое тело HTTP запроса.
+#[derive(Debug, Clone)]
+pub struct RequestBody {
+    /// Значение заголовка `Content-Type`.
+    pub content_type: String,
+
+    /// Байты тела запроса.
+    pub bytes: Vec<u8>,
+}
+
+/// Формирует тело GraphQL запроса из текста запроса и переменных.
+pub trait BodySerializer: Send + Sync {
+    /// Сериализует запрос. `variables` — объект переменных (пустой, если их нет).
+    fn serialize(&self, query: &str, variables: &serde_json::Value) -> Result<RequestBody>;
+}
+
+/// Стандартный JSON формат GraphQL запроса с необязательным блоком `extensions`.
+#[derive(Debug, Clone, Default)]
+pub struct JsonBody {
+    extensions: Option<serde_json::Value>,
+}
+
+impl JsonBody {
+    /// JSON тело с блоком `extensions`.
+    pub fn with_extensions(extensions: serde_json::Value) -> Self {
+        Self {
+            extensions: Some(extensions),
+        }
+    }
+}
+
+impl BodySerializer for JsonBody {
+    fn serialize(&self, query: &str, variables: &serde_json::Value) -> Result<RequestBody> {
+        let mut body = json!({
+            "query": query,
+            "variables": variables
+        });
+        if let Some(extensions) = &self.extensions {
+            body["extensions"] = extensions.clone();
+        }
+
+        Ok(RequestBody {
+            content_type: "application/json".to_string(),
+            bytes: serde_json::to_vec(&body)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_body() {
+        let body = JsonBody::default().serialize("{ animes { id } }", &json!({})).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&body.bytes).unwrap();
+        assert_eq!(body.content_type, "application/json");
+        assert_eq!(value["query"], "{ animes { id } }");
+        assert!(value.get("extensions").is_none());
+
+        let body = JsonBody::with_extensions(json!({ "a": 1 }))
+            .serialize("{ animes { id } }", &json!({ "limit": 1 }))
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&body.bytes).unwrap();
+        assert_eq!(value["variables"]["limit"], 1);
+        assert_eq!(value["extensions"]["a"], 1);
+    }
+}
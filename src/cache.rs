@@ -0,0 +1,308 @@
+//! Хранилища кеша ответов.
+//!
+//! По умолчанию клиент хранит до 500 ответов в памяти ([`MemoryStore`]). Для
+//! CLI утилит, которым нужен «теплый» кеш между запусками, есть [`FileStore`]
+//! (по одному JSON файлу на запрос), а собственное хранилище можно подключить,
+//! реализовав [`CacheStore`].
+//!
+//! Поведение кеша для отдельных запросов задается через [`CacheControl`] и
+//! [`ShikicrateClient::with_cache_control`](crate::ShikicrateClient::with_cache_control).
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::cache::{CacheControl, FileStore};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> shikicrate::Result<()> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .cache_store(FileStore::new("/tmp/shikicrate-cache")?)
+//!     .build()?;
+//!
+//! // Данные из кеша, даже если TTL истек, но не старше суток
+//! let genres = client
+//!     .with_cache_control(CacheControl::MaxAge(Duration::from_secs(86400)))
+//!     .genres()
+//!     .await?;
+//!
+//! // Принудительно обновить кеш
+//! let genres = client.with_cache_control(CacheControl::Refresh).genres().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Ключ кеша: текст запроса (или REST путь) и сериализованные переменные.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub query: String,
+    pub variables: String,
+}
+
+/// Закешированный ответ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// JSON ответа.
+    pub data: serde_json::Value,
+
+    /// Время получения ответа с сервера.
+    pub fetched_at: SystemTime,
+
+    /// Время, после которого запись считается устаревшей.
+    pub expires_at: SystemTime,
+}
+
+impl CacheEntry {
+    pub fn new(data: serde_json::Value, ttl: Duration) -> Self {
+        let fetched_at = SystemTime::now();
+        Self {
+            data,
+            fetched_at,
+            expires_at: fetched_at + ttl,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() > self.expires_at
+    }
+
+    /// Возраст записи.
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed().unwrap_or_default()
+    }
+}
+
+/// Хранилище кеша ответов.
+///
+/// Кеш не влияет на корректность запросов, поэтому ошибки хранилища не
+/// возвращаются: неудачная запись просто не попадает в кеш. Проверку срока
+/// жизни выполняет клиент, хранилище возвращает записи как есть.
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry>;
+
+    fn put(&self, key: CacheKey, entry: CacheEntry);
+
+    fn remove(&self, key: &CacheKey);
+
+    /// Удаляет записи, текст запроса которых содержит `pattern`.
+    fn invalidate(&self, pattern: &str);
+
+    fn clear(&self);
+}
+
+/// Кеш в памяти с вытеснением давно неиспользуемых записей (LRU).
+pub struct MemoryStore {
+    entries: Mutex<LruCache<CacheKey, CacheEntry>>,
+}
+
+impl MemoryStore {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<CacheKey, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(500).unwrap())
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry> {
+        self.lock().get(key).cloned()
+    }
+
+    fn put(&self, key: CacheKey, entry: CacheEntry) {
+        self.lock().put(key, entry);
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        self.lock().pop(key);
+    }
+
+    fn invalidate(&self, pattern: &str) {
+        let mut entries = self.lock();
+        let stale: Vec<CacheKey> = entries
+            .iter()
+            .filter(|(key, _)| key.query.contains(pattern))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in stale {
+            entries.pop(&key);
+        }
+    }
+
+    fn clear(&self) {
+        self.lock().clear();
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    key: CacheKey,
+    entry: CacheEntry,
+}
+
+/// Кеш в каталоге на диске: по одному JSON файлу на запрос.
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Создает хранилище в каталоге `dir` (каталог создается при необходимости).
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        // FNV-1a: имя файла должно быть одинаковым между запусками
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in key.query.bytes().chain([0]).chain(key.variables.bytes()) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        self.dir.join(format!("{:016x}.json", hash))
+    }
+
+    fn read(path: &std::path::Path) -> Option<StoredEntry> {
+        serde_json::from_slice(&std::fs::read(path).ok()?).ok()
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (PathBuf, StoredEntry)> {
+        std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| Self::read(&path).map(|stored| (path, stored)))
+    }
+}
+
+impl CacheStore for FileStore {
+    fn get(&self, key: &CacheKey) -> Option<CacheEntry> {
+        Self::read(&self.path(key))
+            .filter(|stored| stored.key == *key)
+            .map(|stored| stored.entry)
+    }
+
+    fn put(&self, key: CacheKey, entry: CacheEntry) {
+        let path = self.path(&key);
+        let Ok(bytes) = serde_json::to_vec(&StoredEntry { key, entry }) else {
+            return;
+        };
+        // Запись через временный файл, чтобы параллельный запуск не прочитал половину JSON
+        let tmp = path.with_extension("tmp");
+        if std::fs::write(&tmp, bytes).is_ok() {
+            let _ = std::fs::rename(&tmp, &path);
+        }
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        let _ = std::fs::remove_file(self.path(key));
+    }
+
+    fn invalidate(&self, pattern: &str) {
+        for (path, stored) in self.entries() {
+            if stored.key.query.contains(pattern) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        for (path, _) in self.entries() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Режим использования кеша для запросов.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheControl {
+    /// Свежие записи берутся из кеша, ответы сохраняются с TTL по типу запроса.
+    #[default]
+    Default,
+    /// Кеш не читается и не пополняется.
+    Bypass,
+    /// Кеш не читается, но новые ответы сохраняются.
+    Refresh,
+    /// Запись берется из кеша, если она не старше указанного времени (даже если TTL истек).
+    MaxAge(Duration),
+}
+
+impl CacheControl {
+    pub(crate) fn reads(&self) -> bool {
+        matches!(self, Self::Default | Self::MaxAge(_))
+    }
+
+    pub(crate) fn writes(&self) -> bool {
+        !matches!(self, Self::Bypass)
+    }
+
+    pub(crate) fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        match self {
+            Self::MaxAge(max_age) => entry.age() <= *max_age,
+            _ => !entry.is_expired(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(query: &str) -> CacheKey {
+        CacheKey {
+            query: query.to_string(),
+            variables: "{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_file_store_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("shikicrate-cache-{}", std::process::id()));
+        let store = FileStore::new(&dir).unwrap();
+        store.put(key("query GetGenres"), CacheEntry::new(json!({ "data": 1 }), Duration::from_secs(60)));
+        store.put(key("query userRates"), CacheEntry::new(json!({ "data": 2 }), Duration::from_secs(60)));
+
+        // Новый экземпляр видит записи предыдущего «запуска»
+        let store = FileStore::new(&dir).unwrap();
+        assert_eq!(store.get(&key("query GetGenres")).unwrap().data, json!({ "data": 1 }));
+
+        store.invalidate("userRates");
+        assert!(store.get(&key("query userRates")).is_none());
+        assert!(store.get(&key("query GetGenres")).is_some());
+
+        store.clear();
+        assert!(store.get(&key("query GetGenres")).is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_control() {
+        let expired = CacheEntry::new(json!(null), Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(!CacheControl::Default.is_fresh(&expired));
+        assert!(CacheControl::MaxAge(Duration::from_secs(60)).is_fresh(&expired));
+        assert!(!CacheControl::Refresh.reads() && CacheControl::Refresh.writes());
+        assert!(!CacheControl::Bypass.reads() && !CacheControl::Bypass.writes());
+    }
+}
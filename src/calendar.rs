@@ -0,0 +1,111 @@
+//! Календарь выхода эпизодов онгоингов.
+//!
+//! Данные загружаются через REST API Shikimori (`/api/calendar`).
+
+use crate::client::ShikicrateClient;
+use crate::error::Result;
+use crate::types::deser_id;
+use serde::{Deserialize, Serialize};
+
+/// Аниме в календаре.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarAnime {
+    /// ID аниме в системе Shikimori.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// Основное название.
+    pub name: String,
+
+    /// Русское название (если есть).
+    pub russian: Option<String>,
+
+    /// Тип аниме (`tv`, `ona` и т.д.).
+    pub kind: Option<String>,
+
+    /// Статус выхода (`ongoing`, `anons`).
+    pub status: Option<String>,
+
+    /// Общее количество эпизодов (0, если неизвестно).
+    pub episodes: Option<i32>,
+
+    /// Количество вышедших эпизодов.
+    pub episodes_aired: Option<i32>,
+
+    /// Относительный URL страницы на Shikimori.
+    pub url: Option<String>,
+}
+
+/// Ожидаемый выход эпизода.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarEntry {
+    /// Номер следующего эпизода.
+    pub next_episode: i32,
+
+    /// Время выхода эпизода (ISO 8601).
+    pub next_episode_at: String,
+
+    /// Длительность эпизода в минутах (если известна).
+    pub duration: Option<f64>,
+
+    /// Аниме.
+    pub anime: CalendarAnime,
+}
+
+crate::types::utc_accessors!(CalendarEntry { next_episode_at_utc => next_episode_at });
+
+impl ShikicrateClient {
+    /// Получение календаря выхода эпизодов онгоингов и анонсов.
+    ///
+    /// Записи отсортированы по времени выхода. При `censored = true` из
+    /// календаря исключается аниме для взрослых.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    ///
+    /// for entry in client.calendar(true).await?.iter().take(10) {
+    ///     println!("{} — эпизод {} в {}", entry.anime.name, entry.next_episode, entry.next_episode_at);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn calendar(&self, censored: bool) -> Result<Vec<CalendarEntry>> {
+        let query = [("censored", censored.to_string())];
+        self.get_rest("calendar", Some(query)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_calendar_entry_deserialize() {
+        let entry: CalendarEntry = serde_json::from_value(json!({
+            "next_episode": 5,
+            "next_episode_at": "2024-02-03T17:30:00.000+03:00",
+            "duration": null,
+            "anime": {
+                "id": 52991,
+                "name": "Sousou no Frieren",
+                "russian": "Провожающая в последний путь Фрирен",
+                "kind": "tv",
+                "status": "ongoing",
+                "episodes": 28,
+                "episodes_aired": 4,
+                "url": "/animes/52991"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(entry.anime.id, 52991);
+        assert_eq!(entry.next_episode, 5);
+        assert!(entry.duration.is_none());
+    }
+}
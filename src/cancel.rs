@@ -0,0 +1,224 @@
+//! Отмена запросов.
+//!
+//! Клиент, созданный через
+//! [`ShikicrateClient::with_cancellation`](crate::ShikicrateClient::with_cancellation),
+//! прерывает запросы сразу после вызова [`CancellationToken::cancel`], в том
+//! числе во время ожидания лимитера и паузы между повторами, и возвращает
+//! [`ShikicrateError::Cancelled`](crate::ShikicrateError::Cancelled).
+//!
+//! Future методов клиента также можно просто удалить (например, через
+//! `tokio::time::timeout` или `select!`): прерванный запрос не оставляет
+//! захваченных слотов лимитера и circuit breaker.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClient;
+//! use shikicrate::cancel::CancellationToken;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ShikicrateClient::new()?;
+//! let token = CancellationToken::new();
+//!
+//! let request = tokio::spawn({
+//!     let client = client.with_cancellation(token.clone());
+//!     async move { client.anime_by_id(1).await }
+//! });
+//!
+//! // Пользователь ушел со страницы
+//! token.cancel();
+//! assert!(request.await?.is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Wakers ожидающих future по их ключам. Future удаляет свой waker при
+/// удалении, поэтому долгоживущий токен не накапливает wakers завершенных запросов.
+#[derive(Default)]
+struct Waiters {
+    next_key: u64,
+    wakers: HashMap<u64, Waker>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    waiters: Mutex<Waiters>,
+}
+
+/// Токен отмены. Клоны токена разделяют состояние.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Отменяет все запросы, использующие токен (и будущие тоже).
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let wakers = std::mem::take(&mut self.lock_waiters().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Future, которое завершается при отмене токена.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self, key: None }
+    }
+
+    fn lock_waiters(&self) -> std::sync::MutexGuard<'_, Waiters> {
+        self.inner.waiters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Future из [`CancellationToken::cancelled`].
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    /// Ключ зарегистрированного waker.
+    key: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let token = self.token;
+        let mut waiters = token.lock_waiters();
+        // Повторная проверка под блокировкой: cancel() мог выполниться между ними
+        if token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let key = *self.key.get_or_insert_with(|| {
+            waiters.next_key += 1;
+            waiters.next_key
+        });
+        match waiters.wakers.get_mut(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => {
+                waiters.wakers.insert(key, cx.waker().clone());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.lock_waiters().wakers.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_waiters() {
+        let token = CancellationToken::new();
+        let waiter = tokio::spawn({
+            let token = token.clone();
+            async move { token.cancelled().await }
+        });
+        tokio::task::yield_now().await;
+
+        token.cancel();
+        tokio::time::timeout(Duration::from_secs(1), waiter).await.unwrap().unwrap();
+        assert!(token.is_cancelled());
+
+        // Уже отмененный токен завершает future сразу
+        token.cancelled().await;
+    }
+
+    #[tokio::test]
+    async fn test_wakers_removed_after_requests() {
+        use crate::transport::MockTransport;
+        use serde_json::json;
+
+        let token = CancellationToken::new();
+        let client = crate::ShikicrateClientBuilder::new()
+            .transport(MockTransport::new().graphql("SearchAnimes", json!({ "animes": [] })))
+            .token_bucket(crate::rate_limit::TokenBucket {
+                per_second: 1000,
+                per_minute: 60_000,
+            })
+            .build()
+            .unwrap()
+            .with_cancellation(token.clone());
+
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.animes(Default::default()).await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert!(token.lock_waiters().wakers.is_empty());
+
+        // Удаленное до завершения future тоже снимает регистрацию
+        let pending = tokio::time::timeout(Duration::from_millis(10), token.cancelled()).await;
+        assert!(pending.is_err());
+        assert!(token.lock_waiters().wakers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_client_aborts_backoff() {
+        use crate::retry::RetryPolicy;
+        use crate::{ShikicrateClientBuilder, ShikicrateError};
+
+        let token = CancellationToken::new();
+        let client = ShikicrateClientBuilder::new()
+            .base_url("http://127.0.0.1:9/api/graphql".to_string())
+            .retry_policy(RetryPolicy {
+                max_attempts: 5,
+                base_delay: Duration::from_secs(60),
+                ..Default::default()
+            })
+            .build()
+            .unwrap()
+            .with_cancellation(token.clone());
+
+        let request = tokio::spawn(async move {
+            client
+                .execute_query::<serde_json::Value>("{ genres(entryType: Anime) { id } }", None)
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        token.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), request).await.unwrap().unwrap();
+        assert!(matches!(result, Err(ShikicrateError::Cancelled)));
+    }
+}
@@ -0,0 +1,222 @@
+//! Модель страницы «Персонажи и авторы» для аниме и манги.
+//!
+//! Роли тайтла загружаются одним запросом, затем все персонажи гидрируются
+//! пакетным запросом по ID и раскладываются на главных и второстепенных
+//! персонажей, а люди — на группы по ролям (режиссер, автор оригинала и т.д.).
+
+use crate::client::ShikicrateClient;
+use crate::error::{Result, ShikicrateError};
+use crate::queries::{ANIME_ROLES_QUERY, CHARACTER_DETAILS_QUERY, MANGA_ROLES_QUERY};
+use crate::types::*;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+
+/// Максимальное количество ID в одном пакетном запросе персонажей.
+const CHARACTER_BATCH_SIZE: usize = 50;
+
+/// Персонаж тайтла с его ролями.
+#[derive(Debug, Clone)]
+pub struct CastMember {
+    /// Полная информация о персонаже.
+    pub character: CharacterFull,
+
+    /// Роли персонажа в тайтле (на английском, например `"Main"`).
+    pub roles: Vec<String>,
+}
+
+/// Готовая к отображению модель каста тайтла.
+#[derive(Debug, Clone, Default)]
+pub struct CastPage {
+    /// Главные персонажи, отсортированные по имени.
+    pub main: Vec<CastMember>,
+
+    /// Второстепенные персонажи, отсортированные по имени.
+    pub supporting: Vec<CastMember>,
+
+    /// Люди, сгруппированные по роли (на английском). Один человек может
+    /// входить в несколько групп.
+    pub staff: BTreeMap<String, Vec<Person>>,
+}
+
+#[derive(Deserialize)]
+struct TitleRoles {
+    #[serde(rename = "characterRoles")]
+    character_roles: Option<Vec<CharacterRole>>,
+    #[serde(rename = "personRoles")]
+    person_roles: Option<Vec<PersonRole>>,
+}
+
+impl CastPage {
+    /// Собирает страницу каста из ролей тайтла и гидрированных персонажей.
+    ///
+    /// Персонажи, для которых нет полной информации, пропускаются.
+    pub fn from_roles(
+        character_roles: &[CharacterRole],
+        person_roles: &[PersonRole],
+        characters: Vec<CharacterFull>,
+    ) -> Self {
+        let mut by_id: HashMap<i64, CharacterFull> = characters.into_iter().map(|c| (c.id, c)).collect();
+        let mut page = CastPage::default();
+
+        for role in character_roles {
+            let Some(character) = role.character.as_ref().and_then(|c| by_id.remove(&c.id)) else {
+                continue;
+            };
+            let roles = role.roles_en.clone().unwrap_or_default();
+            let member = CastMember { character, roles };
+
+            if member.roles.iter().any(|r| r.eq_ignore_ascii_case("main")) {
+                page.main.push(member);
+            } else {
+                page.supporting.push(member);
+            }
+        }
+
+        for role in person_roles {
+            for name in role.roles_en.iter().flatten() {
+                page.staff.entry(name.clone()).or_default().push(role.person.clone());
+            }
+        }
+
+        page.main.sort_by(|a, b| a.character.name.cmp(&b.character.name));
+        page.supporting.sort_by(|a, b| a.character.name.cmp(&b.character.name));
+        for people in page.staff.values_mut() {
+            people.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        page
+    }
+}
+
+impl ShikicrateClient {
+    /// Загружает страницу каста аниме: главные и второстепенные персонажи,
+    /// авторы и студийный персонал по ролям.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let cast = client.anime_cast(5081).await?;
+    ///
+    /// for member in &cast.main {
+    ///     println!("{}", member.character.name);
+    /// }
+    /// for (role, people) in &cast.staff {
+    ///     println!("{}: {}", role, people.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn anime_cast(&self, id: i64) -> Result<CastPage> {
+        self.title_cast(ANIME_ROLES_QUERY, "animes", id).await
+    }
+
+    /// Загружает страницу каста манги.
+    pub async fn manga_cast(&self, id: i64) -> Result<CastPage> {
+        self.title_cast(MANGA_ROLES_QUERY, "mangas", id).await
+    }
+
+    async fn title_cast(&self, query: &str, key: &str, id: i64) -> Result<CastPage> {
+        let response: serde_json::Value = self.execute_query(query, Some(json!({ "ids": id.to_string() }))).await?;
+
+        let title = response
+            .get(key)
+            .and_then(|v| v.as_array())
+            .and_then(|v| v.first())
+            .cloned()
+            .ok_or_else(|| ShikicrateError::GraphQL {
+                message: format!("Title {} not found", id),
+                errors: None,
+                details: Vec::new(),
+            })?;
+        let roles: TitleRoles = serde_json::from_value(title)?;
+        let character_roles = roles.character_roles.unwrap_or_default();
+        let person_roles = roles.person_roles.unwrap_or_default();
+
+        let ids: Vec<String> = character_roles
+            .iter()
+            .filter_map(|r| r.character.as_ref())
+            .map(|c| c.id.to_string())
+            .collect();
+
+        let mut characters = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(CHARACTER_BATCH_SIZE) {
+            let response: serde_json::Value = self
+                .execute_query(
+                    CHARACTER_DETAILS_QUERY,
+                    Some(json!({ "ids": chunk.join(","), "limit": chunk.len() })),
+                )
+                .await?;
+            let batch = response.get("characters").cloned().unwrap_or_else(|| json!([]));
+            characters.extend(serde_json::from_value::<Vec<CharacterFull>>(batch)?);
+        }
+
+        Ok(CastPage::from_roles(&character_roles, &person_roles, characters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character_role(id: i64, name: &str, role: &str) -> CharacterRole {
+        serde_json::from_value(json!({
+            "id": id,
+            "rolesEn": [role],
+            "character": { "id": id, "name": name }
+        }))
+        .unwrap()
+    }
+
+    fn character(id: i64, name: &str) -> CharacterFull {
+        serde_json::from_value(json!({ "id": id, "name": name })).unwrap()
+    }
+
+    #[test]
+    fn test_cast_page_from_roles() {
+        let character_roles = vec![
+            character_role(1, "Shinobu", "Supporting"),
+            character_role(2, "Koyomi", "Main"),
+            character_role(3, "Hitagi", "Main"),
+            character_role(4, "Missing", "Main"),
+        ];
+        let person_roles: Vec<PersonRole> = serde_json::from_value(json!([
+            { "id": 10, "rolesEn": ["Director", "Storyboard"], "person": { "id": 100, "name": "Shinbou" } },
+            { "id": 11, "rolesEn": ["Director"], "person": { "id": 101, "name": "Itamura" } }
+        ]))
+        .unwrap();
+        let characters = vec![character(1, "Shinobu"), character(2, "Koyomi"), character(3, "Hitagi")];
+
+        let page = CastPage::from_roles(&character_roles, &person_roles, characters);
+
+        let main: Vec<_> = page.main.iter().map(|m| m.character.name.as_str()).collect();
+        assert_eq!(main, vec!["Hitagi", "Koyomi"]);
+        assert_eq!(page.supporting.len(), 1);
+
+        let directors: Vec<_> = page.staff["Director"].iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(directors, vec!["Itamura", "Shinbou"]);
+        assert_eq!(page.staff["Storyboard"].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_title_cast_batches_with_limit() {
+        let roles: Vec<_> = (1..=60)
+            .map(|id| json!({ "id": id, "rolesEn": ["Main"], "character": { "id": id, "name": format!("C{}", id) } }))
+            .collect();
+        let transport = crate::transport::MockTransport::new()
+            .graphql("GetAnimeRoles", json!({ "animes": [{ "id": "1", "characterRoles": roles, "personRoles": [] }] }))
+            .graphql("GetCharacterDetails", json!({ "characters": [{ "id": "1", "name": "C1" }] }));
+        let client = crate::transport::mock_client(&transport);
+
+        client.anime_cast(1).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].body.as_ref().unwrap()["variables"]["limit"], 50);
+        assert_eq!(requests[2].body.as_ref().unwrap()["variables"]["limit"], 10);
+    }
+}
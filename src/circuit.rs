@@ -0,0 +1,218 @@
+//! Circuit breaker для защиты API во время сбоев.
+//!
+//! После `failure_threshold` подряд неудачных запросов (сетевые ошибки, 429
+//! и ответы 5xx) breaker «размыкается»: следующие запросы сразу завершаются
+//! ошибкой [`ShikicrateError::CircuitOpen`], не обращаясь к серверу. Через
+//! `open_for` пропускается один пробный запрос (half-open): при успехе
+//! breaker замыкается, при ошибке снова размыкается.
+//!
+//! Состояние breaker общее для всех клонов клиента.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClientBuilder, ShikicrateError, circuit::CircuitBreaker};
+//!
+//! # async fn example() -> shikicrate::Result<()> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .circuit_breaker(CircuitBreaker::default())
+//!     .build()?;
+//!
+//! match client.genres().await {
+//!     Err(ShikicrateError::CircuitOpen { retry_in }) => {
+//!         println!("API недоступно, следующая попытка через {:?}", retry_in);
+//!     }
+//!     result => println!("{:?}", result.map(|genres| genres.len())),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Настройки circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    /// Количество неудачных запросов подряд, после которого breaker размыкается.
+    pub failure_threshold: u32,
+
+    /// Время, в течение которого запросы отклоняются до пробного запроса.
+    pub open_for: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Default)]
+struct BreakerState {
+    failures: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+pub(crate) struct Breaker {
+    config: CircuitBreaker,
+    state: Mutex<BreakerState>,
+}
+
+/// Разрешение на запрос. Если запрос не завершился (future отменен),
+/// пробный слот освобождается при удалении разрешения.
+pub(crate) struct Permit<'a> {
+    breaker: &'a Breaker,
+    probe: bool,
+}
+
+impl Breaker {
+    pub(crate) fn new(config: CircuitBreaker) -> Result<Self> {
+        if config.failure_threshold == 0 {
+            return Err(ValidationError::new(
+                "failure_threshold",
+                Constraint::Min(1),
+                "CircuitBreaker: failure_threshold должен быть больше 0",
+            )
+            .value(config.failure_threshold)
+            .into());
+        }
+        Ok(Self {
+            config,
+            state: Mutex::new(BreakerState::default()),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Проверяет, можно ли отправить запрос.
+    pub(crate) fn acquire(&self) -> Result<Permit<'_>> {
+        let mut state = self.lock();
+        let Some(opened_at) = state.opened_at else {
+            return Ok(Permit { breaker: self, probe: false });
+        };
+
+        let reopen_at = opened_at + self.config.open_for;
+        let now = Instant::now();
+        if now < reopen_at {
+            return Err(ShikicrateError::CircuitOpen { retry_in: reopen_at - now });
+        }
+        if state.probe_in_flight {
+            return Err(ShikicrateError::CircuitOpen { retry_in: Duration::ZERO });
+        }
+        state.probe_in_flight = true;
+        Ok(Permit { breaker: self, probe: true })
+    }
+
+    fn is_failure(error: &ShikicrateError) -> bool {
+        match error {
+            ShikicrateError::Http(_) | ShikicrateError::RateLimit { .. } => true,
+            ShikicrateError::Api { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl Permit<'_> {
+    /// Учитывает результат запроса.
+    pub(crate) fn record<T>(mut self, result: &Result<T>) {
+        let failed = result.as_ref().err().is_some_and(Breaker::is_failure);
+        let mut state = self.breaker.lock();
+        if failed {
+            state.failures += 1;
+            if self.probe || state.failures >= self.breaker.config.failure_threshold {
+                state.opened_at = Some(Instant::now());
+            }
+        } else {
+            state.failures = 0;
+            state.opened_at = None;
+        }
+        if self.probe {
+            state.probe_in_flight = false;
+            self.probe = false;
+        }
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            self.breaker.lock().probe_in_flight = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure() -> Result<()> {
+        Err(ShikicrateError::Api {
+            status: 503,
+            message: String::new(),
+            headers: Default::default(),
+            operation: None,
+        })
+    }
+
+    #[test]
+    fn test_breaker_opens_and_probes() {
+        let breaker = Breaker::new(CircuitBreaker {
+            failure_threshold: 2,
+            open_for: Duration::ZERO,
+        })
+        .unwrap();
+
+        breaker.acquire().unwrap().record(&failure());
+        // 4xx не считается сбоем и сбрасывает счетчик
+        breaker.acquire().unwrap().record(&Err::<(), _>(ShikicrateError::Api {
+            status: 404,
+            message: String::new(),
+            headers: Default::default(),
+            operation: None,
+        }));
+        breaker.acquire().unwrap().record(&failure());
+        breaker.acquire().unwrap().record(&failure());
+
+        // open_for истек: один пробный запрос, остальные отклоняются
+        let probe = breaker.acquire().unwrap();
+        assert!(matches!(breaker.acquire(), Err(ShikicrateError::CircuitOpen { .. })));
+        probe.record(&Ok(()));
+
+        assert!(breaker.acquire().is_ok());
+    }
+
+    #[test]
+    fn test_breaker_rejects_while_open() {
+        let breaker = Breaker::new(CircuitBreaker {
+            failure_threshold: 1,
+            open_for: Duration::from_secs(60),
+        })
+        .unwrap();
+
+        breaker.acquire().unwrap().record(&failure());
+        match breaker.acquire() {
+            Err(ShikicrateError::CircuitOpen { retry_in }) => assert!(retry_in > Duration::from_secs(59)),
+            _ => panic!("breaker должен быть разомкнут"),
+        }
+    }
+
+    #[test]
+    fn test_dropped_probe_releases_slot() {
+        let breaker = Breaker::new(CircuitBreaker {
+            failure_threshold: 1,
+            open_for: Duration::ZERO,
+        })
+        .unwrap();
+
+        breaker.acquire().unwrap().record(&failure());
+        drop(breaker.acquire().unwrap());
+        assert!(breaker.acquire().is_ok());
+    }
+}
@@ -1,20 +1,28 @@
-use crate::error::{Result, ShikicrateError};
+use crate::body::{BodySerializer, JsonBody};
+use crate::cache::{CacheControl, CacheEntry, CacheKey, CacheStore, MemoryStore};
+use crate::cancel::CancellationToken;
+use crate::circuit::{Breaker, CircuitBreaker};
+use crate::error::{Constraint, GraphQLErrorDetail, ResponseHeaders, Result, ShikicrateError, ValidationError};
+use crate::logging::{LoggedExchange, RequestLogger};
+use crate::meta::{self, ResponseMeta};
+use crate::metrics::{MetricsObserver, RequestInfo};
+use crate::middleware::{Middleware, Next};
+use crate::mirrors::Mirrors;
+use crate::rate_limit::{AdaptiveRateLimit, Priority, RateLimiter, TokenBucket};
+use crate::retry::{RetryPolicy, RetryPredicate};
+use crate::sleep::{Sleeper, TokioSleeper};
+use crate::transport::{FixtureTransport, Transport};
+use crate::warnings::{Warning, WarningHandler};
 use reqwest::Client;
 use serde_json::json;
-use std::hash::{Hash, Hasher};
-use std::num::NonZeroUsize;
+use std::future::Future;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
-use tokio::sync::Mutex;
-use lru::LruCache;
+use std::time::{Duration, Instant, SystemTime};
 
 const API_BASE_URL: &str = "https://shikimori.io/api/graphql";
+const REST_BASE_URL: &str = "https://shikimori.io/api";
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
-const RETRY_DELAYS: [Duration; 3] = [
-    Duration::from_secs(1),
-    Duration::from_secs(2),
-    Duration::from_secs(4),
-];
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
 // Rate limit: 0.33 requests per second (3000ms between requests)
 const RATE_LIMIT_DELAY: Duration = Duration::from_millis(3000);
 
@@ -24,62 +32,110 @@ const CACHE_TTL_USER_RATES: Duration = Duration::from_secs(60); // 1 minute for
 const CACHE_TTL_DETAILS: Duration = Duration::from_secs(3600);
 const CACHE_TTL_STATIC: Duration = Duration::from_secs(86400); // 24 hours for genres/studios
 
-#[derive(Clone)]
-struct CacheKey {
-    query: String,
-    variables: String,
+/// Настройки пула соединений и TCP, которые передаются в reqwest.
+#[derive(Default)]
+struct ConnectionOptions {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    tcp_nodelay: Option<bool>,
 }
 
-impl Hash for CacheKey {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.query.hash(state);
-        self.variables.hash(state);
+impl ConnectionOptions {
+    fn is_default(&self) -> bool {
+        self.pool_max_idle_per_host.is_none()
+            && self.pool_idle_timeout.is_none()
+            && self.tcp_keepalive.is_none()
+            && self.tcp_nodelay.is_none()
     }
 }
 
-impl PartialEq for CacheKey {
-    fn eq(&self, other: &Self) -> bool {
-        self.query == other.query && self.variables == other.variables
-    }
-}
+/// Статистика работы клиента.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientStats {
+    /// Количество отправленных запросов (без учета ответов из кеша).
+    pub requests_sent: u64,
 
-impl Eq for CacheKey {}
+    /// Количество ответов 429 Too Many Requests.
+    pub rate_limited: u64,
 
-struct CacheEntry {
-    data: serde_json::Value,
-    expires_at: Instant,
-}
+    /// Текущая разрешенная частота запросов в секунду.
+    pub current_rps: f64,
 
-impl CacheEntry {
-    fn new(data: serde_json::Value, ttl: Duration) -> Self {
-        Self {
-            data,
-            expires_at: Instant::now() + ttl,
-        }
-    }
-
-    fn is_expired(&self) -> bool {
-        Instant::now() > self.expires_at
-    }
+    /// Оставшееся время общей паузы после 429 или исчерпания бюджета запросов.
+    pub cooldown: Duration,
 }
 
 pub struct ShikicrateClient {
     client: Client,
+    transport: Arc<dyn Transport>,
     base_url: String,
-    last_request: Arc<Mutex<Instant>>,
-    cache: Arc<Mutex<LruCache<CacheKey, CacheEntry>>>,
+    mirrors: Option<Arc<Mirrors>>,
+    limiter: Arc<RateLimiter>,
+    priority: Priority,
+    cache: Arc<dyn CacheStore>,
+    cache_control: CacheControl,
+    cancellation: Option<CancellationToken>,
+    access_token: Option<String>,
+    warning_handler: Option<WarningHandler>,
+    body_serializer: Arc<dyn BodySerializer>,
+    retry_policy: RetryPolicy,
+    retry_predicate: Option<RetryPredicate>,
+    breaker: Option<Arc<Breaker>>,
+    middlewares: Arc<[Arc<dyn Middleware>]>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    sleeper: Arc<dyn Sleeper>,
+    absolute_urls: bool,
 }
 
 pub struct ShikicrateClientBuilder {
     base_url: Option<String>,
+    mirrors: Option<Vec<String>>,
     timeout: Option<Duration>,
+    access_token: Option<String>,
+    adaptive_rate_limit: Option<AdaptiveRateLimit>,
+    token_bucket: Option<TokenBucket>,
+    warning_handler: Option<WarningHandler>,
+    body_serializer: Option<Arc<dyn BodySerializer>>,
+    retry_policy: Option<RetryPolicy>,
+    retry_predicate: Option<RetryPredicate>,
+    circuit_breaker: Option<CircuitBreaker>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    cache_store: Option<Arc<dyn CacheStore>>,
+    connection: ConnectionOptions,
+    user_agent: Option<String>,
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    offline: bool,
+    sleeper: Option<Arc<dyn Sleeper>>,
+    absolute_urls: bool,
 }
 
 impl ShikicrateClientBuilder {
     pub fn new() -> Self {
         Self {
             base_url: None,
+            mirrors: None,
             timeout: None,
+            access_token: None,
+            adaptive_rate_limit: None,
+            token_bucket: None,
+            warning_handler: None,
+            body_serializer: None,
+            retry_policy: None,
+            retry_predicate: None,
+            circuit_breaker: None,
+            middlewares: Vec::new(),
+            metrics: None,
+            cache_store: None,
+            connection: ConnectionOptions::default(),
+            user_agent: None,
+            http_client: None,
+            transport: None,
+            offline: false,
+            sleeper: None,
+            absolute_urls: false,
         }
     }
 
@@ -88,20 +144,361 @@ impl ShikicrateClientBuilder {
         self
     }
 
+    /// Упорядоченный список адресов сайта (например, `https://shikimori.one` и
+    /// `https://shikimori.me`).
+    ///
+    /// GraphQL и REST запросы отправляются на активное зеркало (`/api/graphql` и
+    /// `/api`); при ошибке подключения или DNS клиент сразу переключается на
+    /// следующее. Не совместимо с `base_url()`.
+    pub fn mirrors(mut self, origins: Vec<String>) -> Self {
+        self.mirrors = Some(origins);
+        self
+    }
+
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = Some(timeout);
         self
     }
 
+    /// OAuth2 access token для запросов, требующих авторизации (мутации, списки пользователя).
+    pub fn access_token(mut self, token: String) -> Self {
+        self.access_token = Some(token);
+        self
+    }
+
+    /// Включает адаптивное ограничение частоты запросов (AIMD) вместо фиксированного интервала.
+    pub fn adaptive_rate_limit(mut self, config: AdaptiveRateLimit) -> Self {
+        self.adaptive_rate_limit = Some(config);
+        self
+    }
+
+    /// Включает ограничение частоты по схеме token bucket (по умолчанию — лимиты
+    /// Shikimori: 5 запросов в секунду и 90 в минуту) вместо фиксированного интервала.
+    ///
+    /// Не совместимо с `adaptive_rate_limit()`.
+    pub fn token_bucket(mut self, config: TokenBucket) -> Self {
+        self.token_bucket = Some(config);
+        self
+    }
+
+    /// Обработчик нефатальных предупреждений (см. [`Warning`]).
+    pub fn on_warning<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&Warning) + Send + Sync + 'static,
+    {
+        self.warning_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Сериализатор тела GraphQL запросов (по умолчанию [`JsonBody`]).
+    pub fn body_serializer<S>(mut self, serializer: S) -> Self
+    where
+        S: BodySerializer + 'static,
+    {
+        self.body_serializer = Some(Arc::new(serializer));
+        self
+    }
+
+    /// Политика повторов при временных ошибках (по умолчанию 1/2/4 секунды).
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Условие, при котором ошибка считается временной и запрос повторяется.
+    ///
+    /// Заменяет стандартную проверку [`ShikicrateClient::is_retryable`]; чтобы
+    /// расширить ее, вызовите стандартную проверку внутри замыкания.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, ShikicrateClientBuilder, ShikicrateError};
+    ///
+    /// # fn example() -> shikicrate::Result<()> {
+    /// // Дополнительно повторять ответы 502/503 от прокси
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .retry_if(|error| {
+    ///         ShikicrateClient::is_retryable(error)
+    ///             || matches!(error, ShikicrateError::Api { status: 502 | 503, .. })
+    ///     })
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&ShikicrateError) -> bool + Send + Sync + 'static,
+    {
+        self.retry_predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Включает circuit breaker: после серии сбоев запросы временно отклоняются
+    /// ошибкой `CircuitOpen` без обращения к серверу.
+    pub fn circuit_breaker(mut self, config: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Добавляет обработчик HTTP запросов (см. [`Middleware`]).
+    ///
+    /// Обработчики вызываются в порядке добавления.
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: Middleware + 'static,
+    {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Включает журнал HTTP запросов с маскированием токенов и заголовка
+    /// `Authorization` (см. [`RequestLogger`]).
+    pub fn log_requests<F>(self, callback: F) -> Self
+    where
+        F: Fn(&LoggedExchange) + Send + Sync + 'static,
+    {
+        self.middleware(RequestLogger::new(callback))
+    }
+
+    /// Наблюдатель для сбора метрик запросов (см. [`MetricsObserver`]).
+    pub fn metrics<M>(mut self, observer: M) -> Self
+    where
+        M: MetricsObserver + 'static,
+    {
+        self.metrics = Some(Arc::new(observer));
+        self
+    }
+
+    /// Хранилище кеша ответов (по умолчанию [`MemoryStore`] на 500 записей).
+    pub fn cache_store<S>(mut self, store: S) -> Self
+    where
+        S: CacheStore + 'static,
+    {
+        self.cache_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Значение заголовка `User-Agent`.
+    ///
+    /// Правила API Shikimori требуют указывать в `User-Agent` название приложения;
+    /// для типичного случая удобнее `app_name()`.
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    /// Название приложения для `User-Agent` (например, `"MyAnimeBot"`).
+    ///
+    /// Заголовок будет иметь вид `MyAnimeBot shikicrate/<версия>`.
+    pub fn app_name(mut self, name: String) -> Self {
+        self.user_agent = Some(format!("{} shikicrate/{}", name.trim(), env!("CARGO_PKG_VERSION")));
+        self
+    }
+
+    /// Использует готовый `reqwest::Client`, например чтобы разделить пул
+    /// соединений и настройки TLS между несколькими API клиентами.
+    ///
+    /// Таймаут, `User-Agent` и настройки пула берутся из переданного клиента,
+    /// поэтому их нельзя задавать через builder одновременно с этим методом.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let http = reqwest::Client::builder()
+    ///     .user_agent("MyAnimeBot/1.0")
+    ///     .timeout(Duration::from_secs(20))
+    ///     .build()?;
+    ///
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .with_reqwest_client(http.clone())
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_reqwest_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Транспорт для отправки запросов (по умолчанию `reqwest::Client`),
+    /// например [`MockTransport`](crate::transport::MockTransport) в тестах.
+    pub fn transport<T>(mut self, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Офлайн режим: ответы берутся из JSON файлов каталога `dir`
+    /// (см. [`FixtureTransport`]), сеть не используется.
+    ///
+    /// Если лимитер не задан явно, запросы не ограничиваются по частоте.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClientBuilder, queries::*};
+    ///
+    /// # async fn example() -> shikicrate::Result<()> {
+    /// // examples/fixtures/SearchAnimes.json: {"data": {"animes": [...]}}
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .offline_fixtures("examples/fixtures")
+    ///     .build()?;
+    ///
+    /// let animes = client.animes(AnimeSearchParams::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn offline_fixtures(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.transport = Some(Arc::new(FixtureTransport::new(dir)));
+        self.offline = true;
+        self
+    }
+
+    /// Ожидание между повторами (по умолчанию `tokio::time::sleep`),
+    /// например [`RecordingSleeper`](crate::sleep::RecordingSleeper) в тестах.
+    pub fn sleeper<S>(mut self, sleeper: S) -> Self
+    where
+        S: Sleeper + 'static,
+    {
+        self.sleeper = Some(Arc::new(sleeper));
+        self
+    }
+
+    /// Приводить относительные ссылки в ответах (`url`, `originalUrl` и другие
+    /// поля `*Url`) к абсолютным относительно текущего хоста API или зеркала
+    /// (см. [`crate::urls`]). По умолчанию выключено.
+    pub fn absolute_urls(mut self, enabled: bool) -> Self {
+        self.absolute_urls = enabled;
+        self
+    }
+
+    /// Максимальное количество простаивающих соединений с одним хостом в пуле.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.connection.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Время, после которого простаивающее соединение закрывается (по умолчанию 90 секунд).
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.connection.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Интервал TCP keep-alive для открытых соединений.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.connection.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Включает или отключает `TCP_NODELAY` (алгоритм Нейгла).
+    pub fn tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.connection.tcp_nodelay = Some(enabled);
+        self
+    }
+
     pub fn build(self) -> Result<ShikicrateClient> {
         let base_url = self.base_url.as_deref().unwrap_or(API_BASE_URL);
+        let mirrors = match self.mirrors {
+            Some(_) if self.base_url.is_some() => {
+                return Err(ValidationError::new(
+                    "base_url",
+                    Constraint::ConflictsWith("mirrors"),
+                    "base_url и mirrors нельзя задать одновременно",
+                )
+                .into());
+            }
+            Some(origins) => Some(Arc::new(Mirrors::new(origins)?)),
+            None => None,
+        };
         let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        let retry_policy = self.retry_policy.unwrap_or_default();
+        retry_policy.validate()?;
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => {
+                let user_agent = user_agent.trim();
+                if user_agent.is_empty() || reqwest::header::HeaderValue::from_str(user_agent).is_err() {
+                    return Err(ValidationError::new(
+                        "user_agent",
+                        Constraint::Format,
+                        "User-Agent не должен быть пустым и может содержать только видимые ASCII символы",
+                    )
+                    .value(user_agent)
+                    .into());
+                }
+                user_agent
+            }
+            None => DEFAULT_USER_AGENT,
+        };
+        let breaker = self.circuit_breaker.map(Breaker::new).transpose()?.map(Arc::new);
+
+        let client = match self.http_client {
+            Some(client) => {
+                if self.timeout.is_some() || self.user_agent.is_some() || !self.connection.is_default() {
+                    return Err(ValidationError::new(
+                        "http_client",
+                        Constraint::ConflictsWith("timeout"),
+                        "timeout, user_agent и настройки пула задаются в переданном reqwest::Client",
+                    )
+                    .into());
+                }
+                client
+            }
+            None => ShikicrateClient::mk_client(timeout, user_agent, &self.connection)?,
+        };
 
         Ok(ShikicrateClient {
-            client: ShikicrateClient::mk_client(timeout)?,
+            transport: self.transport.unwrap_or_else(|| Arc::new(client.clone())),
+            client,
             base_url: base_url.to_string(),
-            last_request: Arc::new(Mutex::new(Instant::now() - RATE_LIMIT_DELAY)),
-            cache: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(500).unwrap()))), // Cache up to 500 entries
+            mirrors,
+            limiter: Arc::new(match (self.adaptive_rate_limit, self.token_bucket) {
+                (Some(_), Some(_)) => {
+                    return Err(ValidationError::new(
+                        "token_bucket",
+                        Constraint::ConflictsWith("adaptive_rate_limit"),
+                        "adaptive_rate_limit и token_bucket нельзя включить одновременно",
+                    )
+                    .into());
+                }
+                (Some(config), None) => {
+                    config.validate()?;
+                    RateLimiter::adaptive(config)
+                }
+                (None, Some(config)) => {
+                    if config.per_second == 0 || config.per_minute == 0 {
+                        return Err(ValidationError::new(
+                            "token_bucket",
+                            Constraint::Min(1),
+                            "Лимиты token bucket должны быть больше 0",
+                        )
+                        .into());
+                    }
+                    RateLimiter::token_bucket(config)
+                }
+                (None, None) if self.offline => RateLimiter::fixed(Duration::ZERO),
+                (None, None) => RateLimiter::fixed(RATE_LIMIT_DELAY),
+            }),
+            priority: Priority::default(),
+            cache: self.cache_store.unwrap_or_else(|| Arc::new(MemoryStore::default())),
+            cache_control: CacheControl::default(),
+            cancellation: None,
+            access_token: self.access_token,
+            warning_handler: self.warning_handler,
+            body_serializer: self.body_serializer.unwrap_or_else(|| Arc::new(JsonBody::default())),
+            retry_policy,
+            retry_predicate: self.retry_predicate,
+            breaker,
+            middlewares: self.middlewares.into(),
+            metrics: self.metrics,
+            sleeper: self.sleeper.unwrap_or_else(|| Arc::new(TokioSleeper)),
+            absolute_urls: self.absolute_urls,
         })
     }
 }
@@ -117,7 +514,7 @@ impl ShikicrateClient {
         Self::with_timeout(DEFAULT_TIMEOUT)
     }
 
-    fn mk_client(timeout: Duration) -> Result<Client> {
+    fn mk_client(timeout: Duration, user_agent: &str, connection: &ConnectionOptions) -> Result<Client> {
         use reqwest::header::{HeaderMap, HeaderValue};
         let mut headers = HeaderMap::new();
 
@@ -127,43 +524,163 @@ impl ShikicrateClient {
         headers.insert("Accept", HeaderValue::from_static("application/json"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
 
-        Client::builder()
+        let mut builder = Client::builder()
             .timeout(timeout)
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
-            .default_headers(headers)
-            .build()
-            .map_err(ShikicrateError::Http)
+            .user_agent(user_agent)
+            .default_headers(headers);
+        if let Some(max) = connection.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = connection.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = connection.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if let Some(enabled) = connection.tcp_nodelay {
+            builder = builder.tcp_nodelay(enabled);
+        }
+
+        builder.build().map_err(ShikicrateError::Http)
     }
 
     pub fn with_timeout(timeout: Duration) -> Result<Self> {
-        Ok(Self {
-            client: Self::mk_client(timeout)?,
-            base_url: API_BASE_URL.to_string(),
-            last_request: Arc::new(Mutex::new(Instant::now() - RATE_LIMIT_DELAY)),
-            cache: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(500).unwrap()))),
-        })
+        ShikicrateClientBuilder::new().timeout(timeout).build()
     }
 
     pub fn with_base_url(base_url: String) -> Result<Self> {
-        Ok(Self {
-            client: Self::mk_client(DEFAULT_TIMEOUT)?,
-            base_url,
-            last_request: Arc::new(Mutex::new(Instant::now() - RATE_LIMIT_DELAY)),
-            cache: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(500).unwrap()))),
-        })
+        ShikicrateClientBuilder::new().base_url(base_url).build()
     }
 
     async fn wait_for_rate_limit(&self) {
-        let mut last = self.last_request.lock().await;
-        let elapsed = last.elapsed();
-        if elapsed < RATE_LIMIT_DELAY {
-            let delay = RATE_LIMIT_DELAY - elapsed;
-            drop(last);
-            tokio::time::sleep(delay).await;
-            let mut last = self.last_request.lock().await;
-            *last = Instant::now();
-        } else {
-            *last = Instant::now();
+        self.limiter.acquire(self.priority).await;
+    }
+
+    /// URL GraphQL endpoint (с учетом активного зеркала).
+    fn graphql_url(&self) -> String {
+        match &self.mirrors {
+            Some(mirrors) => format!("{}/api/graphql", mirrors.active()),
+            None => self.base_url.clone(),
+        }
+    }
+
+    /// URL REST метода `path` (с учетом активного зеркала).
+    fn rest_url(&self, path: &str) -> String {
+        match &self.mirrors {
+            Some(mirrors) => format!("{}/api/{}", mirrors.active(), path),
+            None => format!("{}/{}", REST_BASE_URL, path),
+        }
+    }
+
+    /// Адрес сайта текущего хоста (`https://shikimori.io` или активное зеркало).
+    fn site_url(&self) -> String {
+        match &self.mirrors {
+            Some(mirrors) => mirrors.active().to_string(),
+            None => url::Url::parse(&self.base_url)
+                .map(|url| url.origin().ascii_serialization())
+                .unwrap_or_else(|_| crate::description::DEFAULT_BASE_URL.to_string()),
+        }
+    }
+
+    /// Десериализует данные ответа, при включенном `absolute_urls` приводя ссылки к абсолютным.
+    fn decode<T>(&self, mut data: serde_json::Value) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if self.absolute_urls {
+            crate::urls::absolutize(&mut data, &self.site_url());
+        }
+        serde_json::from_value(data).map_err(ShikicrateError::Serialization)
+    }
+
+    /// Возвращает клон клиента с указанным приоритетом запросов.
+    ///
+    /// Клон разделяет лимитер и кеш с исходным клиентом, поэтому фоновые
+    /// запросы (`Priority::Background`) не мешают интерактивным.
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        Self {
+            priority,
+            ..self.clone()
+        }
+    }
+
+    /// Возвращает клон клиента с указанным режимом кеша.
+    ///
+    /// Клон разделяет хранилище кеша с исходным клиентом.
+    pub fn with_cache_control(&self, cache_control: CacheControl) -> Self {
+        Self {
+            cache_control,
+            ..self.clone()
+        }
+    }
+
+    /// Возвращает клон клиента, запросы которого прерываются при отмене `token`
+    /// с ошибкой [`ShikicrateError::Cancelled`] (см. модуль [`cancel`](crate::cancel)).
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        Self {
+            cancellation: Some(token),
+            ..self.clone()
+        }
+    }
+
+    /// Выполняет future, прерывая его при отмене токена клиента.
+    async fn cancellable<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        match &self.cancellation {
+            Some(token) => tokio::select! {
+                biased;
+                _ = token.cancelled() => Err(ShikicrateError::Cancelled),
+                result = future => result,
+            },
+            None => future.await,
+        }
+    }
+
+    /// Очищает кеш ответов (общий для всех клонов).
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    /// Передает лимитеру результат запроса (для адаптивного режима и статистики).
+    fn record_outcome<T>(&self, result: &Result<T>) {
+        match result {
+            Ok(_) => self.limiter.on_success(),
+            Err(ShikicrateError::RateLimit { .. }) => self.limiter.on_rate_limited(),
+            Err(_) => {}
+        }
+    }
+
+    /// Передает предупреждение обработчику (если он задан).
+    pub(crate) fn warn(&self, warning: Warning) {
+        if let Some(handler) = &self.warning_handler {
+            handler(&warning);
+        }
+    }
+
+    /// Политика повторов клиента.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Задержка перед повтором с номером `retry` (с 0).
+    ///
+    /// Общая пауза после 429 выдерживается лимитером, поэтому из задержки политики
+    /// вычитается ее остаток.
+    pub(crate) fn retry_delay(&self, retry: u32) -> Duration {
+        self.retry_policy.delay(retry).saturating_sub(self.limiter.cooldown_remaining())
+    }
+
+    /// Пауза через [`Sleeper`] клиента.
+    pub(crate) fn sleep(&self, delay: Duration) -> futures::future::BoxFuture<'static, ()> {
+        self.sleeper.sleep(delay)
+    }
+
+    /// Текущая статистика клиента (общая для всех клонов).
+    pub fn stats(&self) -> ClientStats {
+        ClientStats {
+            requests_sent: self.limiter.requests_sent(),
+            rate_limited: self.limiter.rate_limited(),
+            current_rps: self.limiter.current_rps(),
+            cooldown: self.limiter.cooldown_remaining(),
         }
     }
 
@@ -174,24 +691,154 @@ impl ShikicrateClient {
         }
     }
 
-    async fn get_from_cache(&self, key: &CacheKey) -> Option<serde_json::Value> {
-        let mut cache = self.cache.lock().await;
-        if let Some(entry) = cache.get(key) {
-            if !entry.is_expired() {
-                return Some(entry.data.clone());
-            } else {
-                cache.pop(key);
-            }
+    async fn get_from_cache(&self, key: &CacheKey) -> Option<(serde_json::Value, SystemTime)> {
+        if !self.cache_control.reads() {
+            return None;
+        }
+        let entry = self.cache.get(key)?;
+        if self.cache_control.is_fresh(&entry) {
+            return Some((entry.data, entry.fetched_at));
+        }
+        if entry.is_expired() {
+            self.cache.remove(key);
         }
         None
     }
 
     async fn put_to_cache(&self, key: CacheKey, data: serde_json::Value, ttl: Duration) {
-        let mut cache = self.cache.lock().await;
-        cache.put(key, CacheEntry::new(data, ttl));
+        if self.cache_control.writes() {
+            self.cache.put(key, CacheEntry::new(data, ttl));
+        }
+    }
+
+    async fn invalidate_cache(&self, pattern: &str) {
+        self.cache.invalidate(pattern);
+    }
+
+    /// Отправляет запрос через circuit breaker, лимитер и цепочку middleware и проверяет ответ.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: Option<&str>,
+        prefix: &str,
+    ) -> Result<reqwest::Response> {
+        self.cancellable(self.send_once(request, operation, prefix)).await
+    }
+
+    async fn send_once(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: Option<&str>,
+        prefix: &str,
+    ) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        let permit = self.breaker.as_deref().map(Breaker::acquire).transpose()?;
+        self.wait_for_rate_limit().await;
+
+        let method = request.method().clone();
+        let endpoint = request.url().to_string();
+        let info = RequestInfo {
+            method: method.as_str(),
+            endpoint: &endpoint,
+            operation,
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.request_started(&info);
+        }
+
+        let started = Instant::now();
+        let result = async {
+            let response = self.run_with_failover(request).await?;
+            self.check_response(response, operation, prefix).await
+        }
+        .await;
+
+        if let Some(metrics) = &self.metrics {
+            match &result {
+                Ok(response) => metrics.request_completed(&info, response.status().as_u16(), started.elapsed()),
+                Err(e) => metrics.request_failed(&info, e, started.elapsed()),
+            }
+        }
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        result
+    }
+
+    /// Пропускает запрос через middleware; при ошибке подключения переносит его
+    /// на следующие зеркала (если они заданы).
+    async fn run_with_failover(&self, mut request: reqwest::Request) -> Result<reqwest::Response> {
+        let mut failovers = 0;
+        loop {
+            let fallback = match &self.mirrors {
+                Some(mirrors) if failovers + 1 < mirrors.len() => request.try_clone().map(|copy| (mirrors, copy)),
+                _ => None,
+            };
+            let result = Next::new(self.transport.as_ref(), &self.middlewares).run(request).await;
+            match (result, fallback) {
+                (Err(ShikicrateError::Http(e)), Some((mirrors, mut copy))) if e.is_connect() => {
+                    let Some(url) = mirrors.fail_over(copy.url()) else {
+                        return Err(ShikicrateError::Http(e));
+                    };
+                    *copy.url_mut() = url;
+                    request = copy;
+                    failovers += 1;
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    /// Передает лимитеру заголовки и результат ответа, затем проверяет HTTP статус.
+    async fn check_response(
+        &self,
+        response: reqwest::Response,
+        operation: Option<&str>,
+        prefix: &str,
+    ) -> Result<reqwest::Response> {
+        self.limiter.on_headers(response.status().as_u16(), response.headers());
+        let response = Self::ensure_success(response, operation, prefix).await;
+        self.record_outcome(&response);
+        response
+    }
+
+    /// Проверяет HTTP статус ответа и преобразует неуспешный ответ в ошибку.
+    pub(crate) async fn ensure_success(
+        response: reqwest::Response,
+        operation: Option<&str>,
+        prefix: &str,
+    ) -> Result<reqwest::Response> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        // Extract Retry-After header for rate limiting before consuming response
+        let retry_after = response.headers()
+            .get("Retry-After")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok());
+        let headers = Box::new(ResponseHeaders::from_headers(response.headers()));
+
+        let text = response.text().await?;
+
+        if status.as_u16() == 429 {
+            return Err(ShikicrateError::RateLimit {
+                message: format!("Too Many Requests: {}", text),
+                retry_after: retry_after.or(Some(60)), // Default to 60 seconds if not provided
+            });
+        }
+
+        Err(ShikicrateError::Api {
+            status: status.as_u16(),
+            message: format!("{} {}: {}", prefix, status, text),
+            headers,
+            operation: operation.map(str::to_string),
+        })
     }
 
-    fn is_retryable(error: &ShikicrateError) -> bool {
+    /// Стандартная проверка временной ошибки: таймауты, ошибки подключения и 429.
+    pub fn is_retryable(error: &ShikicrateError) -> bool {
         match error {
             ShikicrateError::Http(e) => e.is_timeout() || e.is_connect() || e.is_request(),
             ShikicrateError::RateLimit { .. } => true,
@@ -199,62 +846,52 @@ impl ShikicrateClient {
         }
     }
 
-    async fn exec_once<T>(&self, query: &str, variables: Option<serde_json::Value>) -> Result<T>
+    /// Проверяет ошибку условием `retry_if` клиента или стандартной проверкой.
+    pub(crate) fn should_retry(&self, error: &ShikicrateError) -> bool {
+        match &self.retry_predicate {
+            Some(predicate) => predicate(error),
+            None => Self::is_retryable(error),
+        }
+    }
+
+    /// Выполняет одну попытку запроса. Кроме результата возвращает время получения
+    /// данных и признак ответа из кеша.
+    async fn exec_once<T>(&self, query: &str, variables: Option<serde_json::Value>) -> Result<(T, SystemTime, bool)>
     where
         T: serde::de::DeserializeOwned,
     {
         let cache_key = self.get_cache_key(query, &variables);
 
         // Try cache first
-        if let Some(cached_data) = self.get_from_cache(&cache_key).await {
+        if let Some((cached_data, fetched_at)) = self.get_from_cache(&cache_key).await {
             let data = cached_data.get("data").ok_or_else(|| ShikicrateError::GraphQL {
                 message: "No data in cached response".to_string(),
                 errors: None,
+                details: Vec::new(),
             })?;
-            return serde_json::from_value(data.clone()).map_err(ShikicrateError::from);
+            let result = self.decode(data.clone())?;
+            return Ok((result, fetched_at, true));
         }
 
-        self.wait_for_rate_limit().await;
+        let body = self
+            .body_serializer
+            .serialize(query, &variables.unwrap_or(json!({})))?;
 
-        let body = json!({
-            "query": query,
-            "variables": variables.unwrap_or(json!({}))
-        });
-
-        let response = self
+        let request = self
             .client
-            .post(&self.base_url)
+            .post(self.graphql_url())
             .header("Origin", "https://shikimori.io")
-            .header("Referer", "https://shikimori.io/")
-            .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
-            .json(&body)
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            // Extract Retry-After header for rate limiting before consuming response
-            let retry_after = response.headers()
-                .get("Retry-After")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|s| s.parse::<u64>().ok());
-
-            let text = response.text().await?;
-
-            if status.as_u16() == 429 {
-                return Err(ShikicrateError::RateLimit {
-                    message: format!("Too Many Requests: {}", text),
-                    retry_after: retry_after.or(Some(60)), // Default to 60 seconds if not provided
-                });
-            }
-
-            return Err(ShikicrateError::Api {
-                status: status.as_u16(),
-                message: format!("HTTP {}: {}", status, text),
-            });
-        }
+            .header("Referer", "https://shikimori.io/");
+        let request = match &self.access_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        let request = request
+            .header(reqwest::header::CONTENT_TYPE, body.content_type)
+            .body(body.bytes);
 
+        let operation = meta::operation_name(query);
+        let response = self.send(request, operation.as_deref(), "HTTP").await?;
         let text = response.text().await?;
 
         let json: serde_json::Value = serde_json::from_str(&text)?;
@@ -263,12 +900,14 @@ impl ShikicrateClient {
             return Err(ShikicrateError::GraphQL {
                 message: "GraphQL error".to_string(),
                 errors: Some(errors.clone()),
+                details: GraphQLErrorDetail::parse_all(errors),
             });
         }
 
         let data = json.get("data").ok_or_else(|| ShikicrateError::GraphQL {
             message: "No data in response".to_string(),
             errors: None,
+            details: Vec::new(),
         })?;
 
         // Cache successful response
@@ -283,7 +922,8 @@ impl ShikicrateClient {
         };
         self.put_to_cache(cache_key, json.clone(), ttl).await;
 
-        serde_json::from_value(data.clone()).map_err(ShikicrateError::from)
+        let result = self.decode(data.clone())?;
+        Ok((result, SystemTime::now(), false))
     }
 
     pub(crate) async fn execute_query<T>(
@@ -294,17 +934,36 @@ impl ShikicrateClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let mut last_error = match self.exec_once(query, variables.clone()).await {
-            Ok(result) => return Ok(result),
-            Err(e) if !Self::is_retryable(&e) => return Err(e),
+        self.cancellable(self.execute_with_retries(query, variables)).await
+    }
+
+    async fn execute_with_retries<T>(&self, query: &str, variables: Option<serde_json::Value>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut attempts = 1;
+        let mut last_error = match self.exec_attempt(query, &variables, attempts).await {
+            Ok(result) => return Ok(self.record_meta(query, result, attempts)),
+            Err(e) if !self.should_retry(&e) => return Err(e),
             Err(e) => e,
         };
 
-        for delay in RETRY_DELAYS.iter() {
-            tokio::time::sleep(*delay).await;
-            match self.exec_once(query, variables.clone()).await {
-                Ok(result) => return Ok(result),
-                Err(e) if Self::is_retryable(&e) => last_error = e,
+        for retry in 0..self.retry_policy.max_retries() {
+            let delay = self.retry_delay(retry);
+            attempts += 1;
+            if let Some(metrics) = &self.metrics {
+                let operation = meta::operation_name(query);
+                let info = RequestInfo {
+                    method: "POST",
+                    endpoint: &self.graphql_url(),
+                    operation: operation.as_deref(),
+                };
+                metrics.request_retried(&info, attempts, delay, &last_error);
+            }
+            self.sleeper.sleep(delay).await;
+            match self.exec_attempt(query, &variables, attempts).await {
+                Ok(result) => return Ok(self.record_meta(query, result, attempts)),
+                Err(e) if self.should_retry(&e) => last_error = e,
                 Err(e) => return Err(e),
             }
         }
@@ -312,12 +971,41 @@ impl ShikicrateClient {
         Err(last_error)
     }
 
+    /// Одна попытка выполнения запроса (со спаном `tracing`, если включена feature).
+    async fn exec_attempt<T>(
+        &self,
+        query: &str,
+        variables: &Option<serde_json::Value>,
+        attempt: u32,
+    ) -> Result<(T, SystemTime, bool)>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let future = self.exec_once(query, variables.clone());
+        #[cfg(feature = "tracing")]
+        let future = crate::instrument::attempt(query, variables.as_ref(), attempt, future);
+        #[cfg(not(feature = "tracing"))]
+        let _ = attempt;
+        future.await
+    }
+
+    fn record_meta<T>(&self, query: &str, (result, fetched_at, from_cache): (T, SystemTime, bool), attempts: u32) -> T {
+        meta::record(ResponseMeta {
+            fetched_at,
+            from_cache,
+            attempts,
+            endpoint: self.graphql_url(),
+            operation: meta::operation_name(query),
+        });
+        result
+    }
+
     pub async fn get_rest<T, Q>(&self, path: &str, query: Option<Q>) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
         Q: serde::Serialize,
     {
-        let url = format!("https://shikimori.io/api/{}", path);
+        let url = self.rest_url(path);
         let query_str = query.as_ref().map_or(String::new(), |q| serde_json::to_string(q).unwrap_or_default());
         let cache_key = CacheKey {
             query: format!("REST:{}", path),
@@ -326,72 +1014,116 @@ impl ShikicrateClient {
 
         // Try cache first for static data
         if path == "genres" || path == "studios" || path == "publishers" {
-            if let Some(cached_data) = self.get_from_cache(&cache_key).await {
-                return serde_json::from_value(cached_data).map_err(ShikicrateError::Serialization);
+            if let Some((cached_data, fetched_at)) = self.get_from_cache(&cache_key).await {
+                meta::record(ResponseMeta {
+                    fetched_at,
+                    from_cache: true,
+                    attempts: 1,
+                    endpoint: url,
+                    operation: None,
+                });
+                return self.decode(cached_data);
             }
         }
 
-        self.wait_for_rate_limit().await;
-
         let mut req = self.client.get(&url);
+        if let Some(token) = &self.access_token {
+            req = req.bearer_auth(token);
+        }
 
         if let Some(q) = query {
             req = req.query(&q);
         }
 
-        let response = req.send().await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            // Extract Retry-After header for rate limiting before consuming response
-            let retry_after = response.headers()
-                .get("Retry-After")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|s| s.parse::<u64>().ok());
-
-            let text = response.text().await?;
-
-            if status.as_u16() == 429 {
-                return Err(ShikicrateError::RateLimit {
-                    message: format!("Too Many Requests: {}", text),
-                    retry_after: retry_after.or(Some(60)), // Default to 60 seconds if not provided
-                });
-            }
-
-            return Err(ShikicrateError::Api {
-                status: status.as_u16(),
-                message: format!("REST HTTP {}: {}", status, text),
-            });
-        }
+        let response = self.send(req, None, "REST HTTP").await?;
 
         let text = response.text().await.map_err(ShikicrateError::Http)?;
         let data: serde_json::Value = serde_json::from_str(&text).map_err(ShikicrateError::Serialization)?;
+        meta::record(ResponseMeta {
+            fetched_at: SystemTime::now(),
+            from_cache: false,
+            attempts: 1,
+            endpoint: url,
+            operation: None,
+        });
 
         // Cache static data
         if path == "genres" || path == "studios" || path == "publishers" {
             self.put_to_cache(cache_key, data.clone(), CACHE_TTL_STATIC).await;
         }
 
-        serde_json::from_value(data).map_err(ShikicrateError::Serialization)
+        self.decode(data)
     }
 
-    pub(crate) fn to_arc(&self) -> Arc<Self> {
-        Arc::new(Self {
-            client: self.client.clone(),
-            base_url: self.base_url.clone(),
-            last_request: Arc::clone(&self.last_request),
-            cache: Arc::clone(&self.cache),
+    fn require_token(&self) -> Result<&str> {
+        self.access_token.as_deref().ok_or_else(|| {
+            ShikicrateError::Auth("Для этого запроса нужен access token (ShikicrateClientBuilder::access_token)".to_string())
         })
     }
+
+    /// Выполняет авторизованный изменяющий запрос (POST/PATCH/DELETE) к REST API Shikimori.
+    ///
+    /// Возвращает JSON ответа (`Null` для пустого тела). После успешного запроса
+    /// из кеша удаляются записи, содержащие `invalidates`.
+    pub(crate) async fn mutate_rest(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+        invalidates: &str,
+    ) -> Result<serde_json::Value> {
+        let token = self.require_token()?;
+        let url = self.rest_url(path);
+
+        let mut request = self.client.request(method, &url).bearer_auth(token);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let text = self.send(request, None, "REST HTTP").await?.text().await?;
+        meta::record(ResponseMeta {
+            fetched_at: SystemTime::now(),
+            from_cache: false,
+            attempts: 1,
+            endpoint: url,
+            operation: None,
+        });
+
+        self.invalidate_cache(invalidates).await;
+
+        if text.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        serde_json::from_str(&text).map_err(ShikicrateError::Serialization)
+    }
+
+    pub(crate) fn to_arc(&self) -> Arc<Self> {
+        Arc::new(self.clone())
+    }
 }
 
 impl Clone for ShikicrateClient {
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            transport: Arc::clone(&self.transport),
             base_url: self.base_url.clone(),
-            last_request: Arc::clone(&self.last_request),
+            mirrors: self.mirrors.clone(),
+            limiter: Arc::clone(&self.limiter),
+            priority: self.priority,
             cache: Arc::clone(&self.cache),
+            cache_control: self.cache_control,
+            cancellation: self.cancellation.clone(),
+            access_token: self.access_token.clone(),
+            warning_handler: self.warning_handler.clone(),
+            body_serializer: Arc::clone(&self.body_serializer),
+            retry_policy: self.retry_policy,
+            retry_predicate: self.retry_predicate.clone(),
+            breaker: self.breaker.clone(),
+            middlewares: self.middlewares.clone(),
+            metrics: self.metrics.clone(),
+            sleeper: Arc::clone(&self.sleeper),
+            absolute_urls: self.absolute_urls,
         }
     }
 }
@@ -0,0 +1,225 @@
+//! Комментарии к топикам и профилям пользователей.
+//!
+//! Комментарии загружаются через REST API Shikimori (`/api/comments`).
+//! Комментарии к аниме, манге и т.д. находятся в их топиках: сначала
+//! найдите топик через `topics()`, затем запросите его комментарии.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::{User, deser_id, deser_opt_id};
+use serde::{Deserialize, Serialize};
+
+/// Максимальный `limit` для `/api/comments`.
+const COMMENTS_MAX_LIMIT: i32 = 30;
+
+/// Тип сущности, к которой относятся комментарии.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CommentableType {
+    /// Топик форума (включая топики аниме, манги, новостей и т.д.).
+    #[default]
+    Topic,
+    /// Профиль пользователя.
+    User,
+}
+
+impl CommentableType {
+    fn as_str(self) -> &'static str {
+        match self {
+            CommentableType::Topic => "Topic",
+            CommentableType::User => "User",
+        }
+    }
+}
+
+/// Параметры загрузки комментариев.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct CommentSearchParams {
+    /// ID топика или пользователя.
+    pub commentable_id: i64,
+    pub commentable_type: CommentableType,
+    pub page: Option<i32>,
+    /// Количество комментариев на странице (не больше 30).
+    pub limit: Option<i32>,
+    /// Сначала новые комментарии.
+    pub desc: Option<bool>,
+}
+
+/// Комментарий.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    /// ID комментария.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// ID топика или пользователя, к которому относится комментарий.
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub commentable_id: Option<i64>,
+
+    /// Тип сущности: `"Topic"` или `"User"`.
+    pub commentable_type: Option<String>,
+
+    /// Текст комментария (BBCode).
+    pub body: Option<String>,
+
+    /// Текст комментария (HTML).
+    pub html_body: Option<String>,
+
+    /// Автор комментария.
+    pub user: Option<User>,
+
+    /// Дата создания.
+    pub created_at: Option<String>,
+
+    /// Дата последнего изменения.
+    pub updated_at: Option<String>,
+
+    /// Комментарий помечен как оффтопик.
+    pub is_offtopic: Option<bool>,
+
+    /// Комментарий помечен как отзыв.
+    pub is_summary: Option<bool>,
+}
+
+crate::types::identity_by_id!(Comment);
+crate::types::utc_accessors!(Comment { created_at_utc => created_at, updated_at_utc => updated_at });
+
+impl Comment {
+    /// Текст комментария без BBCode разметки (см. [`crate::description::to_plain_text`]).
+    pub fn plain_body(&self) -> Option<String> {
+        self.body.as_deref().map(crate::description::to_plain_text)
+    }
+}
+
+impl CommentSearchParams {
+    /// Комментарии к сущности `commentable_id` типа `commentable_type`.
+    pub fn new(commentable_id: i64, commentable_type: CommentableType) -> Self {
+        Self {
+            commentable_id,
+            commentable_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn desc(mut self, desc: bool) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("commentable_id", self.commentable_id.to_string()),
+            ("commentable_type", self.commentable_type.as_str().to_string()),
+        ];
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(desc) = self.desc {
+            query.push(("desc", if desc { "1" } else { "0" }.to_string()));
+        }
+        query
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.commentable_id <= 0 {
+            return Err(ValidationError::new("commentable_id", Constraint::Min(1), "commentable_id должен быть больше 0").value(self.commentable_id).into());
+        }
+        if self.page.is_some_and(|page| page < 1) {
+            return Err(ValidationError::new("page", Constraint::Min(1), "Страница должна быть не меньше 1").into());
+        }
+        if self.limit.is_some_and(|limit| !(1..=COMMENTS_MAX_LIMIT).contains(&limit)) {
+            return Err(ValidationError::new(
+                "limit",
+                Constraint::Range { min: 1, max: COMMENTS_MAX_LIMIT as i64 },
+                format!("Лимит комментариев должен быть от 1 до {}", COMMENTS_MAX_LIMIT),
+            )
+            .value(self.limit.unwrap_or_default())
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl ShikicrateClient {
+    /// Получение комментариев к топику или профилю пользователя.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use shikicrate::comments::{CommentSearchParams, CommentableType};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let comments = client
+    ///     .comments(CommentSearchParams::new(270099, CommentableType::Topic).limit(10))
+    ///     .await?;
+    ///
+    /// for comment in comments {
+    ///     let author = comment.user.as_ref().map_or("?", |u| u.nickname.as_str());
+    ///     println!("{}: {}", author, comment.plain_body().unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn comments(&self, params: CommentSearchParams) -> Result<Vec<Comment>> {
+        params.validate()?;
+        self.get_rest("comments", Some(params.to_query())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use serde_json::json;
+
+    #[test]
+    fn test_comment_params() {
+        let params = CommentSearchParams {
+            commentable_id: 5,
+            commentable_type: CommentableType::User,
+            desc: Some(true),
+            ..Default::default()
+        };
+        assert!(params.validate().is_ok());
+        assert_eq!(
+            params.to_query(),
+            vec![
+                ("commentable_id", "5".to_string()),
+                ("commentable_type", "User".to_string()),
+                ("desc", "1".to_string()),
+            ]
+        );
+
+        assert!(matches!(
+            CommentSearchParams::default().validate(),
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_comment_plain_body() {
+        let comment: Comment = serde_json::from_value(json!({
+            "id": "1",
+            "body": "[b]Отлично[/b] [spoiler]концовка[/spoiler]",
+            "user": { "id": 1, "nickname": "morr" }
+        }))
+        .unwrap();
+
+        assert_eq!(comment.plain_body().as_deref(), Some("Отлично концовка"));
+    }
+}
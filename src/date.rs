@@ -0,0 +1,287 @@
+//! Неполные даты (год, год и месяц или полная дата).
+//!
+//! Shikimori часто знает дату выхода или рождения только частично: например,
+//! «весна 2025» приходит как год без месяца и дня. [`PartialDate`] хранит
+//! известные компоненты, проверяет их корректность, сравнивается по порядку
+//! и форматируется для показа пользователю.
+//!
+//! # Примеры
+//!
+//! ```
+//! use shikicrate::date::{Locale, PartialDate};
+//!
+//! let date = PartialDate::new(Some(2024), Some(1), Some(5)).unwrap();
+//! assert_eq!(date.to_string(), "2024-01-05");
+//! assert_eq!(date.format(Locale::Ru), "5 января 2024");
+//!
+//! let month = PartialDate::new(Some(2024), Some(1), None).unwrap();
+//! assert_eq!(month.format(Locale::En), "January 2024");
+//! assert!(month < date);
+//!
+//! assert!(PartialDate::new(Some(2023), Some(2), Some(29)).is_err());
+//! ```
+
+use crate::error::{Constraint, Result, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use ts_rs::TS;
+
+/// Язык форматирования дат.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    Ru,
+    En,
+}
+
+const MONTHS_RU: [&str; 12] = [
+    "января", "февраля", "марта", "апреля", "мая", "июня", "июля", "августа", "сентября", "октября", "ноября",
+    "декабря",
+];
+
+const MONTHS_RU_NOMINATIVE: [&str; 12] = [
+    "январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь",
+    "декабрь",
+];
+
+const MONTHS_EN: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November",
+    "December",
+];
+
+/// Дата с необязательными компонентами.
+///
+/// Месяц задается только вместе с годом, день — только вместе с месяцем.
+/// Порядок сравнения: по году, месяцу и дню; менее точная дата идет раньше
+/// более точной с теми же компонентами (`2024` < `2024-01` < `2024-01-05`),
+/// а дата без года — раньше всех.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize, TS)]
+#[serde(from = "RawDate", into = "RawDate")]
+#[ts(type = "{ year: number | null, month: number | null, day: number | null, date: string | null }")]
+pub struct PartialDate {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+/// Представление даты в ответах API.
+#[derive(Serialize, Deserialize)]
+struct RawDate {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    date: Option<String>,
+}
+
+fn is_leap(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: Option<i32>, month: u32) -> u32 {
+    match month {
+        2 if year.is_none_or(is_leap) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl PartialDate {
+    /// Создает дату, проверяя компоненты.
+    pub fn new(year: Option<i32>, month: Option<u32>, day: Option<u32>) -> Result<Self> {
+        if month.is_some() && year.is_none() {
+            return Err(ValidationError::new("month", Constraint::Requires("year"), "Месяц даты задан без года").into());
+        }
+        if day.is_some() && month.is_none() {
+            return Err(ValidationError::new("day", Constraint::Requires("month"), "День даты задан без месяца").into());
+        }
+        if let Some(month) = month {
+            if !(1..=12).contains(&month) {
+                return Err(ValidationError::new(
+                    "month",
+                    Constraint::Range { min: 1, max: 12 },
+                    format!("Некорректный месяц: {}", month),
+                )
+                .value(month)
+                .into());
+            }
+            if let Some(day) = day
+                && (day == 0 || day > days_in_month(year, month))
+            {
+                return Err(ValidationError::new(
+                    "day",
+                    Constraint::Range {
+                        min: 1,
+                        max: days_in_month(year, month).into(),
+                    },
+                    format!("Некорректный день: {}", day),
+                )
+                .value(day)
+                .into());
+            }
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Дата из компонентов без ошибок: некорректные компоненты (и все более
+    /// точные после них) отбрасываются.
+    fn lenient(year: Option<i32>, month: Option<u32>, day: Option<u32>) -> Self {
+        let month = month.filter(|month| year.is_some() && (1..=12).contains(month));
+        let day = month.and_then(|month| day.filter(|day| (1..=days_in_month(year, month)).contains(day)));
+        Self { year, month, day }
+    }
+
+    /// Дата только с годом.
+    pub fn from_year(year: i32) -> Self {
+        Self {
+            year: Some(year),
+            month: None,
+            day: None,
+        }
+    }
+
+    pub fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Месяц (1-12).
+    pub fn month(&self) -> Option<u32> {
+        self.month
+    }
+
+    /// День месяца (1-31).
+    pub fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    /// Известны ли год, месяц и день.
+    pub fn is_complete(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// Нет ни одного компонента.
+    pub fn is_empty(&self) -> bool {
+        self.year.is_none()
+    }
+
+    /// Полная дата как `chrono::NaiveDate` (`None`, если дата неполная).
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(self.year?, self.month?, self.day?)
+    }
+
+    /// Дата для показа пользователю: `"5 января 2024"`, `"январь 2024"`, `"2024"`
+    /// (или `"January 5, 2024"`, `"January 2024"` для [`Locale::En`]).
+    ///
+    /// Для пустой даты возвращается пустая строка.
+    pub fn format(&self, locale: Locale) -> String {
+        let Some(year) = self.year else {
+            return String::new();
+        };
+        let Some(month) = self.month else {
+            return year.to_string();
+        };
+        let index = month as usize - 1;
+        match (locale, self.day) {
+            (Locale::Ru, Some(day)) => format!("{} {} {}", day, MONTHS_RU[index], year),
+            (Locale::Ru, None) => format!("{} {}", MONTHS_RU_NOMINATIVE[index], year),
+            (Locale::En, Some(day)) => format!("{} {}, {}", MONTHS_EN[index], day, year),
+            (Locale::En, None) => format!("{} {}", MONTHS_EN[index], year),
+        }
+    }
+}
+
+/// Формат ISO 8601 с известной точностью: `2024-01-05`, `2024-01` или `2024`.
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => write!(f, "{:04}-{:02}-{:02}", year, month, day),
+            (Some(year), Some(month), None) => write!(f, "{:04}-{:02}", year, month),
+            (Some(year), None, _) => write!(f, "{:04}", year),
+            (None, _, _) => Ok(()),
+        }
+    }
+}
+
+/// Разбор ответа API не падает на некорректной дате: неверные компоненты
+/// отбрасываются, чтобы одна такая дата не ломала всю страницу результатов.
+impl From<RawDate> for PartialDate {
+    fn from(raw: RawDate) -> Self {
+        if raw.year.is_none() {
+            // Некоторые ответы содержат только строку `date` (`"2024-01-05"`)
+            if let Some(date) = raw.date.as_deref() {
+                let mut parts = date.get(..10).unwrap_or(date).splitn(3, '-').map(str::parse::<u32>);
+                if let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) = (parts.next(), parts.next(), parts.next()) {
+                    return Self::lenient(Some(year as i32), Some(month), Some(day));
+                }
+            }
+        }
+        Self::lenient(raw.year, raw.month, raw.day)
+    }
+}
+
+impl From<PartialDate> for RawDate {
+    fn from(date: PartialDate) -> Self {
+        Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            date: date.is_complete().then(|| date.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_validation() {
+        assert!(PartialDate::new(Some(2024), Some(2), Some(29)).is_ok());
+        assert!(PartialDate::new(Some(2023), Some(2), Some(29)).is_err());
+        assert!(PartialDate::new(Some(2024), Some(13), None).is_err());
+        assert!(PartialDate::new(Some(2024), None, Some(5)).is_err());
+        assert!(PartialDate::new(None, Some(5), None).is_err());
+        assert!(PartialDate::new(None, None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let date: PartialDate =
+            serde_json::from_value(json!({ "year": 2024, "month": 1, "day": 5, "date": "2024-01-05" })).unwrap();
+        assert_eq!(date, PartialDate::new(Some(2024), Some(1), Some(5)).unwrap());
+        assert_eq!(serde_json::to_value(date).unwrap()["date"], "2024-01-05");
+
+        let date: PartialDate =
+            serde_json::from_value(json!({ "year": null, "month": null, "day": null, "date": "1998-04-03" }))
+                .unwrap();
+        assert!(date.is_complete());
+
+        let date: PartialDate = serde_json::from_value(json!({ "year": 2025, "month": null, "day": null })).unwrap();
+        assert_eq!(date.to_string(), "2025");
+
+        // Некорректные компоненты отбрасываются, а не ломают разбор
+        let date: PartialDate = serde_json::from_value(json!({ "year": 2024, "month": 0, "day": 5 })).unwrap();
+        assert_eq!(date, PartialDate::from_year(2024));
+        let date: PartialDate = serde_json::from_value(json!({ "year": 2023, "month": 2, "day": 29 })).unwrap();
+        assert_eq!(date, PartialDate::new(Some(2023), Some(2), None).unwrap());
+        let date: PartialDate =
+            serde_json::from_value(json!({ "year": null, "month": 13, "day": null, "date": "2023-13-01" })).unwrap();
+        assert_eq!(date, PartialDate::from_year(2023));
+    }
+
+    #[test]
+    fn test_order_and_format() {
+        let year = PartialDate::from_year(2024);
+        let month = PartialDate::new(Some(2024), Some(3), None).unwrap();
+        let day = PartialDate::new(Some(2024), Some(3), Some(8)).unwrap();
+        assert!(PartialDate::default() < year && year < month && month < day);
+        assert!(day < PartialDate::from_year(2025));
+
+        assert_eq!(day.format(Locale::Ru), "8 марта 2024");
+        assert_eq!(month.format(Locale::Ru), "март 2024");
+        assert_eq!(day.format(Locale::En), "March 8, 2024");
+        assert_eq!(year.format(Locale::En), "2024");
+    }
+}
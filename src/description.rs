@@ -0,0 +1,286 @@
+//! Описания с BBCode разметкой Shikimori.
+//!
+//! Поле `description` у тайтлов и персонажей (а также тексты топиков и
+//! комментариев) содержит BBCode: `[b]...[/b]`, `[spoiler]...[/spoiler]`,
+//! ссылки на сущности `[character=123]Спайк[/character]` и т.д. Функции модуля
+//! превращают его в чистый текст или в безопасный HTML, в котором ссылки на
+//! сущности ведут на страницы сайта. Для `description_html` есть
+//! [`html_to_plain_text`].
+//!
+//! # Примеры
+//!
+//! ```
+//! use shikicrate::description::{to_html, to_plain_text, DEFAULT_BASE_URL};
+//!
+//! let bbcode = "[character=1]Спайк[/character] — [b]охотник[/b] за головами";
+//! assert_eq!(to_plain_text(bbcode), "Спайк — охотник за головами");
+//! assert_eq!(
+//!     to_html(bbcode, DEFAULT_BASE_URL),
+//!     "<a href=\"https://shikimori.io/characters/1\">Спайк</a> — <strong>охотник</strong> за головами"
+//! );
+//! ```
+
+/// Адрес сайта для ссылок на сущности по умолчанию.
+pub const DEFAULT_BASE_URL: &str = "https://shikimori.io";
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: &'a str, value: Option<&'a str> },
+    Close(&'a str),
+}
+
+/// Имя BBCode тега: латиница в нижнем регистре, цифры и `_`.
+fn is_tag_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.len() <= 20
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Разбивает текст на теги и текст. Скобки, не похожие на тег, остаются текстом,
+/// а вики-ссылки `[[Название]]` заменяются названием.
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = input;
+    while let Some(start) = rest.find('[') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(inner) = rest.strip_prefix("[[")
+            && let Some(end) = inner.find("]]")
+        {
+            tokens.push(Token::Text(&inner[..end]));
+            rest = &inner[end + 2..];
+            continue;
+        }
+
+        let token = rest[1..].find(']').and_then(|end| {
+            let tag = &rest[1..end + 1];
+            let token = match tag.strip_prefix('/') {
+                Some(name) => is_tag_name(name).then_some(Token::Close(name)),
+                None => match tag.split_once('=') {
+                    Some((name, value)) => is_tag_name(name).then_some(Token::Open {
+                        name,
+                        value: Some(value),
+                    }),
+                    None => is_tag_name(tag).then_some(Token::Open { name: tag, value: None }),
+                },
+            };
+            Some((token?, end + 2))
+        });
+        match token {
+            Some((token, len)) => {
+                tokens.push(token);
+                rest = &rest[len..];
+            }
+            None => {
+                tokens.push(Token::Text("["));
+                rest = &rest[1..];
+            }
+        }
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+/// Текст без BBCode разметки: теги удаляются, их содержимое остается,
+/// `[br]` заменяется переводом строки.
+pub fn to_plain_text(bbcode: &str) -> String {
+    let mut plain = String::with_capacity(bbcode.len());
+    for token in tokenize(bbcode) {
+        match token {
+            Token::Text(text) => plain.push_str(text),
+            Token::Open { name: "br", .. } => plain.push('\n'),
+            _ => {}
+        }
+    }
+    plain.trim().to_string()
+}
+
+/// Текст из HTML (например, `description_html`): теги удаляются, `<br>` и
+/// концы абзацев заменяются переводом строки, основные сущности
+/// (`&amp;`, `&lt;`, `&quot;` и т.д.) раскодируются.
+pub fn html_to_plain_text(html: &str) -> String {
+    let mut plain = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        plain.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_ascii_lowercase();
+        let name = tag.split(|c: char| c.is_whitespace() || c == '/').find(|part| !part.is_empty());
+        if matches!(name, Some("br")) || matches!(tag.as_str(), "/p" | "/div" | "/li") {
+            plain.push('\n');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    plain.push_str(rest);
+    decode_entities(&plain).trim().to_string()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Раздел сайта для тега ссылки на сущность.
+fn entity_path(name: &str) -> Option<&'static str> {
+    match name {
+        "anime" => Some("animes"),
+        "manga" => Some("mangas"),
+        "ranobe" => Some("ranobe"),
+        "character" => Some("characters"),
+        "person" | "people" => Some("people"),
+        "user" => Some("users"),
+        _ => None,
+    }
+}
+
+/// Безопасный адрес ссылки: абсолютный `http(s)` или путь на сайте.
+fn link_target(url: &str, base_url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Some(url.to_string())
+    } else if url.starts_with('/') && !url.starts_with("//") {
+        Some(format!("{}{}", base_url.trim_end_matches('/'), url))
+    } else {
+        None
+    }
+}
+
+/// HTML из BBCode.
+///
+/// Весь текст экранируется, а в HTML превращаются только известные теги
+/// (`b`, `i`, `u`, `s`, `quote`, `spoiler`, `url`, `br` и ссылки на сущности),
+/// поэтому результат можно вставлять в страницу как есть. Ссылки на сущности
+/// (`[anime=1]`, `[character=1]`, `[person=1]` и т.д.) ведут на страницы сайта
+/// `base_url`, а `[url]` принимает только `http(s)` адреса и пути на сайте.
+/// Незакрытые теги закрываются в конце текста.
+pub fn to_html(bbcode: &str, base_url: &str) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    let mut html = String::with_capacity(bbcode.len());
+    // Открытые теги: имя BBCode тега и закрывающий HTML
+    let mut open: Vec<(&str, &str)> = Vec::new();
+
+    for token in tokenize(bbcode) {
+        match token {
+            Token::Text(text) => html.push_str(&escape(text).replace('\n', "<br>")),
+            Token::Open { name: "br", .. } => html.push_str("<br>"),
+            Token::Open { name, value } => {
+                let closing = match name {
+                    "b" => Some(("<strong>".to_string(), "</strong>")),
+                    "i" => Some(("<em>".to_string(), "</em>")),
+                    "u" => Some(("<u>".to_string(), "</u>")),
+                    "s" => Some(("<del>".to_string(), "</del>")),
+                    "quote" => Some(("<blockquote>".to_string(), "</blockquote>")),
+                    "spoiler" => {
+                        let title = value.filter(|title| !title.trim().is_empty()).unwrap_or("спойлер");
+                        Some((format!("<details><summary>{}</summary>", escape(title)), "</details>"))
+                    }
+                    "url" => value
+                        .and_then(|url| link_target(url, base_url))
+                        .map(|url| (format!("<a href=\"{}\">", escape(&url)), "</a>")),
+                    _ => entity_path(name).and_then(|path| {
+                        let id = value?.split(|c: char| !c.is_ascii_digit()).next()?;
+                        (!id.is_empty()).then(|| (format!("<a href=\"{}/{}/{}\">", base_url, path, id), "</a>"))
+                    }),
+                };
+                if let Some((opening, closing)) = closing {
+                    html.push_str(&opening);
+                    open.push((name, closing));
+                }
+            }
+            Token::Close(name) => {
+                if let Some(index) = open.iter().rposition(|(open_name, _)| *open_name == name) {
+                    for (_, closing) in open.drain(index..).rev() {
+                        html.push_str(closing);
+                    }
+                }
+            }
+        }
+    }
+    for (_, closing) in open.into_iter().rev() {
+        html.push_str(closing);
+    }
+    html.trim().to_string()
+}
+
+/// Чистый текст описания: из `description`, а если его нет — из `description_html`.
+pub(crate) fn plain_description(description: Option<&str>, description_html: Option<&str>) -> Option<String> {
+    description
+        .map(to_plain_text)
+        .filter(|text| !text.is_empty())
+        .or_else(|| description_html.map(html_to_plain_text))
+        .filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_plain_text() {
+        assert_eq!(
+            to_plain_text("[anime=1]Cowboy Bebop[/anime][br][spoiler=концовка]Спайк[/spoiler] [[Вики]] [1]"),
+            "Cowboy Bebop\nСпайк Вики [1]"
+        );
+        assert_eq!(to_plain_text("a [b]b[/b"), "a b[/b");
+    }
+
+    #[test]
+    fn test_html_to_plain_text() {
+        assert_eq!(
+            html_to_plain_text("<div class=\"b-text_with_paragraphs\">Tom &amp; Jerry<br>x &lt; y</div>"),
+            "Tom & Jerry\nx < y"
+        );
+    }
+
+    #[test]
+    fn test_to_html() {
+        assert_eq!(
+            to_html("[person=5]Ватанабэ[/person] <script>\n[url=javascript:alert(1)]x[/url]", "https://shikimori.one/"),
+            "<a href=\"https://shikimori.one/people/5\">Ватанабэ</a> &lt;script&gt;<br>x"
+        );
+        assert_eq!(
+            to_html("[spoiler][b]концовка[/spoiler] [i]курсив", DEFAULT_BASE_URL),
+            "<details><summary>спойлер</summary><strong>концовка</strong></details> <em>курсив</em>"
+        );
+        assert_eq!(
+            to_html("[url=/animes/1]\"Bebop\"[/url][/b]", DEFAULT_BASE_URL),
+            "<a href=\"https://shikimori.io/animes/1\">&quot;Bebop&quot;</a>"
+        );
+    }
+
+    #[test]
+    fn test_plain_description() {
+        assert_eq!(plain_description(Some("[b]a[/b]"), Some("b")).as_deref(), Some("a"));
+        assert_eq!(plain_description(Some(""), Some("<p>b</p>")).as_deref(), Some("b"));
+        assert_eq!(plain_description(None, None), None);
+    }
+}
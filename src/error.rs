@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Ошибки, которые могут возникнуть при работе с Shikimori GraphQL API.
@@ -15,16 +16,11 @@ use thiserror::Error;
 ///     let client = ShikicrateClient::new()?;
 ///     
 ///     // Ошибка валидации
-///     let params = AnimeSearchParams {
-///         search: None,
-///         limit: Some(-1), // Невалидное значение
-///         kind: None,
-///         page: None,
-///     };
+///     let params = AnimeSearchParams::builder().limit(-1); // Невалидное значение
 ///     
 ///     match client.animes(params).await {
-///         Err(shikicrate::ShikicrateError::Validation(msg)) => {
-///             eprintln!("Ошибка валидации: {}", msg);
+///         Err(shikicrate::ShikicrateError::Validation(error)) => {
+///             eprintln!("Ошибка валидации поля {}: {}", error.field, error);
 ///         }
 ///         Ok(animes) => println!("Найдено {} аниме", animes.len()),
 ///         Err(e) => eprintln!("Другая ошибка: {}", e),
@@ -59,12 +55,17 @@ pub enum ShikicrateError {
     /// - Невалидный GraphQL запрос
     /// - Ошибка валидации на стороне сервера
     /// - Отсутствие данных в ответе
+    ///
+    /// По `details` можно отличить, например, ошибку авторизации от
+    /// некорректного аргумента (см. [`GraphQLErrorDetail::code`]).
     #[error("GraphQL error: {message}")]
     GraphQL {
         /// Сообщение об ошибке.
         message: String,
         /// Полный массив ошибок GraphQL (для отладки).
         errors: Option<serde_json::Value>,
+        /// Разобранные ошибки из `errors` (пусто, если ошибок в ответе нет).
+        details: Vec<GraphQLErrorDetail>,
     },
 
     /// Ошибка сериализации/десериализации JSON.
@@ -88,12 +89,19 @@ pub enum ShikicrateError {
     /// - 404 Not Found
     /// - 500 Internal Server Error
     /// - 429 Too Many Requests (rate limiting)
+    ///
+    /// `headers` и `operation` помогают найти запрос в логах сервера и
+    /// приложить его к отчету об ошибке.
     #[error("API error (status {status}): {message}")]
     Api {
         /// HTTP статус код.
         status: u16,
         /// Сообщение об ошибке.
         message: String,
+        /// Заголовки ответа, полезные для отладки.
+        headers: Box<ResponseHeaders>,
+        /// Имя GraphQL операции (для REST запросов — `None`).
+        operation: Option<String>,
     },
 
     /// Ошибка rate limiting (429 Too Many Requests).
@@ -112,13 +120,209 @@ pub enum ShikicrateError {
     ///
     /// Возникает при попытке выполнить запрос с невалидными параметрами
     /// (например, отрицательный `limit`, `page` меньше 1, пустой `ids`).
+    /// [`ValidationError`] содержит имя параметра, значение и нарушенное
+    /// ограничение, чтобы сопоставить ошибку с полем формы.
     ///
     /// # Примеры ситуаций
     /// - `limit <= 0`
     /// - `page < 1`
     /// - Пустой вектор `ids`
     #[error("Validation error: {0}")]
-    Validation(String),
+    Validation(#[from] ValidationError),
+
+    /// Ошибка авторизации.
+    ///
+    /// Возникает при попытке выполнить запрос, требующий OAuth2 токена,
+    /// на клиенте без настроенного `access_token`.
+    ///
+    /// # Примеры ситуаций
+    /// - Удаление пользовательской оценки без токена
+    #[error("Authorization error: {0}")]
+    Auth(String),
+
+    /// Ошибка ввода-вывода.
+    ///
+    /// Возникает при работе с локальными файлами (хранилище токенов и т.д.).
+    ///
+    /// Автоматически конвертируется из `std::io::Error`.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Запрос отклонен circuit breaker без обращения к серверу.
+    ///
+    /// Возникает, когда включен [`CircuitBreaker`](crate::circuit::CircuitBreaker)
+    /// и несколько запросов подряд завершились сетевой ошибкой, 429 или 5xx.
+    #[error("Circuit breaker open, retry in {retry_in:?}")]
+    CircuitOpen {
+        /// Время до пробного запроса.
+        retry_in: std::time::Duration,
+    },
+
+    /// Запрос отменен через [`CancellationToken`](crate::cancel::CancellationToken).
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// Пустой результат там, где он считается ошибкой.
+    ///
+    /// Возникает при явном запросе через [`NonEmptyExt`], а также когда в
+    /// ответе нет обязательных данных.
+    ///
+    /// # Примеры ситуаций
+    /// - Запрос по заведомо существующим ID вернул пустой список
+    /// - В профиле пользователя нет статистики (`user_stats`)
+    #[error("Empty result: {context}")]
+    EmptyResult {
+        /// Описание запроса, вернувшего пустой результат.
+        context: String,
+    },
+}
+
+/// Ошибка из поля `errors` GraphQL ответа.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct GraphQLErrorDetail {
+    /// Сообщение об ошибке.
+    #[serde(default)]
+    pub message: String,
+    /// Код ошибки из `extensions.code` (например, `"UNAUTHENTICATED"`
+    /// или `"argumentLiteralsIncompatible"`).
+    #[serde(skip)]
+    pub code: Option<String>,
+    /// Путь к полю ответа, в котором возникла ошибка.
+    #[serde(default)]
+    pub path: Vec<GraphQLPathSegment>,
+    /// Позиции в тексте запроса.
+    #[serde(default)]
+    pub locations: Vec<GraphQLLocation>,
+}
+
+/// Элемент пути к полю: имя поля или индекс в списке.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum GraphQLPathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// Позиция в тексте GraphQL запроса (строки и столбцы с 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct GraphQLLocation {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl GraphQLErrorDetail {
+    /// Разбирает массив `errors` ответа. Элементы неизвестного формата
+    /// пропускаются.
+    pub(crate) fn parse_all(errors: &serde_json::Value) -> Vec<Self> {
+        let Some(errors) = errors.as_array() else {
+            return Vec::new();
+        };
+        errors
+            .iter()
+            .filter_map(|error| {
+                let mut detail: Self = serde_json::from_value(error.clone()).ok()?;
+                detail.code = error
+                    .pointer("/extensions/code")
+                    .and_then(|code| code.as_str())
+                    .map(str::to_string);
+                Some(detail)
+            })
+            .collect()
+    }
+}
+
+/// Заголовки неуспешного ответа, по которым запрос можно найти на стороне сервера.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseHeaders {
+    /// Идентификатор запроса (`X-Request-Id`).
+    pub request_id: Option<String>,
+    /// Идентификатор запроса в Cloudflare (`CF-Ray`).
+    pub cf_ray: Option<String>,
+    /// Тип содержимого ответа (`Content-Type`): HTML вместо JSON обычно
+    /// означает страницу ошибки прокси.
+    pub content_type: Option<String>,
+}
+
+impl ResponseHeaders {
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        Self {
+            request_id: get("x-request-id"),
+            cf_ray: get("cf-ray"),
+            content_type: get("content-type"),
+        }
+    }
+}
+
+/// Ограничение, которому не соответствует параметр.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Constraint {
+    /// Значение должно быть не меньше `min`.
+    Min(i64),
+    /// Значение должно быть в диапазоне `min..=max`.
+    Range { min: i64, max: i64 },
+    /// Значение не должно быть пустым.
+    NotEmpty,
+    /// Значение должно быть одним из перечисленных.
+    OneOf(Vec<String>),
+    /// Некорректный формат значения.
+    Format,
+    /// Параметр задается только вместе с указанным.
+    Requires(&'static str),
+    /// Параметр нельзя задать вместе с указанным.
+    ConflictsWith(&'static str),
+    /// Другое ограничение (описано в сообщении).
+    Other,
+}
+
+/// Описание ошибки валидации.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{Constraint, ShikicrateClient, ShikicrateError, queries::*};
+///
+/// # async fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClient::new()?;
+/// match client.animes(AnimeSearchParams::builder().limit(0)).await {
+///     Err(ShikicrateError::Validation(error)) if error.field == "limit" => {
+///         assert_eq!(error.constraint, Constraint::Min(1));
+///         assert_eq!(error.value.as_deref(), Some("0"));
+///     }
+///     _ => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct ValidationError {
+    /// Имя параметра (`"limit"`, `"page"`, `"ids"` и т.д.).
+    pub field: &'static str,
+    /// Переданное значение, если его можно показать.
+    pub value: Option<String>,
+    /// Нарушенное ограничение.
+    pub constraint: Constraint,
+    /// Сообщение для пользователя.
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: &'static str, constraint: Constraint, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            value: None,
+            constraint,
+            message: message.into(),
+        }
+    }
+
+    /// Добавляет переданное значение.
+    pub fn value(mut self, value: impl ToString) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
 }
 
 /// Тип-алиас для `Result<T, ShikicrateError>`.
@@ -136,3 +340,100 @@ pub enum ShikicrateError {
 /// }
 /// ```
 pub type Result<T> = std::result::Result<T, ShikicrateError>;
+
+/// Превращает пустой результат запроса в ошибку [`ShikicrateError::EmptyResult`].
+///
+/// Полезно в конвейерах, где пустой ответ означает ошибку (например, при
+/// разрешении заведомо существующих ID), чтобы сбой проявлялся сразу, а не
+/// пропуском данных дальше по цепочке.
+///
+/// # Пример
+///
+/// ```no_run
+/// use shikicrate::{NonEmptyExt, Result, ShikicrateClient};
+///
+/// async fn resolve(client: &ShikicrateClient) -> Result<()> {
+///     let anime = client.anime_by_id(1).await.non_empty("anime_by_id(1)")?;
+///     println!("{}", anime.name);
+///     Ok(())
+/// }
+/// ```
+pub trait NonEmptyExt {
+    /// Значение успешного непустого результата.
+    type Output;
+
+    /// Возвращает ошибку `EmptyResult` с контекстом `context`, если результат пуст.
+    fn non_empty(self, context: impl Into<String>) -> Result<Self::Output>;
+}
+
+impl<T> NonEmptyExt for Result<Vec<T>> {
+    type Output = Vec<T>;
+
+    fn non_empty(self, context: impl Into<String>) -> Result<Vec<T>> {
+        match self {
+            Ok(items) if items.is_empty() => Err(ShikicrateError::EmptyResult { context: context.into() }),
+            other => other,
+        }
+    }
+}
+
+impl<T> NonEmptyExt for Result<Option<T>> {
+    type Output = T;
+
+    fn non_empty(self, context: impl Into<String>) -> Result<T> {
+        self?.ok_or_else(|| ShikicrateError::EmptyResult { context: context.into() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_empty() {
+        let empty: Result<Vec<i32>> = Ok(Vec::new());
+        assert!(matches!(
+            empty.non_empty("animes ids=1"),
+            Err(ShikicrateError::EmptyResult { context }) if context == "animes ids=1"
+        ));
+
+        let items: Result<Vec<i32>> = Ok(vec![1]);
+        assert_eq!(items.non_empty("animes").unwrap(), vec![1]);
+
+        let missing: Result<Option<i32>> = Ok(None);
+        assert!(matches!(missing.non_empty("anime_by_id"), Err(ShikicrateError::EmptyResult { .. })));
+
+        let failed: Result<Option<i32>> = Err(ValidationError::new("id", Constraint::Min(1), "x").into());
+        assert!(matches!(failed.non_empty("anime_by_id"), Err(ShikicrateError::Validation(_))));
+    }
+
+    #[test]
+    fn test_graphql_error_details() {
+        let errors = serde_json::json!([
+            {
+                "message": "Argument 'limit' on Field 'animes' has an invalid value",
+                "locations": [{ "line": 2, "column": 3 }],
+                "path": ["query", "animes", 0],
+                "extensions": { "code": "argumentLiteralsIncompatible" }
+            },
+            { "message": "Unauthorized" },
+            "not an error object"
+        ]);
+
+        let details = GraphQLErrorDetail::parse_all(&errors);
+        assert_eq!(details.len(), 2);
+        assert_eq!(details[0].code.as_deref(), Some("argumentLiteralsIncompatible"));
+        assert_eq!(details[0].locations, vec![GraphQLLocation { line: 2, column: 3 }]);
+        assert_eq!(
+            details[0].path,
+            vec![
+                GraphQLPathSegment::Field("query".to_string()),
+                GraphQLPathSegment::Field("animes".to_string()),
+                GraphQLPathSegment::Index(0),
+            ]
+        );
+        assert_eq!(details[1].message, "Unauthorized");
+        assert_eq!(details[1].code, None);
+        assert!(GraphQLErrorDetail::parse_all(&serde_json::Value::Null).is_empty());
+    }
+}
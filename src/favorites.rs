@@ -0,0 +1,164 @@
+//! Избранное пользователя.
+//!
+//! Чтение избранного доступно без авторизации, добавление и удаление требуют
+//! клиента с `access_token` (см. [`ShikicrateClientBuilder::access_token`](crate::ShikicrateClientBuilder::access_token)).
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::EntryRef;
+use serde::{Deserialize, Serialize};
+
+/// Тип избранной сущности.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FavoriteType {
+    Anime,
+    Manga,
+    Ranobe,
+    Character,
+    Person,
+}
+
+impl FavoriteType {
+    fn as_str(self) -> &'static str {
+        match self {
+            FavoriteType::Anime => "Anime",
+            FavoriteType::Manga => "Manga",
+            FavoriteType::Ranobe => "Ranobe",
+            FavoriteType::Character => "Character",
+            FavoriteType::Person => "Person",
+        }
+    }
+}
+
+/// Избранное пользователя, сгруппированное по типам.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Favorites {
+    pub animes: Vec<EntryRef>,
+    pub mangas: Vec<EntryRef>,
+    pub ranobe: Vec<EntryRef>,
+    pub characters: Vec<EntryRef>,
+    /// Люди, добавленные без уточнения роли.
+    pub people: Vec<EntryRef>,
+    pub mangakas: Vec<EntryRef>,
+    pub seyu: Vec<EntryRef>,
+    pub producers: Vec<EntryRef>,
+}
+
+impl Favorites {
+    /// Проверяет, есть ли сущность в избранном.
+    pub fn contains(&self, favorite_type: FavoriteType, id: i64) -> bool {
+        let lists: &[&Vec<EntryRef>] = match favorite_type {
+            FavoriteType::Anime => &[&self.animes],
+            FavoriteType::Manga => &[&self.mangas],
+            FavoriteType::Ranobe => &[&self.ranobe],
+            FavoriteType::Character => &[&self.characters],
+            FavoriteType::Person => &[&self.people, &self.mangakas, &self.seyu, &self.producers],
+        };
+        lists.iter().any(|list| list.iter().any(|entry| entry.id == id))
+    }
+}
+
+impl ShikicrateClient {
+    /// Получение избранного пользователя.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let favorites = client.favorites(1).await?;
+    ///
+    /// for anime in &favorites.animes {
+    ///     println!("{}", anime.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorites(&self, user_id: i64) -> Result<Favorites> {
+        if user_id <= 0 {
+            return Err(ValidationError::new("user_id", Constraint::Min(1), "ID пользователя должен быть больше 0").value(user_id).into());
+        }
+        self.get_rest(&format!("users/{}/favourites", user_id), None::<serde_json::Value>)
+            .await
+    }
+
+    /// Добавляет сущность в избранное текущего пользователя.
+    ///
+    /// Для людей `kind` задает роль (`"seyu"`, `"mangaka"`, `"producer"`,
+    /// `"person"`); для остальных типов игнорируется. Требует `access_token`.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClientBuilder;
+    /// use shikicrate::favorites::FavoriteType;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .access_token("token".to_string())
+    ///     .build()?;
+    ///
+    /// client.add_favorite(FavoriteType::Anime, 1, None).await?;
+    /// client.add_favorite(FavoriteType::Person, 7, Some("seyu")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite(&self, favorite_type: FavoriteType, id: i64, kind: Option<&str>) -> Result<()> {
+        let path = match (favorite_type, kind) {
+            (FavoriteType::Person, Some(kind)) => format!("{}/{}", Self::favorite_path(favorite_type, id)?, kind),
+            _ => Self::favorite_path(favorite_type, id)?,
+        };
+        self.mutate_rest(reqwest::Method::POST, &path, None, "favourites").await?;
+        Ok(())
+    }
+
+    /// Удаляет сущность из избранного текущего пользователя. Требует `access_token`.
+    pub async fn remove_favorite(&self, favorite_type: FavoriteType, id: i64) -> Result<()> {
+        let path = Self::favorite_path(favorite_type, id)?;
+        self.mutate_rest(reqwest::Method::DELETE, &path, None, "favourites").await?;
+        Ok(())
+    }
+
+    fn favorite_path(favorite_type: FavoriteType, id: i64) -> Result<String> {
+        if id <= 0 {
+            return Err(ValidationError::new("id", Constraint::Min(1), "ID должен быть больше 0").value(id).into());
+        }
+        Ok(format!("favorites/{}/{}", favorite_type.as_str(), id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use serde_json::json;
+
+    #[test]
+    fn test_favorites_contains() {
+        let favorites: Favorites = serde_json::from_value(json!({
+            "animes": [{ "id": 1, "name": "Cowboy Bebop" }],
+            "seyu": [{ "id": 7, "name": "Hanazawa Kana" }]
+        }))
+        .unwrap();
+
+        assert!(favorites.contains(FavoriteType::Anime, 1));
+        assert!(favorites.contains(FavoriteType::Person, 7));
+        assert!(!favorites.contains(FavoriteType::Character, 7));
+    }
+
+    #[tokio::test]
+    async fn test_add_favorite_requires_token() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(
+            client.add_favorite(FavoriteType::Anime, 1, None).await,
+            Err(ShikicrateError::Auth(_))
+        ));
+        assert!(matches!(
+            client.remove_favorite(FavoriteType::Anime, 0).await,
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+}
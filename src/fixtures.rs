@@ -0,0 +1,301 @@
+//! Готовые данные для тестов (feature `test-util`).
+//!
+//! Конструкторы `fixture()` возвращают правдоподобно заполненные структуры,
+//! чтобы в тестах зависимых крейтов не приходилось перечислять десятки полей
+//! вручную. Нужные поля можно поменять после создания.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::types::{Anime, UserRate};
+//!
+//! let mut anime = Anime::fixture();
+//! anime.score = Some(9.1);
+//!
+//! let rate = UserRate::fixture();
+//! assert_eq!(rate.anime.as_ref().map(|a| a.id), Some(anime.id));
+//! ```
+
+use crate::types::{
+    AgeRating, Anime, AnimeStatus, Character, CharacterFull, Genre, Manga, PartialDate, Person, PersonFull, Poster,
+    Publisher, Studio, User, UserRate, WatchStatus,
+};
+
+fn date(year: i32, month: u32, day: u32) -> Option<PartialDate> {
+    PartialDate::new(Some(year), Some(month), Some(day)).ok()
+}
+
+fn poster(kind: &str, id: i64) -> Option<Poster> {
+    let url = |size: &str| Some(format!("https://shikimori.one/uploads/poster/{}/{}/{}.jpeg", kind, id, size));
+    Some(Poster {
+        id: Some(id),
+        original_url: url("original"),
+        main_url: url("main"),
+        preview_url: url("preview"),
+        x96_url: url("x96"),
+        x48_url: url("x48"),
+    })
+}
+
+fn genre(id: i64, name: &str, russian: &str) -> Genre {
+    Genre {
+        id,
+        name: name.to_string(),
+        russian: Some(russian.to_string()),
+        kind: Some("genre".to_string()),
+    }
+}
+
+impl Anime {
+    /// Cowboy Bebop (ID 1): вышедший TV сериал на 26 эпизодов.
+    pub fn fixture() -> Self {
+        Self {
+            id: 1,
+            mal_id: Some(1),
+            name: "Cowboy Bebop".to_string(),
+            russian: Some("Ковбой Бибоп".to_string()),
+            license_name_ru: None,
+            english: Some("Cowboy Bebop".to_string()),
+            japanese: Some("カウボーイビバップ".to_string()),
+            synonyms: Some(Vec::new()),
+            kind: Some("tv".to_string()),
+            rating: Some(AgeRating::R),
+            score: Some(8.75),
+            status: Some(AnimeStatus::Released),
+            episodes: Some(26),
+            episodes_aired: Some(26),
+            duration: Some(24),
+            aired_on: date(1998, 4, 3),
+            released_on: date(1999, 4, 24),
+            url: Some("https://shikimori.one/animes/1-cowboy-bebop".to_string()),
+            season: Some("spring_1998".to_string()),
+            poster: poster("animes", 1),
+            fansubbers: Some(Vec::new()),
+            fandubbers: Some(Vec::new()),
+            licensors: Some(Vec::new()),
+            created_at: Some("2011-03-08T00:00:00+03:00".to_string()),
+            updated_at: Some("2024-01-01T00:00:00+03:00".to_string()),
+            next_episode_at: None,
+            is_censored: Some(false),
+            genres: Some(vec![genre(1, "Action", "Экшен"), genre(24, "Sci-Fi", "Фантастика")]),
+            studios: Some(vec![Studio {
+                id: 14,
+                name: "Sunrise".to_string(),
+                image_url: None,
+            }]),
+            external_links: None,
+            person_roles: None,
+            character_roles: None,
+            related: None,
+            videos: None,
+            screenshots: None,
+            scores_stats: None,
+            statuses_stats: None,
+            description: Some("Космический вестерн об охотниках за головами.".to_string()),
+            description_html: Some("<p>Космический вестерн об охотниках за головами.</p>".to_string()),
+            description_source: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Manga {
+    /// Berserk (ID 2): онгоинг манга.
+    pub fn fixture() -> Self {
+        Self {
+            id: 2,
+            mal_id: Some(2),
+            name: "Berserk".to_string(),
+            russian: Some("Берсерк".to_string()),
+            license_name_ru: None,
+            english: Some("Berserk".to_string()),
+            japanese: Some("ベルセルク".to_string()),
+            synonyms: Some(Vec::new()),
+            kind: Some("manga".to_string()),
+            score: Some(9.47),
+            status: Some("ongoing".to_string()),
+            volumes: Some(0),
+            chapters: Some(0),
+            aired_on: date(1989, 8, 25),
+            released_on: None,
+            url: Some("https://shikimori.one/mangas/2-berserk".to_string()),
+            poster: poster("mangas", 2),
+            licensors: Some(Vec::new()),
+            created_at: Some("2011-03-08T00:00:00+03:00".to_string()),
+            updated_at: Some("2024-01-01T00:00:00+03:00".to_string()),
+            is_censored: Some(false),
+            genres: Some(vec![genre(1, "Action", "Экшен"), genre(14, "Horror", "Ужасы")]),
+            publishers: Some(vec![Publisher {
+                id: 2,
+                name: "Young Animal".to_string(),
+            }]),
+            external_links: None,
+            person_roles: None,
+            character_roles: None,
+            related: None,
+            scores_stats: None,
+            statuses_stats: None,
+            description: Some("Путь мечника Гатса.".to_string()),
+            description_html: Some("<p>Путь мечника Гатса.</p>".to_string()),
+            description_source: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Character {
+    /// Спайк Шпигель (ID 1).
+    pub fn fixture() -> Self {
+        Self {
+            id: 1,
+            name: "Spike Spiegel".to_string(),
+            russian: Some("Спайк Шпигель".to_string()),
+            poster: poster("characters", 1),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl CharacterFull {
+    /// Спайк Шпигель (ID 1) из [`Anime::fixture`].
+    pub fn fixture() -> Self {
+        let character = Character::fixture();
+        Self {
+            id: character.id,
+            mal_id: Some(1),
+            name: character.name,
+            russian: character.russian,
+            japanese: Some("スパイク・スピーゲル".to_string()),
+            synonyms: Some(Vec::new()),
+            url: Some("https://shikimori.one/characters/1-spike-spiegel".to_string()),
+            created_at: None,
+            updated_at: None,
+            is_anime: Some(true),
+            is_manga: Some(true),
+            is_ranobe: Some(false),
+            poster: character.poster,
+            description: None,
+            description_html: None,
+            description_source: None,
+            seyu: Some(vec![Person::fixture()]),
+            animes: Some(vec![Anime::fixture()]),
+            mangas: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Person {
+    /// Коити Ямадэра (ID 1), сейю.
+    pub fn fixture() -> Self {
+        Self {
+            id: 1,
+            name: "Kouichi Yamadera".to_string(),
+            russian: Some("Коити Ямадэра".to_string()),
+            poster: poster("people", 1),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl PersonFull {
+    /// Коити Ямадэра (ID 1), сейю.
+    pub fn fixture() -> Self {
+        let person = Person::fixture();
+        Self {
+            id: person.id,
+            mal_id: Some(1),
+            name: person.name,
+            russian: person.russian,
+            japanese: Some("山寺宏一".to_string()),
+            synonyms: Some(Vec::new()),
+            url: Some("https://shikimori.one/people/1-kouichi-yamadera".to_string()),
+            is_seyu: Some(true),
+            is_mangaka: Some(false),
+            is_producer: Some(false),
+            website: None,
+            created_at: None,
+            updated_at: None,
+            birth_on: date(1961, 6, 17),
+            deceased_on: None,
+            poster: person.poster,
+            works: None,
+            roles: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl User {
+    /// Пользователь `fixture_user` (ID 1) без статистики профиля.
+    pub fn fixture() -> Self {
+        Self {
+            id: 1,
+            nickname: "fixture_user".to_string(),
+            avatar_url: Some("https://shikimori.one/system/users/x160/1.png".to_string()),
+            last_online_at: Some("2024-01-01T00:00:00+03:00".to_string()),
+            url: Some("https://shikimori.one/fixture_user".to_string()),
+            name: None,
+            sex: None,
+            full_years: None,
+            website: None,
+            locale: Some("ru".to_string()),
+            last_online: None,
+            common_info: None,
+            stats: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl UserRate {
+    /// Оценка 9 для [`Anime::fixture`]: статус [`WatchStatus::Watching`], 12 эпизодов из 26.
+    pub fn fixture() -> Self {
+        let anime = Anime::fixture();
+        Self {
+            id: 1,
+            score: Some(9.0),
+            status: WatchStatus::Watching,
+            episodes: Some(12),
+            volumes: Some(0),
+            chapters: Some(0),
+            rewatches: Some(0),
+            text: None,
+            target_id: Some(anime.id),
+            target_type: Some("Anime".to_string()),
+            anime: Some(anime),
+            manga: None,
+            created_at: Some("2024-01-01T00:00:00+03:00".to_string()),
+            updated_at: Some("2024-01-02T00:00:00+03:00".to_string()),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixture должна проходить через сериализацию и обратно, как ответ API.
+    fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: T) -> T {
+        serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_fixtures_round_trip() {
+        assert_eq!(round_trip(Anime::fixture()).name, "Cowboy Bebop");
+        assert_eq!(round_trip(Manga::fixture()).id, 2);
+        assert_eq!(round_trip(CharacterFull::fixture()).seyu.unwrap()[0].id, 1);
+        assert!(round_trip(PersonFull::fixture()).is_seyu.unwrap());
+        assert_eq!(round_trip(User::fixture()).nickname, "fixture_user");
+        assert_eq!(round_trip(UserRate::fixture()).anime.unwrap().episodes, Some(26));
+    }
+}
@@ -0,0 +1,377 @@
+//! Граф библиотеки пользователя.
+//!
+//! Оцененные пользователем тайтлы связываются ребрами по связям (сиквелы,
+//! приквелы и т.д.), франшизам и студиям/издательствам. Граф можно выгрузить в
+//! формате DOT (Graphviz) или JSON для визуализаций вроде «карты моего вкуса».
+//!
+//! Тайтлы загружаются пакетными запросами по ID, поэтому для списка из N
+//! тайтлов выполняется порядка N / 50 запросов, а не N.
+
+use crate::client::ShikicrateClient;
+use crate::error::Result;
+use crate::queries::{ANIME_LINKS_QUERY, MANGA_LINKS_QUERY, UserRateSearchParams};
+use crate::types::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+/// Максимальное количество ID в одном пакетном запросе тайтлов.
+const TITLE_BATCH_SIZE: usize = 50;
+
+/// Тип узла графа.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeKind {
+    Anime,
+    Manga,
+    Studio,
+    Publisher,
+    Franchise,
+}
+
+/// Тип ребра графа.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    /// Связь между тайтлами (`relationKind`, например `sequel`).
+    Related,
+    /// Тайтл принадлежит франшизе.
+    Franchise,
+    /// Аниме сделано студией.
+    Studio,
+    /// Манга выпущена издательством.
+    Publisher,
+}
+
+/// Узел графа.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    /// Уникальный ключ узла, например `"anime:1"` или `"franchise:bakemonogatari"`.
+    pub key: String,
+
+    /// Тип узла.
+    pub kind: NodeKind,
+
+    /// Подпись (русское название, если есть).
+    pub label: String,
+
+    /// Оценка пользователя (только для тайтлов из списка).
+    pub score: Option<f64>,
+
+    /// Статус в списке пользователя (только для тайтлов из списка).
+    pub status: Option<WatchStatus>,
+}
+
+/// Ребро графа.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+    /// Ключ исходного узла.
+    pub from: String,
+
+    /// Ключ целевого узла.
+    pub to: String,
+
+    /// Тип ребра.
+    pub kind: EdgeKind,
+
+    /// Подпись ребра (например, `relationKind` для связей).
+    pub label: Option<String>,
+}
+
+/// Граф тайтлов из списка пользователя.
+///
+/// Связи (`EdgeKind::Related`) добавляются только между тайтлами из списка.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LibraryGraph {
+    /// Узлы в порядке добавления: сначала тайтлы, затем студии, издательства и франшизы.
+    pub nodes: Vec<GraphNode>,
+
+    /// Ребра графа.
+    pub edges: Vec<GraphEdge>,
+}
+
+#[derive(Deserialize)]
+struct TitleLinks {
+    #[serde(deserialize_with = "deser_id")]
+    id: i64,
+    name: String,
+    russian: Option<String>,
+    franchise: Option<String>,
+    studios: Option<Vec<Studio>>,
+    publishers: Option<Vec<Publisher>>,
+    related: Option<Vec<Related>>,
+}
+
+fn title_key(kind: NodeKind, id: i64) -> String {
+    match kind {
+        NodeKind::Manga => format!("manga:{}", id),
+        _ => format!("anime:{}", id),
+    }
+}
+
+impl LibraryGraph {
+    fn from_titles(rates: &[UserRate], animes: Vec<TitleLinks>, mangas: Vec<TitleLinks>) -> Self {
+        let mut graph = LibraryGraph::default();
+        let mut hubs: BTreeMap<String, GraphNode> = BTreeMap::new();
+
+        let in_library: HashSet<String> = animes
+            .iter()
+            .map(|t| title_key(NodeKind::Anime, t.id))
+            .chain(mangas.iter().map(|t| title_key(NodeKind::Manga, t.id)))
+            .collect();
+
+        let titles = animes
+            .into_iter()
+            .map(|t| (NodeKind::Anime, t))
+            .chain(mangas.into_iter().map(|t| (NodeKind::Manga, t)));
+
+        for (kind, title) in titles {
+            let key = title_key(kind, title.id);
+            let rate = rates.iter().find(|rate| match kind {
+                NodeKind::Manga => rate.manga.as_ref().is_some_and(|m| m.id == title.id),
+                _ => rate.anime.as_ref().is_some_and(|a| a.id == title.id),
+            });
+
+            graph.nodes.push(GraphNode {
+                key: key.clone(),
+                kind,
+                label: title.russian.filter(|r| !r.is_empty()).unwrap_or(title.name),
+                score: rate.and_then(|r| r.score).filter(|score| *score > 0.0),
+                status: rate.map(|r| r.status.clone()),
+            });
+
+            let studios = title.studios.into_iter().flatten().map(|s| (NodeKind::Studio, EdgeKind::Studio, s.id, s.name));
+            let publishers = title
+                .publishers
+                .into_iter()
+                .flatten()
+                .map(|p| (NodeKind::Publisher, EdgeKind::Publisher, p.id, p.name));
+            for (hub_kind, edge_kind, id, name) in studios.chain(publishers) {
+                let hub_key = format!("{}:{}", if hub_kind == NodeKind::Studio { "studio" } else { "publisher" }, id);
+                graph.link(&key, &hub_key, edge_kind, None);
+                hubs.entry(hub_key.clone()).or_insert(GraphNode {
+                    key: hub_key,
+                    kind: hub_kind,
+                    label: name,
+                    score: None,
+                    status: None,
+                });
+            }
+
+            if let Some(franchise) = title.franchise.filter(|f| !f.is_empty()) {
+                let hub_key = format!("franchise:{}", franchise);
+                graph.link(&key, &hub_key, EdgeKind::Franchise, None);
+                hubs.entry(hub_key.clone()).or_insert(GraphNode {
+                    key: hub_key,
+                    kind: NodeKind::Franchise,
+                    label: franchise,
+                    score: None,
+                    status: None,
+                });
+            }
+
+            for related in title.related.into_iter().flatten() {
+                let target = match (&related.anime, &related.manga) {
+                    (Some(anime), _) => anime.id.map(|id| title_key(NodeKind::Anime, id)),
+                    (None, Some(manga)) => manga.id.map(|id| title_key(NodeKind::Manga, id)),
+                    (None, None) => None,
+                };
+                if let Some(target) = target.filter(|t| in_library.contains(t)) {
+                    graph.link(&key, &target, EdgeKind::Related, Some(related.relation_kind.to_string()));
+                }
+            }
+        }
+
+        let mut hubs: Vec<GraphNode> = hubs.into_values().collect();
+        hubs.sort_by_key(|node| node.kind);
+        graph.nodes.extend(hubs);
+        graph
+    }
+
+    fn link(&mut self, from: &str, to: &str, kind: EdgeKind, label: Option<String>) {
+        self.edges.push(GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+            label,
+        });
+    }
+
+    /// Выгружает граф в JSON вида `{"nodes": [...], "edges": [...]}`.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Выгружает граф в формате DOT (Graphviz).
+    pub fn to_dot(&self) -> String {
+        fn quote(value: &str) -> String {
+            format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+
+        let mut dot = String::from("digraph library {\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                NodeKind::Anime | NodeKind::Manga => "box",
+                NodeKind::Studio | NodeKind::Publisher => "ellipse",
+                NodeKind::Franchise => "diamond",
+            };
+            let label = match node.score {
+                Some(score) => format!("{} ({})", node.label, score),
+                None => node.label.clone(),
+            };
+            let _ = writeln!(dot, "  {} [label={}, shape={}];", quote(&node.key), quote(&label), shape);
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Related => "solid",
+                EdgeKind::Franchise => "dashed",
+                EdgeKind::Studio | EdgeKind::Publisher => "dotted",
+            };
+            match &edge.label {
+                Some(label) => {
+                    let _ = writeln!(
+                        dot,
+                        "  {} -> {} [label={}, style={}];",
+                        quote(&edge.from),
+                        quote(&edge.to),
+                        quote(label),
+                        style
+                    );
+                }
+                None => {
+                    let _ = writeln!(dot, "  {} -> {} [style={}];", quote(&edge.from), quote(&edge.to), style);
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl ShikicrateClient {
+    /// Строит граф тайтлов из списка пользователя.
+    ///
+    /// Загружает весь список пользователя, затем пакетно получает связи,
+    /// франшизы и студии/издательства тайтлов.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let graph = client.library_graph(1).await?;
+    ///
+    /// std::fs::write("library.dot", graph.to_dot())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn library_graph(&self, user_id: i64) -> Result<LibraryGraph> {
+        let mut rates = Vec::new();
+        for page in 1.. {
+            let batch = self
+                .user_rates(UserRateSearchParams {
+                    user_id: Some(user_id),
+                    page: Some(page),
+                    limit: Some(TITLE_BATCH_SIZE as i32),
+                    ..Default::default()
+                })
+                .await?;
+            let last = batch.len() < TITLE_BATCH_SIZE;
+            rates.extend(batch);
+            if last {
+                break;
+            }
+        }
+
+        let anime_ids: Vec<i64> = rates.iter().filter_map(|r| r.anime.as_ref()).map(|a| a.id).collect();
+        let manga_ids: Vec<i64> = rates.iter().filter_map(|r| r.manga.as_ref()).map(|m| m.id).collect();
+
+        let animes = self.title_links(ANIME_LINKS_QUERY, "animes", &anime_ids).await?;
+        let mangas = self.title_links(MANGA_LINKS_QUERY, "mangas", &manga_ids).await?;
+
+        Ok(LibraryGraph::from_titles(&rates, animes, mangas))
+    }
+
+    async fn title_links(&self, query: &str, key: &str, ids: &[i64]) -> Result<Vec<TitleLinks>> {
+        let mut titles = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(TITLE_BATCH_SIZE) {
+            let ids: Vec<String> = chunk.iter().map(|id| id.to_string()).collect();
+            let response: serde_json::Value = self
+                .execute_query(query, Some(json!({ "ids": ids.join(","), "limit": chunk.len() })))
+                .await?;
+            let batch = response.get(key).cloned().unwrap_or_else(|| json!([]));
+            titles.extend(serde_json::from_value::<Vec<TitleLinks>>(batch)?);
+        }
+        Ok(titles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> LibraryGraph {
+        let rates: Vec<UserRate> = serde_json::from_value(json!([
+            { "id": 1, "score": 9.0, "status": "completed", "anime": { "id": "5081", "name": "Bakemonogatari" } },
+            { "id": 2, "score": 0.0, "status": "planned", "anime": { "id": "11597", "name": "Nisemonogatari" } }
+        ]))
+        .unwrap();
+        let animes: Vec<TitleLinks> = serde_json::from_value(json!([
+            {
+                "id": "5081",
+                "name": "Bakemonogatari",
+                "russian": "Истории монстров",
+                "franchise": "monogatari",
+                "studios": [{ "id": "44", "name": "Shaft" }],
+                "related": [
+                    { "id": "1", "relationKind": "sequel", "anime": { "id": "11597" }, "manga": null },
+                    { "id": "2", "relationKind": "adaptation", "anime": null, "manga": { "id": "23751" } }
+                ]
+            },
+            {
+                "id": "11597",
+                "name": "Nisemonogatari",
+                "russian": null,
+                "franchise": "monogatari",
+                "studios": [{ "id": "44", "name": "Shaft" }],
+                "related": []
+            }
+        ]))
+        .unwrap();
+
+        LibraryGraph::from_titles(&rates, animes, Vec::new())
+    }
+
+    #[test]
+    fn test_library_graph_from_titles() {
+        let graph = sample_graph();
+
+        let keys: Vec<_> = graph.nodes.iter().map(|n| n.key.as_str()).collect();
+        assert_eq!(keys, vec!["anime:5081", "anime:11597", "studio:44", "franchise:monogatari"]);
+        assert_eq!(graph.nodes[0].label, "Истории монстров");
+        assert_eq!(graph.nodes[0].score, Some(9.0));
+        assert_eq!(graph.nodes[1].score, None);
+
+        // Связь с мангой не из списка не попадает в граф
+        let related: Vec<_> = graph.edges.iter().filter(|e| e.kind == EdgeKind::Related).collect();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].to, "anime:11597");
+        assert_eq!(graph.edges.len(), 5);
+    }
+
+    #[test]
+    fn test_library_graph_export() {
+        let graph = sample_graph();
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph library {"));
+        assert!(dot.contains("\"anime:5081\" -> \"anime:11597\" [label=\"sequel\", style=solid];"));
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 4);
+        assert_eq!(json["edges"][0]["kind"], "studio");
+    }
+}
@@ -0,0 +1,50 @@
+//! Спаны `tracing` для GraphQL запросов (feature `tracing`).
+//!
+//! Каждая попытка выполнения запроса оборачивается в спан `shikicrate.graphql`
+//! с полями `operation`, `variables` (без секретов), `attempt`, `duration_ms`
+//! и `status` (`ok`, `cached` или `error`; при ошибке также `error`).
+
+use crate::error::Result;
+use crate::logging::redact;
+use crate::meta;
+use serde_json::Value;
+use std::future::Future;
+use std::time::{Instant, SystemTime};
+use tracing::Instrument;
+use tracing::field::Empty;
+
+/// Выполняет одну попытку запроса внутри спана.
+pub(crate) async fn attempt<T, F>(
+    query: &str,
+    variables: Option<&Value>,
+    attempt: u32,
+    future: F,
+) -> Result<(T, SystemTime, bool)>
+where
+    F: Future<Output = Result<(T, SystemTime, bool)>>,
+{
+    let operation = meta::operation_name(query);
+    let variables = variables.map(redact).unwrap_or(Value::Null);
+    let span = tracing::info_span!(
+        "shikicrate.graphql",
+        operation = operation.as_deref().unwrap_or("anonymous"),
+        variables = %variables,
+        attempt,
+        duration_ms = Empty,
+        status = Empty,
+        error = Empty,
+    );
+
+    let started = Instant::now();
+    let result = future.instrument(span.clone()).await;
+    span.record("duration_ms", started.elapsed().as_millis() as u64);
+    match &result {
+        Ok((_, _, true)) => span.record("status", "cached"),
+        Ok(_) => span.record("status", "ok"),
+        Err(e) => {
+            span.record("error", tracing::field::display(e));
+            span.record("status", "error")
+        }
+    };
+    result
+}
@@ -9,8 +9,21 @@
 //! - Поиск персонажей (по странице или по ID)
 //! - Поиск людей (сейю, мангаки, продюсеры)
 //! - Поиск пользовательских оценок
+//! - Сводная статистика профиля (статусы, оценки, время просмотра)
+//! - Быстрое автодополнение по аниме, манге, персонажам и людям
+//! - Календарь выхода эпизодов
+//! - Избранное пользователя: чтение, добавление и удаление (запись требует OAuth2 токен)
+//! - Друзья пользователя и игнор-лист
+//! - Топики форума, новости, комментарии и отзывы
+//! - Публичный журнал банов модерации
+//! - Создание, изменение и удаление пользовательских оценок (требует OAuth2 токен)
+//! - Синхронизация списка пользователя с последующей сверкой состояния
+//! - OAuth2 авторизация, включая out-of-band режим для CLI
 //! - Автоматический retry для сетевых ошибок с экспоненциальной задержкой
 //! - Валидация параметров запросов
+//! - Метаданные происхождения ответов (время загрузки, кеш, количество попыток)
+//! - Кеширование ответов в памяти или на диске с режимами bypass, refresh и max-age
+//! - Ограничение частоты запросов: фиксированный интервал, адаптивный режим (AIMD) или token bucket под лимиты Shikimori
 //!
 //! ## Быстрый старт
 //!
@@ -23,12 +36,7 @@
 //!     let client = ShikicrateClient::new()?;
 //!
 //!     // Поиск аниме
-//!     let animes = client.animes(AnimeSearchParams {
-//!         search: Some("naruto".to_string()),
-//!         limit: Some(10),
-//!         kind: None,
-//!         page: None,
-//!     }).await?;
+//!     let animes = client.animes(AnimeSearchParams::builder().search("naruto").limit(10)).await?;
 //!
 //!     for anime in animes {
 //!         println!("{} (ID: {})", anime.name, anime.id);
@@ -59,10 +67,46 @@
 //!
 //! ## Модули
 //!
+//! - [`auth`] - OAuth2 авторизация и хранение токенов
+//! - [`autocomplete`] - Быстрый поиск для автодополнения
+//! - [`bans`] - Журнал банов модерации
+//! - [`batch`] - Объединение нескольких GraphQL запросов в один HTTP запрос
+//! - [`body`] - Сериализация тела GraphQL запросов
+//! - [`circuit`] - Circuit breaker для защиты API во время сбоев
+//! - [`cache`] - Хранилища кеша ответов (в памяти и на диске)
+//! - [`calendar`] - Календарь выхода эпизодов онгоингов
+//! - [`cancel`] - Отмена запросов, включая ожидание между повторами
+//! - [`cast`] - Страница каста тайтла (персонажи и авторы по ролям)
 //! - [`client`] - HTTP клиент для выполнения GraphQL запросов
+//! - [`comments`] - Комментарии к топикам и профилям
+//! - [`date`] - Неполные даты с проверкой и форматированием
+//! - [`description`] - BBCode описаний: чистый текст и безопасный HTML
 //! - [`error`] - Типы ошибок
+//! - [`favorites`] - Избранное пользователя
+//! - `fixtures` - Готовые данные для тестов: `Anime::fixture()` и т.д. (feature `test-util`)
+//! - [`graph`] - Граф библиотеки пользователя с выгрузкой в DOT/JSON
+//! - [`logging`] - Журнал HTTP запросов с маскированием секретов
+//! - [`meta`] - Метаданные происхождения ответов
+//! - [`metrics`] - Хуки для сбора метрик запросов
+//! - [`middleware`] - Промежуточные обработчики HTTP запросов
+//! - [`transport`] - Транспорт HTTP запросов, заглушка для тестов и офлайн фикстуры
 //! - [`types`] - Типы данных (Anime, Manga, Character, Person и т.д.)
+//! - [`urls`] - Абсолютные ссылки на страницы и изображения
+//! - `vcr` - Запись и воспроизведение HTTP ответов в кассеты (feature `vcr`)
+//! - [`videos`] - Хелперы для опенингов, эндингов и промо-видео
+//! - [`warnings`] - Нефатальные предупреждения (уменьшенный лимит, проигнорированные фильтры)
+//! - [`profile`] - Сводная статистика профиля пользователя
 //! - [`queries`] - Методы для выполнения запросов и параметры поиска
+//! - [`rate_limit`] - Настройки ограничения частоты запросов
+//! - [`retry`] - Настройки повторов запросов
+//! - [`sleep`] - Ожидание между повторами, подменяемое в тестах
+//! - [`reviews`] - Отзывы на аниме и мангу
+//! - [`title`] - Выбор названия по приоритету языков
+//! - [`topics`] - Топики форума и новости
+//! - [`mutations`] - Методы, изменяющие данные пользователя (требуют токен)
+//! - [`social`] - Друзья и игнор-лист
+//! - [`sync`] - Очередь синхронизации списка пользователя
+//! - `testing` - Локальный mock сервер для интеграционных тестов (feature `test-util`)
 //!
 //! ## Retry логика
 //!
@@ -70,11 +114,36 @@
 //! - Сетевые ошибки (таймауты, ошибки подключения, ошибки запроса)
 //! - Rate limiting (429 Too Many Requests) - с учетом заголовка `Retry-After`
 //!
-//! Retry выполняется до 3 раз с экспоненциальной задержкой: 1 секунда, 2 секунды, 4 секунды.
+//! По умолчанию retry выполняется до 3 раз с экспоненциальной задержкой: 1 секунда, 2 секунды, 4 секунды.
+//! Количество попыток и задержки настраиваются через [`retry::RetryPolicy`].
 //! Для rate limiting используется значение из заголовка `Retry-After`, если оно указано.
 //!
 //! Ошибки валидации, GraphQL ошибки и другие API ошибки (неуспешные HTTP статусы, кроме 429) не повторяются.
 //!
+//! ## Tracing
+//!
+//! С feature `tracing` каждая попытка GraphQL запроса оборачивается в спан `shikicrate.graphql`
+//! с именем операции, переменными (значения токенов и паролей скрыты), номером попытки,
+//! длительностью и статусом.
+//!
+//! ## Даты
+//!
+//! Метки времени (`created_at`, `updated_at`, `next_episode_at`, `last_online_at`)
+//! хранятся строками ISO 8601. С feature `chrono` у структур есть методы
+//! `created_at_utc()`, `updated_at_utc()` и т.д., возвращающие `chrono::DateTime<Utc>`.
+//!
+//! ## Новые поля API
+//!
+//! С feature `extra-fields` у `Anime`, `Manga`, `Character`, `Person`, `User`,
+//! `UserRate` и полных версий персонажа и человека появляется поле `extra` с
+//! полями ответа, которых еще нет в структуре (из REST ответов и собственных
+//! GraphQL запросов).
+//!
+//! ## Кассеты
+//!
+//! С feature `vcr` модуль `vcr` позволяет один раз записать реальные ответы API в JSON
+//! файл и воспроизводить их в тестах без сети (см. `vcr::Cassette`).
+//!
 //! ## Валидация параметров
 //!
 //! Все методы автоматически валидируют параметры запроса:
@@ -91,13 +160,61 @@
 //! - [`queries`] - методы поиска и параметры
 //! - [`types`] - структуры данных
 
+pub mod auth;
+pub mod autocomplete;
+pub mod bans;
+pub mod batch;
+pub mod body;
+pub mod cache;
+pub mod calendar;
+pub mod cancel;
+pub mod cast;
+pub mod circuit;
 pub mod client;
+pub mod comments;
+pub mod date;
+pub mod description;
 pub mod error;
+pub mod favorites;
+#[cfg(feature = "test-util")]
+pub mod fixtures;
+pub mod graph;
+#[cfg(feature = "tracing")]
+mod instrument;
+pub mod logging;
+pub mod meta;
+pub mod metrics;
+pub mod middleware;
+mod mirrors;
+pub mod mutations;
 pub mod pagination;
+pub mod profile;
 pub mod queries;
+pub mod rate_limit;
+pub mod retry;
+pub mod reviews;
+pub mod sleep;
+pub mod social;
+pub mod sync;
+#[cfg(feature = "test-util")]
+pub mod testing;
+pub mod title;
+pub mod topics;
+pub mod transport;
 pub mod types;
+pub mod urls;
+pub mod videos;
+#[cfg(feature = "vcr")]
+pub mod vcr;
+pub mod warnings;
 
-pub use client::{ShikicrateClient, ShikicrateClientBuilder};
-pub use error::{Result, ShikicrateError};
+pub use client::{ClientStats, ShikicrateClient, ShikicrateClientBuilder};
+pub use error::{
+    Constraint, GraphQLErrorDetail, GraphQLLocation, GraphQLPathSegment, NonEmptyExt, ResponseHeaders, Result,
+    ShikicrateError, ValidationError,
+};
+pub use meta::{Fetched, ResponseMeta};
 pub use queries::*;
 pub use types::*;
+pub use videos::VideoKind;
+pub use warnings::Warning;
@@ -0,0 +1,226 @@
+//! Журнал HTTP запросов с маскированием секретов.
+//!
+//! [`RequestLogger`] — middleware, которое после каждого запроса передает в
+//! callback описание обмена ([`LoggedExchange`]). Заголовки `Authorization` и
+//! `Cookie`, а также токены, пароли и секреты в параметрах URL и теле запроса
+//! заменяются на `***`, поэтому журнал можно писать в логи production сервиса.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//!
+//! # fn example() -> shikicrate::Result<()> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .access_token("secret".to_string())
+//!     .log_requests(|exchange| {
+//!         eprintln!(
+//!             "{} {} -> {:?} за {:?}",
+//!             exchange.method, exchange.url, exchange.status, exchange.elapsed
+//!         );
+//!     })
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::middleware::{Middleware, Next};
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Подстроки имен полей, значения которых маскируются.
+const SECRET_KEYS: [&str; 4] = ["token", "password", "secret", "authorization"];
+
+/// Заголовки, значения которых маскируются.
+const SECRET_HEADERS: [&str; 4] = ["authorization", "proxy-authorization", "cookie", "set-cookie"];
+
+const MASK: &str = "***";
+
+pub(crate) fn is_secret(key: &str) -> bool {
+    let lower = key.to_lowercase();
+    SECRET_KEYS.iter().any(|secret| lower.contains(secret))
+}
+
+/// Копия JSON значения, в которой значения секретных полей заменены на `***`.
+pub(crate) fn redact(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| {
+                if is_secret(key) {
+                    (key.clone(), Value::String(MASK.to_string()))
+                } else {
+                    (key.clone(), redact(value))
+                }
+            })
+            .collect(),
+        Value::Array(items) => items.iter().map(redact).collect(),
+        other => other.clone(),
+    }
+}
+
+fn redact_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(&name.as_str()) {
+                MASK.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+fn redact_url(url: &url::Url) -> String {
+    if url.query().is_none() {
+        return url.to_string();
+    }
+    let mut url = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if is_secret(&key) { MASK.into() } else { value.into_owned() };
+            (key.into_owned(), value)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.to_string()
+}
+
+fn redact_body(bytes: &[u8]) -> String {
+    if let Ok(json) = serde_json::from_slice::<Value>(bytes) {
+        return redact(&json).to_string();
+    }
+    let text = String::from_utf8_lossy(bytes);
+    if text.contains('=') && !text.contains(char::is_whitespace) {
+        // application/x-www-form-urlencoded
+        return url::form_urlencoded::parse(bytes)
+            .map(|(key, value)| {
+                let value = if is_secret(&key) { MASK.into() } else { value };
+                format!("{}={}", key, value)
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+    }
+    format!("<{} байт>", bytes.len())
+}
+
+/// Описание одного HTTP запроса и ответа на него (секреты замаскированы).
+#[derive(Debug, Clone)]
+pub struct LoggedExchange {
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+
+    /// Тело запроса (JSON и form-urlencoded с замаскированными секретами).
+    pub request_body: Option<String>,
+
+    /// HTTP статус ответа (`None`, если ответ не получен).
+    pub status: Option<u16>,
+    pub response_headers: Vec<(String, String)>,
+
+    /// Текст ошибки, если запрос не удался до получения ответа.
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+/// Middleware, передающее каждый запрос в callback (см. [`LoggedExchange`]).
+pub struct RequestLogger {
+    callback: Arc<dyn Fn(&LoggedExchange) + Send + Sync>,
+}
+
+impl RequestLogger {
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(&LoggedExchange) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+}
+
+impl Middleware for RequestLogger {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> BoxFuture<'a, Result<reqwest::Response>> {
+        let mut exchange = LoggedExchange {
+            method: request.method().to_string(),
+            url: redact_url(request.url()),
+            request_headers: redact_headers(request.headers()),
+            request_body: request.body().and_then(|body| body.as_bytes()).map(redact_body),
+            status: None,
+            response_headers: Vec::new(),
+            error: None,
+            elapsed: Duration::ZERO,
+        };
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = next.run(request).await;
+            exchange.elapsed = started.elapsed();
+            match &result {
+                Ok(response) => {
+                    exchange.status = Some(response.status().as_u16());
+                    exchange.response_headers = redact_headers(response.headers());
+                }
+                Err(e) => exchange.error = Some(e.to_string()),
+            }
+            (self.callback)(&exchange);
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact() {
+        let redacted = redact(&json!({
+            "userId": 1,
+            "accessToken": "abc",
+            "nested": [{ "client_secret": "xyz", "page": 2 }]
+        }));
+        assert_eq!(
+            redacted,
+            json!({
+                "userId": 1,
+                "accessToken": "***",
+                "nested": [{ "client_secret": "***", "page": 2 }]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_logger_masks_secrets() {
+        let logged = Arc::new(std::sync::Mutex::new(None));
+        let sink = logged.clone();
+        let logger = RequestLogger::new(move |exchange| *sink.lock().unwrap() = Some(exchange.clone()));
+
+        let client = reqwest::Client::new();
+        let request = client
+            .post("http://127.0.0.1:9/oauth/token?access_token=abc&page=2")
+            .bearer_auth("abc")
+            .body("grant_type=refresh_token&refresh_token=abc&client_id=app")
+            .build()
+            .unwrap();
+        let middlewares: Vec<Arc<dyn Middleware>> = vec![Arc::new(logger)];
+        assert!(Next::new(&client, &middlewares).run(request).await.is_err());
+
+        let exchange = logged.lock().unwrap().clone().unwrap();
+        assert_eq!(exchange.url, "http://127.0.0.1:9/oauth/token?access_token=***&page=2");
+        assert!(exchange.request_headers.contains(&("authorization".to_string(), "***".to_string())));
+        assert_eq!(
+            exchange.request_body.as_deref(),
+            Some("grant_type=refresh_token&refresh_token=***&client_id=app")
+        );
+        assert!(exchange.error.is_some() && exchange.status.is_none());
+    }
+}
@@ -0,0 +1,144 @@
+//! Метаданные происхождения ответов.
+//!
+//! Методы клиента возвращают только данные, а сведения о том, как они были
+//! получены (время загрузки, ответ из кеша, количество попыток, endpoint),
+//! собираются через [`with_meta`]: все запросы, выполненные внутри переданного
+//! future, записывают по одному [`ResponseMeta`].
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClient, queries::*};
+//! use shikicrate::meta::with_meta;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ShikicrateClient::new()?;
+//! let fetched = with_meta(client.anime_by_id(1)).await;
+//!
+//! for meta in &fetched.meta {
+//!     println!("{} (кеш: {}, попыток: {})", meta.endpoint, meta.from_cache, meta.attempts);
+//! }
+//! let anime = fetched.data?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::time::SystemTime;
+
+tokio::task_local! {
+    static COLLECTOR: RefCell<Vec<ResponseMeta>>;
+}
+
+/// Сведения об одном запросе к API.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// Время получения данных с сервера (для ответа из кеша — время исходного запроса).
+    pub fetched_at: SystemTime,
+
+    /// Ответ взят из локального кеша без обращения к серверу.
+    pub from_cache: bool,
+
+    /// Количество выполненных попыток (1, если повторов не было).
+    pub attempts: u32,
+
+    /// URL, к которому был отправлен запрос.
+    pub endpoint: String,
+
+    /// Имя GraphQL операции (например, `SearchAnimes`), для REST запросов — `None`.
+    pub operation: Option<String>,
+}
+
+/// Результат вместе с метаданными всех запросов, выполненных для его получения.
+#[derive(Debug, Clone)]
+pub struct Fetched<T> {
+    /// Результат future.
+    pub data: T,
+
+    /// Метаданные запросов в порядке их выполнения.
+    pub meta: Vec<ResponseMeta>,
+}
+
+impl<T> Fetched<T> {
+    /// Возвращает `true`, если все данные взяты из кеша.
+    pub fn from_cache(&self) -> bool {
+        self.meta.iter().all(|meta| meta.from_cache)
+    }
+
+    /// Суммарное количество попыток по всем запросам.
+    pub fn attempts(&self) -> u32 {
+        self.meta.iter().map(|meta| meta.attempts).sum()
+    }
+
+    /// Время получения самых старых данных среди всех запросов.
+    pub fn fetched_at(&self) -> Option<SystemTime> {
+        self.meta.iter().map(|meta| meta.fetched_at).min()
+    }
+}
+
+/// Выполняет future и собирает метаданные всех запросов клиента внутри него.
+pub async fn with_meta<F: Future>(future: F) -> Fetched<F::Output> {
+    COLLECTOR
+        .scope(RefCell::new(Vec::new()), async move {
+            let data = future.await;
+            let meta = COLLECTOR.with(|collector| collector.take());
+            Fetched { data, meta }
+        })
+        .await
+}
+
+/// Записывает метаданные запроса, если он выполняется внутри [`with_meta`].
+pub(crate) fn record(meta: ResponseMeta) {
+    let _ = COLLECTOR.try_with(|collector| collector.borrow_mut().push(meta));
+}
+
+/// Извлекает имя операции из текста GraphQL запроса.
+pub(crate) fn operation_name(query: &str) -> Option<String> {
+    let rest = query.trim_start().strip_prefix("query")?.trim_start();
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(
+            operation_name("\n  query SearchAnimes($search: String) { animes { id } }").as_deref(),
+            Some("SearchAnimes")
+        );
+        assert_eq!(operation_name("{ animes { id } }"), None);
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_collects_records() {
+        let meta = |from_cache| ResponseMeta {
+            fetched_at: SystemTime::now(),
+            from_cache,
+            attempts: 2,
+            endpoint: "https://example.com".to_string(),
+            operation: None,
+        };
+
+        let fetched = with_meta(async {
+            record(meta(true));
+            record(meta(false));
+            42
+        })
+        .await;
+
+        assert_eq!(fetched.data, 42);
+        assert_eq!(fetched.meta.len(), 2);
+        assert_eq!(fetched.attempts(), 4);
+        assert!(!fetched.from_cache());
+
+        // Вне with_meta записи игнорируются
+        record(meta(true));
+    }
+}
@@ -0,0 +1,122 @@
+//! Хуки для сбора метрик запросов.
+//!
+//! Реализуйте [`MetricsObserver`] и передайте его в
+//! [`ShikicrateClientBuilder::metrics`](crate::ShikicrateClientBuilder::metrics),
+//! чтобы экспортировать количество и длительность запросов в Prometheus,
+//! OpenTelemetry и т.п. Все методы имеют пустую реализацию по умолчанию.
+//!
+//! Наблюдатель вызывается для каждого HTTP запроса к API (GraphQL и REST).
+//! Ответы из кеша клиента в метрики не попадают, а ожидание лимитера не входит
+//! в `latency`.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::metrics::{MetricsObserver, RequestInfo};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::time::Duration;
+//!
+//! #[derive(Default)]
+//! struct Counters {
+//!     completed: AtomicU64,
+//!     failed: AtomicU64,
+//! }
+//!
+//! impl MetricsObserver for Counters {
+//!     fn request_completed(&self, request: &RequestInfo<'_>, status: u16, latency: Duration) {
+//!         self.completed.fetch_add(1, Ordering::Relaxed);
+//!         println!("{:?} {} за {:?}", request.operation, status, latency);
+//!     }
+//!
+//!     fn request_failed(&self, _request: &RequestInfo<'_>, _error: &shikicrate::ShikicrateError, _latency: Duration) {
+//!         self.failed.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! # fn example() -> shikicrate::Result<()> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .metrics(Counters::default())
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::ShikicrateError;
+use std::time::Duration;
+
+/// Описание запроса, передаваемое наблюдателю.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestInfo<'a> {
+    /// HTTP метод (`POST` для GraphQL).
+    pub method: &'a str,
+
+    /// URL запроса.
+    pub endpoint: &'a str,
+
+    /// Имя GraphQL операции (например, `SearchAnimes`), для REST запросов — `None`.
+    pub operation: Option<&'a str>,
+}
+
+/// Наблюдатель за запросами клиента.
+pub trait MetricsObserver: Send + Sync {
+    /// Запрос отправляется на сервер.
+    fn request_started(&self, _request: &RequestInfo<'_>) {}
+
+    /// Получен успешный HTTP ответ.
+    fn request_completed(&self, _request: &RequestInfo<'_>, _status: u16, _latency: Duration) {}
+
+    /// Запрос завершился сетевой ошибкой или неуспешным HTTP статусом.
+    fn request_failed(&self, _request: &RequestInfo<'_>, _error: &ShikicrateError, _latency: Duration) {}
+
+    /// Запрос будет повторен через `delay`; `attempt` — номер следующей попытки (с 2).
+    fn request_retried(&self, _request: &RequestInfo<'_>, _attempt: u32, _delay: Duration, _error: &ShikicrateError) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShikicrateClientBuilder;
+    use crate::retry::RetryPolicy;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl MetricsObserver for Recorder {
+        fn request_started(&self, request: &RequestInfo<'_>) {
+            self.0.lock().unwrap().push(format!("started {:?}", request.operation));
+        }
+
+        fn request_failed(&self, _request: &RequestInfo<'_>, _error: &ShikicrateError, _latency: Duration) {
+            self.0.lock().unwrap().push("failed".to_string());
+        }
+
+        fn request_retried(&self, _request: &RequestInfo<'_>, attempt: u32, _delay: Duration, _error: &ShikicrateError) {
+            self.0.lock().unwrap().push(format!("retried {}", attempt));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_failures_and_retries() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = ShikicrateClientBuilder::new()
+            .base_url("http://127.0.0.1:9/api/graphql".to_string())
+            .retry_policy(RetryPolicy {
+                max_attempts: 2,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            })
+            .metrics(Recorder(events.clone()))
+            .build()
+            .unwrap();
+
+        let result: crate::Result<serde_json::Value> =
+            client.execute_query("query GetGenres { genres(entryType: Anime) { id } }", None).await;
+        assert!(result.is_err());
+        assert_eq!(
+            *events.lock().unwrap(),
+            ["started Some(\"GetGenres\")", "failed", "retried 2", "started Some(\"GetGenres\")", "failed"]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Промежуточные обработчики HTTP запросов.
+//!
+//! [`Middleware`] получает готовый [`reqwest::Request`] перед отправкой и может
+//! изменить его, вернуть ответ самостоятельно или передать запрос дальше через
+//! [`Next::run`]; после последнего обработчика запрос уходит в
+//! [`Transport`] клиента. Обработчики вызываются
+//! в порядке добавления через
+//! [`ShikicrateClientBuilder::middleware`](crate::ShikicrateClientBuilder::middleware)
+//! для каждой попытки отправки, включая повторы. Ответы из кеша клиента
+//! обработчики не видят.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use futures::future::BoxFuture;
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::middleware::{Middleware, Next};
+//! use std::time::Instant;
+//!
+//! struct Timing;
+//!
+//! impl Middleware for Timing {
+//!     fn handle<'a>(
+//!         &'a self,
+//!         request: reqwest::Request,
+//!         next: Next<'a>,
+//!     ) -> BoxFuture<'a, shikicrate::Result<reqwest::Response>> {
+//!         Box::pin(async move {
+//!             let url = request.url().clone();
+//!             let started = Instant::now();
+//!             let response = next.run(request).await;
+//!             println!("{} за {:?}", url, started.elapsed());
+//!             response
+//!         })
+//!     }
+//! }
+//!
+//! # fn example() -> shikicrate::Result<()> {
+//! let client = ShikicrateClientBuilder::new()
+//!     .middleware(Timing)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::transport::Transport;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Обработчик, через который проходит каждый HTTP запрос клиента.
+pub trait Middleware: Send + Sync {
+    /// Обрабатывает запрос. Чтобы отправить его дальше, вызовите `next.run(request)`.
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> BoxFuture<'a, Result<reqwest::Response>>;
+}
+
+/// Оставшаяся часть цепочки обработчиков.
+pub struct Next<'a> {
+    transport: &'a dyn Transport,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(transport: &'a dyn Transport, middlewares: &'a [Arc<dyn Middleware>]) -> Self {
+        Self { transport, middlewares }
+    }
+
+    /// Передает запрос следующему обработчику, а после последнего — отправляет его.
+    pub fn run(self, request: reqwest::Request) -> BoxFuture<'a, Result<reqwest::Response>> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(
+                request,
+                Next {
+                    transport: self.transport,
+                    middlewares: rest,
+                },
+            ),
+            None => self.transport.execute(request),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Constraint, ShikicrateError, ValidationError};
+    use std::sync::Mutex;
+
+    struct Record(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+    impl Middleware for Record {
+        fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> BoxFuture<'a, Result<reqwest::Response>> {
+            self.1.lock().unwrap().push(self.0);
+            next.run(request)
+        }
+    }
+
+    struct Reject;
+
+    impl Middleware for Reject {
+        fn handle<'a>(&'a self, request: reqwest::Request, _next: Next<'a>) -> BoxFuture<'a, Result<reqwest::Response>> {
+            let url = request.url().to_string();
+            Box::pin(async move { Err(ValidationError::new("url", Constraint::Other, format!("отклонено: {}", url)).into()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_order_and_short_circuit() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let middlewares: Vec<Arc<dyn Middleware>> = vec![
+            Arc::new(Record("first", calls.clone())),
+            Arc::new(Record("second", calls.clone())),
+            Arc::new(Reject),
+            Arc::new(Record("unreachable", calls.clone())),
+        ];
+        let client = reqwest::Client::new();
+        let request = client.get("http://localhost/graphql").build().unwrap();
+
+        let result = Next::new(&client, &middlewares).run(request).await;
+        assert!(matches!(result, Err(ShikicrateError::Validation(e)) if e.message.contains("localhost")));
+        assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Список зеркал API с переключением при ошибках подключения.
+
+use crate::error::{Constraint, Result, ValidationError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Упорядоченный список адресов сайта (`https://shikimori.one` и т.п.).
+///
+/// Активное зеркало общее для всех клонов клиента: после переключения
+/// следующие запросы сразу идут на рабочий адрес.
+pub(crate) struct Mirrors {
+    origins: Vec<String>,
+    active: AtomicUsize,
+}
+
+impl Mirrors {
+    pub(crate) fn new(origins: Vec<String>) -> Result<Self> {
+        if origins.is_empty() {
+            return Err(ValidationError::new("mirrors", Constraint::NotEmpty, "Список зеркал не должен быть пустым").into());
+        }
+        let origins = origins
+            .into_iter()
+            .map(|origin| {
+                let origin = origin.trim().trim_end_matches('/').to_string();
+                match url::Url::parse(&origin) {
+                    Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(origin),
+                    _ => Err(ValidationError::new(
+                        "mirrors",
+                        Constraint::Format,
+                        format!("Некорректный адрес зеркала: {}", origin),
+                    )
+                    .value(&origin)
+                    .into()),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            origins,
+            active: AtomicUsize::new(0),
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// Адрес активного зеркала.
+    pub(crate) fn active(&self) -> &str {
+        &self.origins[self.active.load(Ordering::Relaxed)]
+    }
+
+    /// Переключается на зеркало, следующее за тем, на которое был отправлен `url`,
+    /// и возвращает `url`, перенесенный на новое активное зеркало.
+    ///
+    /// Если другой запрос уже переключил зеркало, используется выбранное им.
+    pub(crate) fn fail_over(&self, url: &url::Url) -> Option<url::Url> {
+        let url = url.as_str();
+        let (failed, origin) = self
+            .origins
+            .iter()
+            .enumerate()
+            .find(|(_, origin)| url.strip_prefix(origin.as_str()).is_some_and(|rest| rest.starts_with('/')))?;
+        let next = (failed + 1) % self.origins.len();
+        let active = match self.active.compare_exchange(failed, next, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => next,
+            Err(current) => current,
+        };
+        url::Url::parse(&format!("{}{}", self.origins[active], &url[origin.len()..])).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fail_over_rotates() {
+        let mirrors = Mirrors::new(vec![
+            "https://shikimori.one/".to_string(),
+            "https://shikimori.me".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(mirrors.active(), "https://shikimori.one");
+
+        let url = url::Url::parse("https://shikimori.one/api/animes?page=2").unwrap();
+        let moved = mirrors.fail_over(&url).unwrap();
+        assert_eq!(moved.as_str(), "https://shikimori.me/api/animes?page=2");
+        assert_eq!(mirrors.active(), "https://shikimori.me");
+
+        // Запрос, отправленный на старое зеркало до переключения, переносится на активное
+        assert_eq!(mirrors.fail_over(&url).unwrap().as_str(), "https://shikimori.me/api/animes?page=2");
+
+        let moved = mirrors.fail_over(&moved).unwrap();
+        assert_eq!(moved.as_str(), "https://shikimori.one/api/animes?page=2");
+    }
+
+    #[test]
+    fn test_invalid_mirrors() {
+        assert!(Mirrors::new(Vec::new()).is_err());
+        assert!(Mirrors::new(vec!["shikimori.one".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use crate::types::*;
+use serde_json::json;
+
+impl ShikicrateClient {
+    /// Удаляет пользовательскую оценку (запись из списка пользователя).
+    ///
+    /// Требует клиента с настроенным `access_token`, иначе возвращает
+    /// `ShikicrateError::Auth`. После успешного удаления закешированные
+    /// результаты `user_rates()` сбрасываются.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClientBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .access_token("token".to_string())
+    ///     .build()?;
+    ///
+    /// let deleted = client.delete_user_rate(12345).await?;
+    /// println!("Удалена оценка {}", deleted.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_user_rate(&self, id: i64) -> Result<UserRateDeleted> {
+        if id <= 0 {
+            return Err(ValidationError::new("id", Constraint::Min(1), "ID оценки должен быть больше 0").value(id).into());
+        }
+
+        self.mutate_rest(reqwest::Method::DELETE, &format!("v2/user_rates/{}", id), None, "userRates")
+            .await?;
+        Ok(UserRateDeleted { id })
+    }
+
+    /// Создает пользовательскую оценку (добавляет тайтл в список пользователя).
+    ///
+    /// Требует клиента с настроенным `access_token`.
+    pub async fn create_user_rate(&self, user_id: i64, input: &UserRateInput) -> Result<UserRate> {
+        Self::val_rate_input(input)?;
+
+        let mut user_rate = serde_json::to_value(input)?;
+        user_rate["user_id"] = json!(user_id);
+        let body = json!({ "user_rate": user_rate });
+
+        let response = self
+            .mutate_rest(reqwest::Method::POST, "v2/user_rates", Some(&body), "userRates")
+            .await?;
+        serde_json::from_value(response).map_err(ShikicrateError::Serialization)
+    }
+
+    /// Обновляет пользовательскую оценку.
+    ///
+    /// Отправляются только заданные (`Some`) поля `input`; `target_id` и
+    /// `target_type` не изменяются. Требует клиента с настроенным `access_token`.
+    pub async fn update_user_rate(&self, id: i64, input: &UserRateInput) -> Result<UserRate> {
+        if id <= 0 {
+            return Err(ValidationError::new("id", Constraint::Min(1), "ID оценки должен быть больше 0").value(id).into());
+        }
+        Self::val_rate_input(input)?;
+
+        let mut user_rate = serde_json::to_value(input)?;
+        if let Some(fields) = user_rate.as_object_mut() {
+            fields.remove("target_id");
+            fields.remove("target_type");
+        }
+        let body = json!({ "user_rate": user_rate });
+
+        let response = self
+            .mutate_rest(reqwest::Method::PATCH, &format!("v2/user_rates/{}", id), Some(&body), "userRates")
+            .await?;
+        serde_json::from_value(response).map_err(ShikicrateError::Serialization)
+    }
+
+    pub(crate) fn val_rate_input(input: &UserRateInput) -> Result<()> {
+        if input.target_id <= 0 {
+            return Err(ValidationError::new("target_id", Constraint::Min(1), "ID тайтла должен быть больше 0").value(input.target_id).into());
+        }
+        if input.target_type != "Anime" && input.target_type != "Manga" {
+            return Err(ValidationError::new(
+                "target_type",
+                Constraint::OneOf(vec!["Anime".to_string(), "Manga".to_string()]),
+                "target_type должен быть \"Anime\" или \"Manga\"",
+            )
+            .value(&input.target_type)
+            .into());
+        }
+        if let Some(status @ WatchStatus::Unknown(_)) = &input.status {
+            return Err(ValidationError::new("status", Constraint::Other, "Неизвестный статус записи")
+                .value(status)
+                .into());
+        }
+        if input.score.is_some_and(|score| !(0..=10).contains(&score)) {
+            return Err(ValidationError::new("score", Constraint::Range { min: 0, max: 10 }, "Оценка должна быть от 0 до 10")
+                .value(input.score.unwrap_or_default())
+                .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_user_rate_requires_token() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(
+            client.delete_user_rate(1).await,
+            Err(ShikicrateError::Auth(_))
+        ));
+    }
+
+    #[test]
+    fn test_val_rate_input() {
+        let mut input = UserRateInput::new(1, "Anime");
+        assert!(ShikicrateClient::val_rate_input(&input).is_ok());
+
+        input.score = Some(11);
+        assert!(matches!(
+            ShikicrateClient::val_rate_input(&input),
+            Err(ShikicrateError::Validation(_))
+        ));
+
+        let input = UserRateInput::new(1, "Ranobe");
+        assert!(matches!(
+            ShikicrateClient::val_rate_input(&input),
+            Err(ShikicrateError::Validation(_))
+        ));
+
+        let mut input = UserRateInput::new(1, "Anime");
+        input.status = Some(WatchStatus::Unknown("rewatching_twice".to_string()));
+        assert!(matches!(
+            ShikicrateClient::val_rate_input(&input),
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_rate_invalid_id() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(
+            client.delete_user_rate(0).await,
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::client::ShikicrateClient;
-use crate::error::Result;
+use crate::error::{Result, ShikicrateError};
 use crate::queries::*;
 use crate::types::*;
+use crate::warnings::MAX_LIMIT;
+use futures::future::BoxFuture;
 use futures::stream::{self, Stream, StreamExt};
 
 use std::sync::Arc;
+use std::time::Instant;
 
 /// Состояние пагинатора для аниме
 struct AnimesPaginatorState {
@@ -28,7 +31,6 @@ struct CharactersPaginatorState {
 }
 
 /// Состояние пагинатора для людей
-#[allow(dead_code)]
 struct PeoplePaginatorState {
     client: Arc<ShikicrateClient>,
     params: PeopleSearchParams,
@@ -56,12 +58,8 @@ struct UserRatesPaginatorState {
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = ShikicrateClient::new()?;
 ///
-/// let mut paginator = client.animes_paginated(AnimeSearchParams {
-///     search: Some("naruto".to_string()),
-///     page: None,  // Начнет с первой страницы
-///     limit: Some(10),
-///     kind: None,
-/// });
+/// // Без `page` начнет с первой страницы
+/// let mut paginator = client.animes_paginated(AnimeSearchParams::builder().search("naruto").limit(10));
 ///
 /// while let Some(anime) = paginator.next().await {
 ///     let anime = anime?;
@@ -98,7 +96,163 @@ pub type PeoplePaginator = Box<dyn Stream<Item = Result<PersonFull>> + Send + Un
 /// Используется через метод `user_rates_paginated()`.
 pub type UserRatesPaginator = Box<dyn Stream<Item = Result<UserRate>> + Send + Unpin>;
 
+/// Ленивый итератор для пагинации произвольного запроса.
+///
+/// Используется через метод `fetch_list_paginated()`.
+pub type ListPaginator<T> = Box<dyn Stream<Item = Result<T>> + Send + Unpin>;
+
+/// Результат частичной выборки пагинатора до дедлайна.
+pub struct Drained<T> {
+    /// Элементы, полученные до дедлайна.
+    pub items: Vec<T>,
+
+    /// Оставшаяся часть пагинатора (курсор) для продолжения выборки.
+    ///
+    /// `None`, если пагинатор исчерпан или остановился на ошибке.
+    pub rest: Option<Box<dyn Stream<Item = Result<T>> + Send + Unpin>>,
+
+    /// Ошибка, на которой остановился пагинатор (если была).
+    pub error: Option<ShikicrateError>,
+}
+
+impl<T> Drained<T> {
+    /// Проверяет, что пагинатор выбран полностью и без ошибок.
+    pub fn is_complete(&self) -> bool {
+        self.rest.is_none() && self.error.is_none()
+    }
+}
+
+/// Размер страницы `animes_page()` и `mangas_page()`, если `limit` не указан.
+const DEFAULT_PAGE_LIMIT: i32 = 20;
+
+/// Одна страница результатов с признаком наличия следующей.
+///
+/// GraphQL схема Shikimori не отдает метаданных пагинации (`pageInfo`,
+/// общего количества), поэтому `has_next_page` вычисляется по заполненности
+/// страницы: полная страница считается не последней. Если количество записей
+/// кратно размеру страницы, последняя страница будет пустой.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Элементы страницы.
+    pub items: Vec<T>,
+
+    /// Номер страницы (с 1).
+    pub page: i32,
+
+    /// Есть ли следующая страница.
+    pub has_next_page: bool,
+
+    /// Общее количество записей, если API его сообщает (сейчас всегда `None`).
+    pub total: Option<u64>,
+}
+
+impl<T> Page<T> {
+    pub(crate) fn from_items(items: Vec<T>, page: i32, limit: i32) -> Self {
+        let has_next_page = items.len() >= limit.min(MAX_LIMIT) as usize;
+        Self {
+            items,
+            page,
+            has_next_page,
+            total: None,
+        }
+    }
+}
+
+/// Расширение пагинаторов для выборки с ограничением по времени.
+pub trait PaginatorExt<T> {
+    /// Собирает элементы до наступления `deadline`.
+    ///
+    /// Возвращает уже полученные элементы и курсор (`rest`), которым можно
+    /// продолжить выборку позже, например в фоновой задаче. Загрузка страницы,
+    /// прерванная дедлайном, не теряется — она продолжится при следующем опросе курсора.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::*, pagination::PaginatorExt};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let paginator = client.animes_paginated(AnimeSearchParams::default());
+    ///
+    /// let drained = paginator.collect_until(Instant::now() + Duration::from_secs(2)).await;
+    /// println!("Успели получить {} аниме", drained.items.len());
+    ///
+    /// if let Some(rest) = drained.rest {
+    ///     tokio::spawn(async move {
+    ///         let _ = rest.collect_until(Instant::now() + Duration::from_secs(60)).await;
+    ///     });
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn collect_until(self, deadline: Instant) -> BoxFuture<'static, Drained<T>>;
+}
+
+impl<T: Send + 'static> PaginatorExt<T> for Box<dyn Stream<Item = Result<T>> + Send + Unpin> {
+    fn collect_until(mut self, deadline: Instant) -> BoxFuture<'static, Drained<T>> {
+        Box::pin(async move {
+            let deadline = tokio::time::Instant::from_std(deadline);
+            let mut items = Vec::new();
+
+            loop {
+                match tokio::time::timeout_at(deadline, self.next()).await {
+                    Ok(Some(Ok(item))) => items.push(item),
+                    Ok(Some(Err(e))) => {
+                        return Drained { items, rest: None, error: Some(e) };
+                    }
+                    Ok(None) => return Drained { items, rest: None, error: None },
+                    Err(_) => {
+                        return Drained { items, rest: Some(self), error: None };
+                    }
+                }
+            }
+        })
+    }
+}
+
 impl ShikicrateClient {
+    /// Загружает одну страницу поиска аниме вместе с признаком следующей страницы.
+    ///
+    /// Если `page` не указан, загружается первая страница; если не указан
+    /// `limit`, используется 20.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let page = client
+    ///     .animes_page(AnimeSearchParams::builder().search("naruto").page(3))
+    ///     .await?;
+    ///
+    /// println!("Страница {}: {} аниме", page.page, page.items.len());
+    /// if page.has_next_page {
+    ///     println!("Дальше есть еще");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn animes_page(&self, mut params: AnimeSearchParams) -> Result<Page<Anime>> {
+        let page = *params.page.get_or_insert(1);
+        let limit = *params.limit.get_or_insert(DEFAULT_PAGE_LIMIT);
+        let items = self.animes(params).await?;
+        Ok(Page::from_items(items, page, limit))
+    }
+
+    /// Загружает одну страницу поиска манги вместе с признаком следующей страницы.
+    ///
+    /// Значения по умолчанию такие же, как в `animes_page()`.
+    pub async fn mangas_page(&self, mut params: MangaSearchParams) -> Result<Page<Manga>> {
+        let page = *params.page.get_or_insert(1);
+        let limit = *params.limit.get_or_insert(DEFAULT_PAGE_LIMIT);
+        let items = self.mangas(params).await?;
+        Ok(Page::from_items(items, page, limit))
+    }
+
     /// Создает ленивый итератор для пагинации результатов поиска аниме.
     ///
     /// Итератор автоматически загружает следующую страницу при достижении конца текущей.
@@ -113,12 +267,7 @@ impl ShikicrateClient {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ShikicrateClient::new()?;
     ///
-    /// let mut paginator = client.animes_paginated(AnimeSearchParams {
-    ///     search: Some("naruto".to_string()),
-    ///     page: None,
-    ///     limit: Some(10),
-    ///     kind: None,
-    /// });
+    /// let mut paginator = client.animes_paginated(AnimeSearchParams::builder().search("naruto").limit(10));
     ///
     /// // Обрабатываем первые 50 результатов
     /// let mut count = 0;
@@ -266,13 +415,90 @@ impl ShikicrateClient {
         )
     }
 
+    /// Создает ленивый итератор для пагинации произвольного запроса (см. `fetch_list()`).
+    ///
+    /// Запрос должен принимать переменную `$page: Int`; она выставляется
+    /// автоматически, начиная с `page` из `variables` (или с 1). Итератор
+    /// останавливается на пустой странице или после первой ошибки.
+    pub fn fetch_list_paginated<T>(
+        &self,
+        query: &str,
+        mut variables: serde_json::Value,
+        response_key: &str,
+    ) -> ListPaginator<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let start_page = variables.get("page").and_then(|page| page.as_i64()).unwrap_or(1);
+        if let Some(vars) = variables.as_object_mut() {
+            vars.remove("page");
+        }
+
+        let state = Some((self.to_arc(), query.to_string(), variables, response_key.to_string(), start_page));
+
+        Box::new(
+            stream::unfold(state, |state| async move {
+                let (client, query, variables, response_key, page) = state?;
+
+                let mut page_vars = variables.clone();
+                page_vars["page"] = serde_json::json!(page);
+
+                match client.fetch_list::<T>(&query, page_vars, &response_key).await {
+                    Ok(items) if items.is_empty() => None,
+                    Ok(items) => Some((Ok(items), Some((client, query, variables, response_key, page + 1)))),
+                    Err(e) => Some((Err(e), None)),
+                }
+            })
+            .flat_map(|result: Result<Vec<T>>| {
+                stream::iter(match result {
+                    Ok(page) => page.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                })
+            })
+            .boxed(),
+        )
+    }
+
     /// Создает ленивый итератор для пагинации результатов поиска людей.
     ///
     /// Итератор автоматически загружает следующую страницу при достижении конца текущей.
-    pub fn people_paginated(&self, _params: PeopleSearchParams) -> PeoplePaginator {
-        // Для people нет параметра page, но можно использовать limit для пагинации
-        // Пока что возвращаем пустой стрим, так как API не поддерживает page для people
-        Box::new(stream::empty().boxed())
+    /// Если `page` не указан, начнет с первой страницы.
+    pub fn people_paginated(&self, mut params: PeopleSearchParams) -> PeoplePaginator {
+        let start_page = params.page.unwrap_or(1);
+        params.page = Some(start_page);
+
+        let client = self.to_arc();
+        let state = PeoplePaginatorState {
+            client,
+            params,
+            current_page: start_page - 1,
+        };
+
+        Box::new(
+            stream::unfold(state, |mut state| async move {
+                state.current_page += 1;
+                state.params.page = Some(state.current_page);
+
+                match state.client.people(state.params.clone()).await {
+                    Ok(page) if page.is_empty() => None,
+                    Ok(page) => Some((Ok(page), state)),
+                    Err(e) => {
+                        // Возвращаем ошибку как элемент, стрим остановится после обработки в flat_map
+                        Some((Err(e), state))
+                    }
+                }
+            })
+            .flat_map(|result: Result<Vec<PersonFull>>| {
+                stream::iter(match result {
+                    Ok(page) => page.into_iter().map(Ok).collect(),
+                    Err(e) => {
+                        // Возвращаем ошибку как элемент стрима
+                        vec![Err(e)]
+                    }
+                })
+            })
+            .boxed(),
+        )
     }
 
     /// Создает ленивый итератор для пагинации результатов поиска пользовательских оценок.
@@ -317,3 +543,84 @@ impl ShikicrateClient {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Constraint, ValidationError};
+    use std::time::Duration;
+
+    fn boxed<T: Send + 'static>(
+        stream: impl Stream<Item = Result<T>> + Send + 'static,
+    ) -> Box<dyn Stream<Item = Result<T>> + Send + Unpin> {
+        Box::new(stream.boxed())
+    }
+
+    #[tokio::test]
+    async fn test_collect_until_exhausted() {
+        let paginator = boxed(stream::iter(vec![Ok(1), Ok(2), Ok(3)]));
+        let drained = paginator.collect_until(Instant::now() + Duration::from_secs(1)).await;
+
+        assert_eq!(drained.items, vec![1, 2, 3]);
+        assert!(drained.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_collect_until_deadline_is_resumable() {
+        let slow = stream::iter(vec![1, 2]).then(|i| async move {
+            if i == 2 {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+            Ok(i)
+        });
+        let paginator = boxed(slow);
+
+        let drained = paginator.collect_until(Instant::now() + Duration::from_millis(50)).await;
+        assert_eq!(drained.items, vec![1]);
+        let rest = drained.rest.expect("paginator should be resumable");
+
+        let drained = rest.collect_until(Instant::now() + Duration::from_secs(1)).await;
+        assert_eq!(drained.items, vec![2]);
+        assert!(drained.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_collect_until_stops_on_error() {
+        let paginator = boxed(stream::iter(vec![
+            Ok(1),
+            Err(ValidationError::new("page", Constraint::Other, "boom").into()),
+            Ok(2),
+        ]));
+        let drained = paginator.collect_until(Instant::now() + Duration::from_secs(1)).await;
+
+        assert_eq!(drained.items, vec![1]);
+        assert!(drained.rest.is_none());
+        assert!(matches!(drained.error, Some(ShikicrateError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_list_paginated_stops_on_error() {
+        let client = ShikicrateClient::new().unwrap();
+        let paginator = client.fetch_list_paginated::<serde_json::Value>(
+            "query($page: Int, $limit: Int) { clubs(page: $page, limit: $limit) { id } }",
+            serde_json::json!({ "limit": 0 }),
+            "clubs",
+        );
+
+        let items: Vec<_> = paginator.collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(ShikicrateError::Validation(_))));
+    }
+
+    #[test]
+    fn test_page_from_items() {
+        let page = Page::from_items(vec![1, 2, 3], 2, 3);
+        assert!(page.has_next_page);
+        assert_eq!(page.page, 2);
+        assert!(page.total.is_none());
+
+        assert!(!Page::from_items(vec![1, 2], 1, 3).has_next_page);
+        // limit больше максимального ограничивается до MAX_LIMIT
+        assert!(Page::from_items(vec![0; MAX_LIMIT as usize], 1, 100).has_next_page);
+    }
+}
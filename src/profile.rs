@@ -0,0 +1,214 @@
+//! Сводная статистика профиля пользователя.
+//!
+//! Объединяет статистику профиля из REST API (`user_stats()`) с временем
+//! просмотра, которое вычисляется по списку аниме пользователя.
+//!
+//! Метод называется `user_summary()`: имя `user_stats()` занято низкоуровневым
+//! методом, который возвращает ответ REST API без обработки.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::queries::USER_WATCH_TIME_QUERY;
+use crate::types::{ScoreStat, UserStatValue, UserStats, UserStatusCount, WatchStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Размер страницы при обходе списка аниме.
+const WATCH_TIME_PAGE_SIZE: i32 = 50;
+
+/// Сводка по аниме или манге пользователя.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetSummary {
+    /// Количество тайтлов по статусам.
+    pub statuses: HashMap<WatchStatus, i64>,
+
+    /// Распределение оценок (от 10 до 1).
+    pub scores: Vec<ScoreStat>,
+}
+
+impl TargetSummary {
+    fn from_stats(statuses: &[UserStatusCount], scores: &[UserStatValue]) -> Self {
+        let mut counts = HashMap::new();
+        for status in statuses {
+            let key = WatchStatus::from(status.grouped_id.clone().unwrap_or_default());
+            *counts.entry(key).or_insert(0) += status.size;
+        }
+
+        let mut scores: Vec<ScoreStat> = scores
+            .iter()
+            .filter_map(|s| {
+                Some(ScoreStat {
+                    score: s.name.parse().ok()?,
+                    count: s.value as i32,
+                })
+            })
+            .collect();
+        scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+
+        Self { statuses: counts, scores }
+    }
+
+    /// Общее количество тайтлов в списке.
+    pub fn total(&self) -> i64 {
+        self.statuses.values().sum()
+    }
+
+    /// Средняя оценка пользователя (`None`, если оценок нет).
+    pub fn mean_score(&self) -> Option<f64> {
+        crate::types::mean_score(&self.scores)
+    }
+}
+
+/// Сводная статистика пользователя для виджетов профиля.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserSummary {
+    pub anime: TargetSummary,
+    pub manga: TargetSummary,
+
+    /// Время просмотра аниме в минутах (с учетом пересмотров).
+    pub anime_minutes: i64,
+}
+
+impl UserSummary {
+    fn from_stats(stats: &UserStats, anime_minutes: i64) -> Self {
+        // full_statuses включает пересмотр, statuses — только сгруппированные статусы
+        let statuses = if stats.full_statuses.anime.is_empty() && stats.full_statuses.manga.is_empty() {
+            &stats.statuses
+        } else {
+            &stats.full_statuses
+        };
+        Self {
+            anime: TargetSummary::from_stats(&statuses.anime, &stats.scores.anime),
+            manga: TargetSummary::from_stats(&statuses.manga, &stats.scores.manga),
+            anime_minutes,
+        }
+    }
+
+    /// Время просмотра аниме.
+    pub fn anime_time(&self) -> Duration {
+        Duration::from_secs(self.anime_minutes.max(0) as u64 * 60)
+    }
+}
+
+#[derive(Deserialize)]
+struct WatchedAnime {
+    duration: Option<i32>,
+    episodes: Option<i32>,
+    #[serde(rename = "episodesAired")]
+    episodes_aired: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct WatchedRate {
+    episodes: Option<i32>,
+    rewatches: Option<i32>,
+    anime: Option<WatchedAnime>,
+}
+
+impl WatchedRate {
+    fn minutes(&self) -> i64 {
+        let Some(anime) = &self.anime else { return 0 };
+        let duration = anime.duration.unwrap_or(0) as i64;
+        let total_episodes = anime.episodes.filter(|&e| e > 0).or(anime.episodes_aired).unwrap_or(0) as i64;
+        let watched = self.episodes.unwrap_or(0) as i64 + self.rewatches.unwrap_or(0) as i64 * total_episodes;
+        duration * watched
+    }
+}
+
+impl ShikicrateClient {
+    /// Получение сводной статистики пользователя: количество тайтлов по статусам,
+    /// распределение оценок и общее время просмотра аниме.
+    ///
+    /// Время просмотра считается по всему списку аниме пользователя, поэтому для
+    /// больших списков выполняется несколько запросов (по 50 записей).
+    /// Сырые распределения без обработки возвращает [`user_stats`](Self::user_stats).
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use shikicrate::types::WatchStatus;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let summary = client.user_summary(1).await?;
+    ///
+    /// println!("Просмотрено: {:?}", summary.anime.statuses.get(&WatchStatus::Completed));
+    /// println!("Средняя оценка: {:?}", summary.anime.mean_score());
+    /// println!("Часов за просмотром: {}", summary.anime_time().as_secs() / 3600);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_summary(&self, user_id: i64) -> Result<UserSummary> {
+        if user_id <= 0 {
+            return Err(ValidationError::new("user_id", Constraint::Min(1), "ID пользователя должен быть больше 0").value(user_id).into());
+        }
+
+        let stats = self.user_stats(user_id).await?;
+        let anime_minutes = self.anime_watch_minutes(user_id).await?;
+        Ok(UserSummary::from_stats(&stats, anime_minutes))
+    }
+
+    async fn anime_watch_minutes(&self, user_id: i64) -> Result<i64> {
+        let mut minutes = 0;
+        for page in 1.. {
+            let response: serde_json::Value = self
+                .execute_query(
+                    USER_WATCH_TIME_QUERY,
+                    Some(json!({ "userId": user_id, "page": page, "limit": WATCH_TIME_PAGE_SIZE })),
+                )
+                .await?;
+            let rates: Vec<WatchedRate> =
+                serde_json::from_value(response.get("userRates").cloned().unwrap_or_else(|| json!([])))?;
+
+            minutes += rates.iter().map(WatchedRate::minutes).sum::<i64>();
+            if rates.len() < WATCH_TIME_PAGE_SIZE as usize {
+                break;
+            }
+        }
+        Ok(minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_summary_from_stats() {
+        let stats: UserStats = serde_json::from_value(json!({
+            "full_statuses": {
+                "anime": [
+                    { "id": 0, "grouped_id": "planned", "name": "planned", "size": 3 },
+                    { "id": 2, "grouped_id": "completed", "name": "completed", "size": 10 },
+                    { "id": 9, "grouped_id": "rewatching", "name": "rewatching", "size": 1 }
+                ]
+            },
+            "scores": {
+                "anime": [{ "name": "8", "value": 2 }, { "name": "10", "value": 1 }]
+            }
+        }))
+        .unwrap();
+
+        let summary = UserSummary::from_stats(&stats, 90);
+        assert_eq!(summary.anime.total(), 14);
+        assert_eq!(summary.anime.statuses[&WatchStatus::Rewatching], 1);
+        assert_eq!(summary.anime.scores[0].score, 10);
+        assert!((summary.anime.mean_score().unwrap() - 26.0 / 3.0).abs() < 1e-9);
+        assert!(summary.manga.mean_score().is_none());
+        assert_eq!(summary.anime_time(), Duration::from_secs(90 * 60));
+    }
+
+    #[test]
+    fn test_watched_rate_minutes() {
+        let rate: WatchedRate = serde_json::from_value(json!({
+            "episodes": 12,
+            "rewatches": 1,
+            "anime": { "duration": 24, "episodes": 12, "episodesAired": 12 }
+        }))
+        .unwrap();
+        assert_eq!(rate.minutes(), 24 * 24);
+    }
+}
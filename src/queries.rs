@@ -1,11 +1,16 @@
 use crate::client::ShikicrateClient;
-use crate::error::{Result, ShikicrateError};
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
 use crate::types::*;
+use crate::warnings::{MAX_LIMIT, Warning};
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
 use serde_json::json;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
 
 const ANIMES_QUERY: &str = r#"
-  query SearchAnimes($search: String, $ids: String, $limit: Int, $page: Int, $kind: AnimeKindString, $status: AnimeStatusString, $genre: String, $studio: String, $order: OrderEnum, $censored: Boolean) {
-    animes(search: $search, ids: $ids, limit: $limit, page: $page, kind: $kind, status: $status, genre: $genre, studio: $studio, order: $order, censored: $censored) {
+  query SearchAnimes($search: String, $ids: String, $excludeIds: String, $limit: Int, $page: Int, $kind: AnimeKindString, $status: AnimeStatusString, $season: SeasonString, $score: Int, $rating: RatingString, $duration: DurationString, $genre: String, $studio: String, $franchise: String, $order: OrderEnum, $censored: Boolean) {
+    animes(search: $search, ids: $ids, excludeIds: $excludeIds, limit: $limit, page: $page, kind: $kind, status: $status, season: $season, score: $score, rating: $rating, duration: $duration, genre: $genre, studio: $studio, franchise: $franchise, order: $order, censored: $censored) {
       id
       name
       russian
@@ -29,8 +34,8 @@ const ANIMES_QUERY: &str = r#"
 "#;
 
 const ANIMES_LITE_QUERY: &str = r#"
-  query SearchAnimesLite($search: String, $ids: String, $limit: Int, $page: Int, $kind: AnimeKindString, $status: AnimeStatusString, $genre: String, $studio: String) {
-    animes(search: $search, ids: $ids, limit: $limit, page: $page, kind: $kind, status: $status, genre: $genre, studio: $studio) {
+  query SearchAnimesLite($search: String, $ids: String, $excludeIds: String, $limit: Int, $page: Int, $kind: AnimeKindString, $status: AnimeStatusString, $season: SeasonString, $score: Int, $rating: RatingString, $duration: DurationString, $genre: String, $studio: String, $franchise: String, $order: OrderEnum, $censored: Boolean) {
+    animes(search: $search, ids: $ids, excludeIds: $excludeIds, limit: $limit, page: $page, kind: $kind, status: $status, season: $season, score: $score, rating: $rating, duration: $duration, genre: $genre, studio: $studio, franchise: $franchise, order: $order, censored: $censored) {
       id
       name
       russian
@@ -70,6 +75,13 @@ const ANIME_DETAILS_QUERY: &str = r#"
       }
       url
       season
+      fansubbers
+      fandubbers
+      licensors
+      createdAt
+      updatedAt
+      nextEpisodeAt
+      isCensored
       poster {
         id
         originalUrl
@@ -122,6 +134,7 @@ const ANIME_DETAILS_QUERY: &str = r#"
       related {
         id
         relationKind
+        relationText
         anime {
           id
           name
@@ -171,6 +184,7 @@ const ANIME_DETAILS_QUERY: &str = r#"
       }
       description
       descriptionHtml
+      descriptionSource
     }
   }
 "#;
@@ -223,6 +237,10 @@ const MANGA_DETAILS_QUERY: &str = r#"
         date
       }
       url
+      licensors
+      createdAt
+      updatedAt
+      isCensored
       poster {
         id
         originalUrl
@@ -274,6 +292,7 @@ const MANGA_DETAILS_QUERY: &str = r#"
       related {
         id
         relationKind
+        relationText
         anime {
           id
           name
@@ -309,6 +328,7 @@ const MANGA_DETAILS_QUERY: &str = r#"
       }
       description
       descriptionHtml
+      descriptionSource
     }
   }
 "#;
@@ -358,8 +378,77 @@ const MANGAS_WITH_KIND_QUERY: &str = r#"
 "#;
 
 const PEOPLE_QUERY: &str = r#"
-  query SearchPeople($search: String, $limit: Int) {
-    people(search: $search, limit: $limit) {
+  query SearchPeople($search: String, $ids: String, $page: Int, $limit: Int, $isSeyu: Boolean, $isMangaka: Boolean, $isProducer: Boolean) {
+    people(search: $search, ids: $ids, page: $page, limit: $limit, isSeyu: $isSeyu, isMangaka: $isMangaka, isProducer: $isProducer) {
+      id
+      malId
+      name
+      russian
+      japanese
+      synonyms
+      url
+      isSeyu
+      isMangaka
+      isProducer
+      website
+      createdAt
+      updatedAt
+      birthOn {
+        year
+        month
+        day
+        date
+      }
+      deceasedOn {
+        year
+        month
+        day
+        date
+      }
+      poster {
+        id
+        originalUrl
+        mainUrl
+      }
+      works {
+        anime {
+          id
+          name
+          russian
+          kind
+          url
+        }
+        manga {
+          id
+          name
+          russian
+          kind
+          url
+        }
+        role
+      }
+      roles {
+        characters {
+          id
+          name
+          russian
+          url
+        }
+        animes {
+          id
+          name
+          russian
+          kind
+          url
+        }
+      }
+    }
+  }
+"#;
+
+const PERSON_DETAILS_QUERY: &str = r#"
+  query GetPersonDetails($ids: String) {
+    people(ids: $ids) {
       id
       malId
       name
@@ -390,6 +479,38 @@ const PEOPLE_QUERY: &str = r#"
         originalUrl
         mainUrl
       }
+      works {
+        anime {
+          id
+          name
+          russian
+          kind
+          url
+        }
+        manga {
+          id
+          name
+          russian
+          kind
+          url
+        }
+        role
+      }
+      roles {
+        characters {
+          id
+          name
+          russian
+          url
+        }
+        animes {
+          id
+          name
+          russian
+          kind
+          url
+        }
+      }
     }
   }
 "#;
@@ -417,6 +538,41 @@ const CHARACTERS_QUERY: &str = r#"
       description
       descriptionHtml
       descriptionSource
+      seyu {
+        id
+        name
+        russian
+        poster {
+          id
+          mainUrl
+        }
+      }
+      animes {
+        id
+        name
+        russian
+        kind
+        airedOn {
+          year
+        }
+        poster {
+          id
+          mainUrl
+        }
+      }
+      mangas {
+        id
+        name
+        russian
+        kind
+        airedOn {
+          year
+        }
+        poster {
+          id
+          mainUrl
+        }
+      }
     }
   }
 "#;
@@ -436,9 +592,9 @@ const CHARACTERS_BY_IDS_QUERY: &str = r#"
   }
 "#;
 
-const CHARACTER_DETAILS_QUERY: &str = r#"
-  query GetCharacterDetails($ids: String) {
-    characters(ids: $ids) {
+pub(crate) const CHARACTER_DETAILS_QUERY: &str = r#"
+  query GetCharacterDetails($ids: String, $limit: Int) {
+    characters(ids: $ids, limit: $limit) {
       id
       malId
       name
@@ -459,19 +615,56 @@ const CHARACTER_DETAILS_QUERY: &str = r#"
       description
       descriptionHtml
       descriptionSource
+      seyu {
+        id
+        name
+        russian
+        poster {
+          id
+          mainUrl
+        }
+      }
+      animes {
+        id
+        name
+        russian
+        kind
+        airedOn {
+          year
+        }
+        poster {
+          id
+          mainUrl
+        }
+      }
+      mangas {
+        id
+        name
+        russian
+        kind
+        airedOn {
+          year
+        }
+        poster {
+          id
+          mainUrl
+        }
+      }
     }
   }
 "#;
 
 const USER_RATES_QUERY: &str = r#"
-  query SearchUserRates($page: Int, $limit: Int) {
-    userRates(page: $page, limit: $limit) {
+  query SearchUserRates($page: Int, $limit: Int, $userId: ID, $targetType: UserRateTargetTypeEnum, $status: UserRateStatusEnum, $order: UserRateOrderInputType) {
+    userRates(page: $page, limit: $limit, userId: $userId, targetType: $targetType, status: $status, order: $order) {
       id
       score
       status
       episodes
       volumes
       chapters
+      rewatches
+      text
       anime {
         id
         name
@@ -499,151 +692,1040 @@ const USER_RATES_QUERY: &str = r#"
         chapters
       }
       createdAt
+      updatedAt
     }
   }
 "#;
 
-const RELATED_ANIME_QUERY: &str = r#"
-  query GetRelatedAnime($ids: String) {
+const ANIME_EXTERNAL_LINKS_QUERY: &str = r#"
+  query GetAnimeExternalLinks($ids: String) {
     animes(ids: $ids, limit: 1) {
       id
-      related {
+      externalLinks {
         id
-        relationKind
-        relationText
-        anime {
-          id
-          name
-          russian
-          airedOn {
-            year
-          }
-          poster {
-            id
-            mainUrl
-          }
-        }
-        manga {
-          id
-          name
-          russian
-          airedOn {
-            year
-          }
-          poster {
-            id
-            mainUrl
-          }
-        }
+        kind
+        url
+        createdAt
+        updatedAt
       }
     }
   }
 "#;
 
-const RELATED_MANGA_QUERY: &str = r#"
-  query GetRelatedManga($ids: String) {
+const MANGA_EXTERNAL_LINKS_QUERY: &str = r#"
+  query GetMangaExternalLinks($ids: String) {
     mangas(ids: $ids, limit: 1) {
       id
-      related {
+      externalLinks {
         id
-        relationKind
-        relationText
-        anime {
-          id
-          name
-          russian
-          airedOn {
-            year
-          }
-          poster {
-            id
-            mainUrl
-          }
-        }
-        manga {
-          id
-          name
-          russian
-          airedOn {
-            year
-          }
-          poster {
-            id
-            mainUrl
-          }
-        }
+        kind
+        url
+        createdAt
+        updatedAt
       }
     }
   }
 "#;
 
-#[derive(Clone, Default)]
-pub struct AnimeSearchParams {
-    pub search: Option<String>,
-    pub ids: Option<String>,
-    pub limit: Option<i32>,
-    pub kind: Option<String>,
-    pub status: Option<String>,
-    pub season: Option<String>,
-    pub rating: Option<String>,
-    pub genre: Option<String>,
-    pub studio: Option<String>,
-    pub page: Option<i32>,
-    pub order: Option<String>,
-    pub censored: Option<bool>,
-}
-
-#[derive(Clone, Default)]
-pub struct MangaSearchParams {
-    pub limit: Option<i32>,
-    pub search: Option<String>,
-    pub ids: Option<String>,
-    pub kind: Option<String>,
-    pub status: Option<String>,
-    pub genre: Option<String>,
-    pub publisher: Option<String>,
-    pub page: Option<i32>,
-    pub order: Option<String>,
-    pub censored: Option<bool>,
-}
-
-#[derive(Clone, Default)]
-pub struct PeopleSearchParams {
-    pub limit: Option<i32>,
-    pub search: Option<String>,
-}
+const TOP_ANIMES_QUERY: &str = r#"
+  query TopAnimes($limit: Int, $kind: AnimeKindString) {
+    animes(limit: $limit, kind: $kind, order: ranked) {
+      id
+      name
+      russian
+      kind
+      score
+      poster {
+        id
+        mainUrl
+      }
+    }
+  }
+"#;
 
-#[derive(Clone, Default)]
-pub struct CharacterSearchParams {
-    pub search: Option<String>,
-    pub page: Option<i32>,
-    pub limit: Option<i32>,
-    pub ids: Option<Vec<String>>,
+const SEARCH_ALL_QUERY: &str = r#"
+  query SearchAll($search: String, $limit: Int) {
+    foundAnimes: animes(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      kind
+      score
+      status
+      episodes
+      episodesAired
+      airedOn {
+        year
+        month
+        day
+        date
+      }
+      poster {
+        id
+        mainUrl
+      }
+    }
+    foundMangas: mangas(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      kind
+      score
+      status
+      volumes
+      chapters
+      poster {
+        id
+        mainUrl
+      }
+    }
+    foundCharacters: characters(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      poster {
+        id
+        mainUrl
+      }
+    }
+    foundPeople: people(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      poster {
+        id
+        mainUrl
+      }
+    }
+  }
+"#;
+
+pub(crate) const USER_WATCH_TIME_QUERY: &str = r#"
+  query UserWatchTime($userId: ID, $page: Int, $limit: Int) {
+    userRates(userId: $userId, targetType: Anime, page: $page, limit: $limit) {
+      status
+      episodes
+      rewatches
+      anime {
+        duration
+        episodes
+        episodesAired
+      }
+    }
+  }
+"#;
+
+pub(crate) const AUTOCOMPLETE_QUERY: &str = r#"
+  query Autocomplete($search: String, $limit: Int) {
+    animes(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      kind
+      url
+    }
+    mangas(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      kind
+      url
+    }
+    characters(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      url
+    }
+    people(search: $search, limit: $limit) {
+      id
+      name
+      russian
+      url
+    }
+  }
+"#;
+
+pub(crate) const ANIME_LINKS_QUERY: &str = r#"
+  query GetAnimeLinks($ids: String, $limit: Int) {
+    animes(ids: $ids, limit: $limit) {
+      id
+      name
+      russian
+      franchise
+      studios {
+        id
+        name
+      }
+      related {
+        id
+        relationKind
+        relationText
+        anime {
+          id
+        }
+        manga {
+          id
+        }
+      }
+    }
+  }
+"#;
+
+pub(crate) const MANGA_LINKS_QUERY: &str = r#"
+  query GetMangaLinks($ids: String, $limit: Int) {
+    mangas(ids: $ids, limit: $limit) {
+      id
+      name
+      russian
+      franchise
+      publishers {
+        id
+        name
+      }
+      related {
+        id
+        relationKind
+        relationText
+        anime {
+          id
+        }
+        manga {
+          id
+        }
+      }
+    }
+  }
+"#;
+
+pub(crate) const ANIME_ROLES_QUERY: &str = r#"
+  query GetAnimeRoles($ids: String) {
+    animes(ids: $ids, limit: 1) {
+      id
+      characterRoles {
+        id
+        rolesRu
+        rolesEn
+        character {
+          id
+          name
+          russian
+        }
+      }
+      personRoles {
+        id
+        rolesRu
+        rolesEn
+        person {
+          id
+          name
+          russian
+          poster {
+            id
+            mainUrl
+          }
+        }
+      }
+    }
+  }
+"#;
+
+pub(crate) const MANGA_ROLES_QUERY: &str = r#"
+  query GetMangaRoles($ids: String) {
+    mangas(ids: $ids, limit: 1) {
+      id
+      characterRoles {
+        id
+        rolesRu
+        rolesEn
+        character {
+          id
+          name
+          russian
+        }
+      }
+      personRoles {
+        id
+        rolesRu
+        rolesEn
+        person {
+          id
+          name
+          russian
+          poster {
+            id
+            mainUrl
+          }
+        }
+      }
+    }
+  }
+"#;
+
+const USERS_QUERY: &str = r#"
+  query SearchUsers($search: String, $page: Int, $limit: Int) {
+    users(search: $search, page: $page, limit: $limit) {
+      id
+      nickname
+      avatarUrl
+      lastOnlineAt
+      url
+    }
+  }
+"#;
+
+const RELATED_ANIME_QUERY: &str = r#"
+  query GetRelatedAnime($ids: String) {
+    animes(ids: $ids, limit: 1) {
+      id
+      related {
+        id
+        relationKind
+        relationText
+        anime {
+          id
+          name
+          russian
+          airedOn {
+            year
+          }
+          poster {
+            id
+            mainUrl
+          }
+        }
+        manga {
+          id
+          name
+          russian
+          airedOn {
+            year
+          }
+          poster {
+            id
+            mainUrl
+          }
+        }
+      }
+    }
+  }
+"#;
+
+const RELATED_MANGA_QUERY: &str = r#"
+  query GetRelatedManga($ids: String) {
+    mangas(ids: $ids, limit: 1) {
+      id
+      related {
+        id
+        relationKind
+        relationText
+        anime {
+          id
+          name
+          russian
+          airedOn {
+            year
+          }
+          poster {
+            id
+            mainUrl
+          }
+        }
+        manga {
+          id
+          name
+          russian
+          airedOn {
+            year
+          }
+          poster {
+            id
+            mainUrl
+          }
+        }
+      }
+    }
+  }
+"#;
+
+/// Собирает значение фильтра `genre` из включаемых и исключаемых ID жанров.
+///
+/// # Примеры
+///
+/// ```
+/// use shikicrate::queries::genre_filter;
+///
+/// // Комедии без этти
+/// assert_eq!(genre_filter(&[4], &[9]), "4,!9");
+/// ```
+pub fn genre_filter(include: &[i64], exclude: &[i64]) -> String {
+    include
+        .iter()
+        .map(|id| id.to_string())
+        .chain(exclude.iter().map(|id| format!("!{}", id)))
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
+/// Количество одновременных поисков в `search_many()`.
+const SEARCH_MANY_CONCURRENCY: usize = 4;
+
+/// Количество ID в одном запросе `animes_by_ids()` и `mangas_by_ids()`.
+const IDS_BATCH_SIZE: usize = MAX_LIMIT as usize;
+
+/// Параметры поиска аниме.
+///
+/// Структуры `*SearchParams` помечены `#[non_exhaustive]`: новые фильтры
+/// добавляются без поломки совместимости. Вне крейта параметры создаются
+/// через `builder()` или `Default::default()` с присваиванием полей:
+///
+/// ```no_run
+/// use shikicrate::AgeRating;
+/// use shikicrate::queries::*;
+///
+/// let params = AnimeSearchParams::builder().search("naruto").limit(10);
+///
+/// let mut params = AnimeSearchParams::default();
+/// params.limit = Some(10);
+///
+/// // Без взрослого контента: rating = "!rx,!r_plus"
+/// let params = AnimeSearchParams::builder()
+///     .exclude_rating(AgeRating::Rx)
+///     .exclude_rating(AgeRating::RPlus);
+/// ```
 #[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct AnimeSearchParams {
+    pub search: Option<String>,
+    /// Список ID аниме для выборки.
+    pub ids: Option<Vec<i64>>,
+    /// ID аниме, которые нужно исключить из результатов.
+    pub exclude_ids: Option<Vec<i64>>,
+    pub limit: Option<i32>,
+    pub kind: Option<String>,
+    pub status: Option<String>,
+    /// Сезон выхода: `"winter_2024"`, `"2023"`, диапазон `"2020_2023"` и т.д.
+    pub season: Option<String>,
+    /// Минимальная оценка тайтла (целое число от 1 до 10).
+    pub score: Option<i32>,
+    /// Возрастные рейтинги через запятую: `g`, `pg`, `pg_13`, `r`, `r_plus`, `rx`;
+    /// `!` перед рейтингом исключает его (`"!rx,!r_plus"`).
+    pub rating: Option<String>,
+    /// Длительность эпизода: `S` (до 10 минут), `D` (до 30 минут), `F` (более 30 минут);
+    /// значения через запятую, `!` исключает (`"S,D"`, `"!F"`).
+    pub duration: Option<String>,
+    /// ID жанров через запятую; `!` перед ID исключает жанр (`"4,!9"`).
+    /// Удобно собирать через [`genre_filter`].
+    pub genre: Option<String>,
+    /// ID студий через запятую (ID можно узнать через `studios()`),
+    /// например `"2"` для Kyoto Animation.
+    pub studio: Option<String>,
+    /// Франшиза (латиницей, как в URL Shikimori), например `"monogatari"`.
+    pub franchise: Option<String>,
+    pub page: Option<i32>,
+    /// Сортировка на стороне сервера.
+    pub order: Option<AnimeOrder>,
+    pub censored: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct MangaSearchParams {
+    pub limit: Option<i32>,
+    pub search: Option<String>,
+    /// Список ID манги для выборки.
+    pub ids: Option<Vec<i64>>,
+    pub kind: Option<String>,
+    pub status: Option<String>,
+    pub genre: Option<String>,
+    pub publisher: Option<String>,
+    pub page: Option<i32>,
+    pub order: Option<String>,
+    pub censored: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct PeopleSearchParams {
+    pub limit: Option<i32>,
+    pub search: Option<String>,
+    pub page: Option<i32>,
+    /// Список ID людей для выборки (не больше 50). `search` и `page` при этом
+    /// игнорируются.
+    pub ids: Option<Vec<i64>>,
+    /// Только сейю.
+    pub is_seyu: Option<bool>,
+    /// Только мангаки.
+    pub is_mangaka: Option<bool>,
+    /// Только продюсеры.
+    pub is_producer: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct CharacterSearchParams {
+    pub search: Option<String>,
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+    pub ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
 pub struct UserRateSearchParams {
     pub page: Option<i32>,
     pub limit: Option<i32>,
+    /// ID пользователя, чей список запрашивается (по умолчанию — владелец токена).
+    pub user_id: Option<i64>,
+    /// Статус записи.
+    pub status: Option<WatchStatus>,
     pub target_type: Option<String>,
-    pub order_field: Option<String>,
-    pub order: Option<String>,
+    /// Поле сортировки.
+    pub order: Option<UserRateOrder>,
+    /// Направление сортировки (по умолчанию по убыванию). Учитывается только вместе с `order`.
+    pub direction: Option<SortDirection>,
+}
+
+/// Сортировка результатов поиска аниме (GraphQL `OrderEnum`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimeOrder {
+    Id,
+    IdDesc,
+    /// По месту в рейтинге.
+    Ranked,
+    Kind,
+    /// По популярности.
+    Popularity,
+    Name,
+    /// По дате выхода.
+    AiredOn,
+    Episodes,
+    Status,
+    /// Случайный порядок.
+    Random,
+    /// Случайный порядок среди тайтлов с рейтингом.
+    RankedRandom,
+    /// По рейтингу Shikimori.
+    RankedShiki,
+    CreatedAt,
+    CreatedAtDesc,
+}
+
+/// Сезон выхода аниме.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Fall,
+}
+
+impl Season {
+    /// Значение фильтра `season` для сезона года, например `"winter_2025"`.
+    pub fn filter(self, year: i32) -> String {
+        let name = match self {
+            Season::Winter => "winter",
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Fall => "fall",
+        };
+        format!("{}_{}", name, year)
+    }
+}
+
+/// Длительность эпизода для фильтра `duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpisodeDuration {
+    /// До 10 минут.
+    Short,
+    /// До 30 минут.
+    Medium,
+    /// Более 30 минут.
+    Long,
+}
+
+impl EpisodeDuration {
+    /// Значение в формате API (`"S"`, `"D"`, `"F"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EpisodeDuration::Short => "S",
+            EpisodeDuration::Medium => "D",
+            EpisodeDuration::Long => "F",
+        }
+    }
+}
+
+/// Добавляет значение в фильтр со списком через запятую.
+fn push_filter(filter: &mut Option<String>, value: &str) {
+    *filter = Some(match filter.take() {
+        Some(current) if !current.is_empty() => format!("{},{}", current, value),
+        _ => value.to_string(),
+    });
+}
+
+/// Тип аниме для фильтра `kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimeKind {
+    Tv,
+    Movie,
+    Ova,
+    Ona,
+    Special,
+    TvSpecial,
+    Music,
+    Pv,
+    Cm,
+}
+
+impl AnimeKind {
+    /// Значение в формате API (`"tv"`, `"tv_special"` и т.д.).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AnimeKind::Tv => "tv",
+            AnimeKind::Movie => "movie",
+            AnimeKind::Ova => "ova",
+            AnimeKind::Ona => "ona",
+            AnimeKind::Special => "special",
+            AnimeKind::TvSpecial => "tv_special",
+            AnimeKind::Music => "music",
+            AnimeKind::Pv => "pv",
+            AnimeKind::Cm => "cm",
+        }
+    }
+}
+
+/// Поле сортировки пользовательских оценок.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRateOrder {
+    Id,
+    UpdatedAt,
+    CreatedAt,
+    Score,
+}
+
+/// Направление сортировки.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct UserSearchParams {
+    pub search: Option<String>,
+    pub limit: Option<i32>,
+    pub page: Option<i32>,
+}
+
+/// Fluent builder: `AnimeSearchParams::builder().search("naruto").limit(10)`.
+///
+/// Незаданные фильтры остаются `None`.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{ShikicrateClient, queries::*};
+///
+/// # async fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClient::new()?;
+/// let params = AnimeSearchParams::builder()
+///     .search("naruto")
+///     .kind(AnimeKind::Tv)
+///     .order(AnimeOrder::Popularity)
+///     .limit(10);
+/// let animes = client.animes(params).await?;
+/// # Ok(())
+/// # }
+/// ```
+impl AnimeSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    pub fn ids(mut self, ids: impl IntoIterator<Item = i64>) -> Self {
+        self.ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    pub fn exclude_ids(mut self, ids: impl IntoIterator<Item = i64>) -> Self {
+        self.exclude_ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Добавляет тип в фильтр `kind`; повторные вызовы собирают список
+    /// через запятую (`.kind(AnimeKind::Tv).kind(AnimeKind::Movie)` → `"tv,movie"`).
+    pub fn kind(mut self, kind: AnimeKind) -> Self {
+        push_filter(&mut self.kind, kind.as_str());
+        self
+    }
+
+    /// Исключает тип из результатов (`"!special"`); сочетается с [`kind`](Self::kind).
+    pub fn exclude_kind(mut self, kind: AnimeKind) -> Self {
+        push_filter(&mut self.kind, &format!("!{}", kind.as_str()));
+        self
+    }
+
+    pub fn status(mut self, status: AnimeStatus) -> Self {
+        self.status = Some(status.as_str().to_string());
+        self
+    }
+
+    /// Добавляет сезон года в фильтр `season` (`"winter_2024"`); повторные вызовы
+    /// и [`year`](Self::year)/[`years`](Self::years) собирают список через запятую.
+    pub fn season(mut self, season: Season, year: i32) -> Self {
+        push_filter(&mut self.season, &season.filter(year));
+        self
+    }
+
+    /// Добавляет год выхода в фильтр `season` (`"2023"`).
+    pub fn year(mut self, year: i32) -> Self {
+        push_filter(&mut self.season, &year.to_string());
+        self
+    }
+
+    /// Добавляет диапазон лет в фильтр `season` (`2020..=2023` → `"2020_2023"`).
+    pub fn years(mut self, years: RangeInclusive<i32>) -> Self {
+        push_filter(&mut self.season, &format!("{}_{}", years.start(), years.end()));
+        self
+    }
+
+    pub fn score(mut self, score: i32) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Добавляет возрастной рейтинг в фильтр `rating`; повторные вызовы
+    /// собирают список через запятую.
+    pub fn rating(mut self, rating: AgeRating) -> Self {
+        push_filter(&mut self.rating, rating.as_str());
+        self
+    }
+
+    /// Исключает рейтинг из результатов (`"!rx"`); сочетается с [`rating`](Self::rating).
+    pub fn exclude_rating(mut self, rating: AgeRating) -> Self {
+        push_filter(&mut self.rating, &format!("!{}", rating.as_str()));
+        self
+    }
+
+    /// Добавляет длительность эпизода в фильтр `duration`; повторные вызовы
+    /// собирают список через запятую.
+    pub fn duration(mut self, duration: EpisodeDuration) -> Self {
+        push_filter(&mut self.duration, duration.as_str());
+        self
+    }
+
+    /// Исключает длительность эпизода из результатов (`"!F"`).
+    pub fn exclude_duration(mut self, duration: EpisodeDuration) -> Self {
+        push_filter(&mut self.duration, &format!("!{}", duration.as_str()));
+        self
+    }
+
+    /// Фильтр по жанрам; собирается через [`genre_filter`].
+    pub fn genre(mut self, genre: impl Into<String>) -> Self {
+        self.genre = Some(genre.into());
+        self
+    }
+
+    pub fn studio(mut self, studio: impl Into<String>) -> Self {
+        self.studio = Some(studio.into());
+        self
+    }
+
+    pub fn franchise(mut self, franchise: impl Into<String>) -> Self {
+        self.franchise = Some(franchise.into());
+        self
+    }
+
+    pub fn order(mut self, order: AnimeOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn censored(mut self, censored: bool) -> Self {
+        self.censored = Some(censored);
+        self
+    }
+}
+
+/// Fluent builder: `MangaSearchParams::builder().search("berserk").limit(5)`.
+impl MangaSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    pub fn ids(mut self, ids: impl IntoIterator<Item = i64>) -> Self {
+        self.ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    pub fn genre(mut self, genre: impl Into<String>) -> Self {
+        self.genre = Some(genre.into());
+        self
+    }
+
+    pub fn publisher(mut self, publisher: impl Into<String>) -> Self {
+        self.publisher = Some(publisher.into());
+        self
+    }
+
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn censored(mut self, censored: bool) -> Self {
+        self.censored = Some(censored);
+        self
+    }
+}
+
+/// Fluent builder: `PeopleSearchParams::builder().search("miyazaki").is_mangaka(true)`.
+impl PeopleSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    pub fn ids(mut self, ids: impl IntoIterator<Item = i64>) -> Self {
+        self.ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn is_seyu(mut self, is_seyu: bool) -> Self {
+        self.is_seyu = Some(is_seyu);
+        self
+    }
+
+    pub fn is_mangaka(mut self, is_mangaka: bool) -> Self {
+        self.is_mangaka = Some(is_mangaka);
+        self
+    }
+
+    pub fn is_producer(mut self, is_producer: bool) -> Self {
+        self.is_producer = Some(is_producer);
+        self
+    }
+}
+
+/// Fluent builder: `CharacterSearchParams::builder().search("spike").limit(5)`.
+impl CharacterSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    pub fn ids(mut self, ids: impl IntoIterator<Item = i64>) -> Self {
+        self.ids = Some(ids.into_iter().map(|id| id.to_string()).collect());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Fluent builder: `UserRateSearchParams::builder().user_id(1).status(WatchStatus::Watching)`.
+impl UserRateSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    pub fn status(mut self, status: WatchStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Тип тайтла: `"Anime"` или `"Manga"`.
+    pub fn target_type(mut self, target_type: impl Into<String>) -> Self {
+        self.target_type = Some(target_type.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn order(mut self, order: UserRateOrder, direction: SortDirection) -> Self {
+        self.order = Some(order);
+        self.direction = Some(direction);
+        self
+    }
+}
+
+/// Fluent builder: `UserSearchParams::builder().search("morr").limit(5)`.
+impl UserSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
 }
 
 impl ShikicrateClient {
     fn val_lim(limit: Option<i32>) -> Result<()> {
         if let Some(limit) = limit {
             if limit <= 0 {
-                return Err(ShikicrateError::Validation("Лимит должен быть больше 0".to_string()));
+                return Err(ValidationError::new("limit", Constraint::Min(1), "Лимит должен быть больше 0")
+                    .value(limit)
+                    .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn val_pg(page: Option<i32>) -> Result<()> {
+        if let Some(page) = page {
+            if page < 1 {
+                return Err(ValidationError::new("page", Constraint::Min(1), "Страница должна быть не меньше 1")
+                    .value(page)
+                    .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn val_score(score: Option<i32>) -> Result<()> {
+        if let Some(score) = score
+            && !(1..=10).contains(&score)
+        {
+            return Err(ValidationError::new(
+                "score",
+                Constraint::Range { min: 1, max: 10 },
+                "Минимальная оценка должна быть от 1 до 10",
+            )
+            .value(score)
+            .into());
+        }
+        Ok(())
+    }
+
+    fn val_rating(rating: Option<&str>) -> Result<()> {
+        if let Some(rating) = rating {
+            for value in rating.split(',') {
+                let value = value.trim();
+                let rating = AgeRating::from(value.strip_prefix('!').unwrap_or(value).to_string());
+                if matches!(rating, AgeRating::Unknown(_)) {
+                    return Err(ValidationError::new(
+                        "rating",
+                        Constraint::OneOf(
+                            ["none", "g", "pg", "pg_13", "r", "r_plus", "rx"].map(String::from).to_vec(),
+                        ),
+                        format!("Неизвестный возрастной рейтинг: {}", value),
+                    )
+                    .value(value)
+                    .into());
+                }
             }
         }
         Ok(())
     }
 
-    fn val_pg(page: Option<i32>) -> Result<()> {
-        if let Some(page) = page {
-            if page < 1 {
-                return Err(ShikicrateError::Validation("Страница должна быть не меньше 1".to_string()));
+    fn val_duration(duration: Option<&str>) -> Result<()> {
+        if let Some(duration) = duration {
+            for value in duration.split(',') {
+                let value = value.trim();
+                if !["S", "D", "F"].contains(&value.strip_prefix('!').unwrap_or(value)) {
+                    return Err(ValidationError::new(
+                        "duration",
+                        Constraint::OneOf(["S", "D", "F"].map(String::from).to_vec()),
+                        format!("Неизвестная длительность: {} (ожидается S, D или F)", value),
+                    )
+                    .value(value)
+                    .into());
+                }
             }
         }
         Ok(())
@@ -652,29 +1734,131 @@ impl ShikicrateClient {
     fn val_ids(ids: Option<&Vec<String>>) -> Result<()> {
         if let Some(ids) = ids {
             if ids.is_empty() {
-                return Err(ShikicrateError::Validation("Список ID не должен быть пустым".to_string()));
+                return Err(ValidationError::new("ids", Constraint::NotEmpty, "Список ID не должен быть пустым").into());
             }
         }
         Ok(())
     }
 
+    fn val_num_ids(ids: Option<&[i64]>) -> Result<()> {
+        if ids.is_some_and(|ids| ids.is_empty()) {
+            return Err(ValidationError::new("ids", Constraint::NotEmpty, "Список ID не должен быть пустым").into());
+        }
+        Ok(())
+    }
+
+    fn join_ids(ids: &[i64]) -> String {
+        ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+    }
+
+    /// Упорядочивает найденные записи по списку ID; ненайденные ID пропускаются.
+    fn in_input_order<T: Clone>(ids: &[i64], items: Vec<T>, id_of: impl Fn(&T) -> i64) -> Vec<T> {
+        let by_id: HashMap<i64, T> = items.into_iter().map(|item| (id_of(&item), item)).collect();
+        ids.iter().filter_map(|id| by_id.get(id).cloned()).collect()
+    }
+
     async fn fetch<T, F>(&self, query: String, build_variables: F, response_key: &str) -> Result<Vec<T>>
     where
         T: serde::de::DeserializeOwned,
         F: FnOnce() -> serde_json::Value,
     {
-        let variables = build_variables();
+        let mut variables = build_variables();
+        self.clamp_limit(&mut variables);
+
         let response: serde_json::Value = self.execute_query(&query, Some(variables)).await?;
 
-        let items = response
-            .get(response_key)
-            .and_then(|v| v.as_array())
-            .cloned()
-            .unwrap_or_default();
+        let items = match response.get(response_key).and_then(|v| v.as_array()) {
+            Some(items) => items.clone(),
+            None => {
+                self.warn(Warning::MissingResponseField {
+                    field: response_key.to_string(),
+                });
+                Vec::new()
+            }
+        };
 
         serde_json::from_value(json!(items)).map_err(crate::error::ShikicrateError::Serialization)
     }
 
+    /// Уменьшает переменную `limit` до 50 с предупреждением.
+    pub(crate) fn clamp_limit(&self, variables: &mut serde_json::Value) {
+        if let Some(requested) = variables.get("limit").and_then(|v| v.as_i64())
+            && requested > MAX_LIMIT as i64
+        {
+            variables["limit"] = json!(MAX_LIMIT);
+            self.warn(Warning::LimitClamped {
+                requested: requested as i32,
+                applied: MAX_LIMIT,
+            });
+        }
+    }
+
+    /// Выполнение произвольного GraphQL запроса, корневое поле которого возвращает список.
+    ///
+    /// Позволяет использовать запросы, для которых в библиотеке еще нет готового
+    /// метода, с той же обработкой, что и у встроенных: переменные `limit` и `page`
+    /// валидируются, `limit` больше 50 уменьшается с предупреждением, а отсутствие
+    /// поля `response_key` в ответе дает [`Warning::MissingResponseField`].
+    /// Для постраничного обхода используйте `fetch_list_paginated()`.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Club {
+    ///     id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let clubs: Vec<Club> = client
+    ///     .fetch_list(
+    ///         "query($page: Int, $limit: Int) { clubs(page: $page, limit: $limit) { id name } }",
+    ///         json!({ "page": 1, "limit": 10 }),
+    ///         "clubs",
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_list<T>(&self, query: &str, variables: serde_json::Value, response_key: &str) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        Self::val_vars(&variables)?;
+        self.fetch(query.to_string(), || variables, response_key).await
+    }
+
+    pub(crate) fn val_vars(variables: &serde_json::Value) -> Result<()> {
+        let int_var = |name: &'static str| -> Result<Option<i32>> {
+            match variables.get(name) {
+                None | Some(serde_json::Value::Null) => Ok(None),
+                Some(value) => value
+                    .as_i64()
+                    .and_then(|v| i32::try_from(v).ok())
+                    .map(Some)
+                    .ok_or_else(|| {
+                        ValidationError::new(name, Constraint::Format, format!("Переменная {} должна быть целым числом", name))
+                            .value(value)
+                            .into()
+                    }),
+            }
+        };
+
+        if !variables.is_object() {
+            return Err(
+                ValidationError::new("variables", Constraint::Format, "Переменные запроса должны быть объектом").into(),
+            );
+        }
+        Self::val_lim(int_var("limit")?)?;
+        Self::val_pg(int_var("page")?)
+    }
+
     fn build_vars(search: Option<String>, page: Option<i32>, limit: Option<i32>) -> serde_json::Value {
         let mut variables = json!({});
         if let Some(search) = search {
@@ -690,20 +1874,164 @@ impl ShikicrateClient {
     }
 
     pub async fn animes(&self, params: AnimeSearchParams) -> Result<Vec<Anime>> {
+        let (query, vars) = Self::anime_request(&params)?;
+        self.fetch(query.to_string(), || vars, "animes").await
+    }
+
+    /// Проверяет параметры поиска аниме и возвращает текст запроса с переменными.
+    pub(crate) fn anime_request(params: &AnimeSearchParams) -> Result<(&'static str, serde_json::Value)> {
         Self::val_lim(params.limit)?;
         Self::val_pg(params.page)?;
+        Self::val_score(params.score)?;
+        Self::val_rating(params.rating.as_deref())?;
+        Self::val_duration(params.duration.as_deref())?;
+        Self::val_num_ids(params.ids.as_deref())?;
+        Self::val_num_ids(params.exclude_ids.as_deref())?;
+
+        Ok((ANIMES_QUERY, Self::anime_vars(params)))
+    }
+
+    /// Получение аниме сезона.
+    ///
+    /// Поле `season` в `params` заменяется на указанный сезон, остальные
+    /// фильтры применяются как в `animes()`.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let lineup = client
+    ///     .seasonal_animes(
+    ///         Season::Winter,
+    ///         2025,
+    ///         AnimeSearchParams::builder()
+    ///             .kind(AnimeKind::Tv)
+    ///             .order(AnimeOrder::Popularity)
+    ///             .limit(50),
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("Зима 2025: {} тайтлов", lineup.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seasonal_animes(&self, season: Season, year: i32, params: AnimeSearchParams) -> Result<Vec<Anime>> {
+        self.animes(AnimeSearchParams {
+            season: Some(season.filter(year)),
+            ..params
+        })
+        .await
+    }
+
+    /// Получение аниме по списку ID.
+    ///
+    /// Списки длиннее 50 ID разбиваются на несколько запросов. Результат
+    /// упорядочен как `ids`; ненайденные ID пропускаются.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let animes = client.animes_by_ids(&[5081, 1, 20]).await?;
+    ///
+    /// for anime in animes {
+    ///     println!("{}: {}", anime.id, anime.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn animes_by_ids(&self, ids: &[i64]) -> Result<Vec<Anime>> {
+        Self::val_num_ids(Some(ids))?;
+
+        let mut animes = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(IDS_BATCH_SIZE) {
+            let params = AnimeSearchParams {
+                ids: Some(chunk.to_vec()),
+                limit: Some(chunk.len() as i32),
+                ..Default::default()
+            };
+            animes.extend(self.animes(params).await?);
+        }
+        Ok(Self::in_input_order(ids, animes, |anime| anime.id))
+    }
+
+    /// Поиск аниме, манги, персонажей и людей одним HTTP запросом.
+    ///
+    /// Использует GraphQL алиасы, поэтому заменяет четыре последовательных
+    /// запроса. `limit` применяется к каждому типу отдельно.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let found = client.search_all("bebop", 5).await?;
+    ///
+    /// println!(
+    ///     "аниме: {}, манга: {}, персонажи: {}, люди: {}",
+    ///     found.animes.len(),
+    ///     found.mangas.len(),
+    ///     found.characters.len(),
+    ///     found.people.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_all(&self, query: &str, limit: i32) -> Result<SearchAllResult> {
+        if query.trim().is_empty() {
+            return Err(ValidationError::new("query", Constraint::NotEmpty, "Строка поиска не должна быть пустой").into());
+        }
+        Self::val_lim(Some(limit))?;
+
+        let applied = limit.min(MAX_LIMIT);
+        if applied < limit {
+            self.warn(Warning::LimitClamped { requested: limit, applied });
+        }
+
+        self.execute_query(
+            SEARCH_ALL_QUERY,
+            Some(json!({ "search": query.trim(), "limit": applied })),
+        )
+        .await
+    }
+
+    /// Получение топа аниме по рейтингу Shikimori.
+    ///
+    /// Запрашивает только поля, нужные для списков лидеров: название, тип,
+    /// оценку и постер. `kind` ограничивает топ одним типом аниме.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::AnimeKind};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    ///
+    /// for (place, anime) in client.top_animes(10, Some(AnimeKind::Movie)).await?.iter().enumerate() {
+    ///     println!("{}. {} — {:?}", place + 1, anime.name, anime.score);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_animes(&self, limit: i32, kind: Option<AnimeKind>) -> Result<Vec<Anime>> {
+        Self::val_lim(Some(limit))?;
 
         self.fetch(
-            ANIMES_QUERY.to_string(),
+            TOP_ANIMES_QUERY.to_string(),
             || {
-                let mut vars = Self::build_vars(params.search.clone(), params.page, params.limit);
-                if let Some(kind) = &params.kind { vars["kind"] = json!(kind); }
-                if let Some(status) = &params.status { vars["status"] = json!(status); }
-                if let Some(genre) = &params.genre { vars["genre"] = json!(genre); }
-                if let Some(studio) = &params.studio { vars["studio"] = json!(studio); }
-                if let Some(ids) = &params.ids { vars["ids"] = json!(ids); }
-                if let Some(order) = &params.order { vars["order"] = json!(order); }
-                if let Some(censored) = params.censored { vars["censored"] = json!(censored); }
+                let mut vars = json!({ "limit": limit });
+                if let Some(kind) = kind {
+                    vars["kind"] = json!(kind.as_str());
+                }
                 vars
             },
             "animes",
@@ -711,27 +2039,80 @@ impl ShikicrateClient {
         .await
     }
 
+    /// Выполняет несколько поисков аниме параллельно (не более 4 одновременно).
+    ///
+    /// `i`-й элемент результата соответствует `i`-му набору параметров. Ошибка
+    /// одного поиска не прерывает остальные. Все запросы проходят через общий
+    /// лимитер клиента, поэтому ограничение частоты запросов соблюдается.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let queries = ["naruto", "bleach", "one piece"]
+    ///     .map(|title| AnimeSearchParams::builder().search(title).limit(1));
+    ///
+    /// for (title, result) in queries.iter().zip(client.search_many(queries.to_vec()).await) {
+    ///     println!("{:?}: {:?}", title.search, result.map(|found| found.len()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_many(&self, params: Vec<AnimeSearchParams>) -> Vec<Result<Vec<Anime>>> {
+        self.search_many_bounded(params, SEARCH_MANY_CONCURRENCY).await
+    }
+
+    /// То же, что `search_many()`, с заданным числом одновременных запросов.
+    ///
+    /// Следующий поиск запускается только после завершения одного из текущих,
+    /// поэтому в полете никогда не больше `concurrency` запросов.
+    pub async fn search_many_bounded(&self, params: Vec<AnimeSearchParams>, concurrency: usize) -> Vec<Result<Vec<Anime>>> {
+        stream::iter(params)
+            .map(|params| self.animes(params))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
     pub async fn animes_lite(&self, params: AnimeSearchParams) -> Result<Vec<Anime>> {
         Self::val_lim(params.limit)?;
         Self::val_pg(params.page)?;
+        Self::val_score(params.score)?;
+        Self::val_rating(params.rating.as_deref())?;
+        Self::val_duration(params.duration.as_deref())?;
+        Self::val_num_ids(params.ids.as_deref())?;
+        Self::val_num_ids(params.exclude_ids.as_deref())?;
 
         self.fetch(
             ANIMES_LITE_QUERY.to_string(),
-            || {
-                let mut vars = Self::build_vars(params.search.clone(), params.page, params.limit);
-                if let Some(kind) = &params.kind { vars["kind"] = json!(kind); }
-                if let Some(status) = &params.status { vars["status"] = json!(status); }
-                if let Some(genre) = &params.genre { vars["genre"] = json!(genre); }
-                if let Some(studio) = &params.studio { vars["studio"] = json!(studio); }
-                if let Some(ids) = &params.ids { vars["ids"] = json!(ids); }
-                vars
-            },
+            || Self::anime_vars(&params),
             "animes",
         )
         .await
     }
 
-    pub async fn anime_detail(&self, id: i64) -> Result<Option<Anime>> {
+    /// Получение полной информации об аниме по ID.
+    ///
+    /// Запрашивает все поля `Anime` (жанры, студии, роли, связанные произведения,
+    /// видео, скриншоты, статистику и т.д.). Возвращает `None`, если аниме не найдено.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// if let Some(anime) = client.anime_by_id(5081).await? {
+    ///     println!("{}: {:?}", anime.name, anime.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn anime_by_id(&self, id: i64) -> Result<Option<Anime>> {
         let mut animes = self.fetch(
             ANIME_DETAILS_QUERY.to_string(),
             || json!({ "ids": id.to_string() }),
@@ -741,29 +2122,77 @@ impl ShikicrateClient {
         Ok(animes.pop())
     }
 
+    #[deprecated(note = "используйте anime_by_id")]
+    pub async fn anime_detail(&self, id: i64) -> Result<Option<Anime>> {
+        self.anime_by_id(id).await
+    }
+
     pub async fn mangas(&self, params: MangaSearchParams) -> Result<Vec<Manga>> {
+        let (query, vars) = Self::manga_request(&params)?;
+        self.fetch(query.to_string(), || vars, "mangas").await
+    }
+
+    /// Проверяет параметры поиска манги и возвращает текст запроса с переменными.
+    pub(crate) fn manga_request(params: &MangaSearchParams) -> Result<(&'static str, serde_json::Value)> {
         Self::val_lim(params.limit)?;
         Self::val_pg(params.page)?;
+        Self::val_num_ids(params.ids.as_deref())?;
 
         let mut vars = Self::build_vars(params.search.clone(), params.page, params.limit);
         if let Some(kind) = &params.kind { vars["kind"] = json!(kind); }
         if let Some(status) = &params.status { vars["status"] = json!(status); }
         if let Some(genre) = &params.genre { vars["genre"] = json!(genre); }
         if let Some(publisher) = &params.publisher { vars["publisher"] = json!(publisher); }
-        if let Some(ids) = &params.ids { vars["ids"] = json!(ids); }
+        if let Some(ids) = &params.ids { vars["ids"] = json!(Self::join_ids(ids)); }
         if let Some(order) = &params.order { vars["order"] = json!(order); }
         if let Some(censored) = params.censored { vars["censored"] = json!(censored); }
 
         let query = if params.kind.is_some() {
-            MANGAS_WITH_KIND_QUERY.to_string()
+            MANGAS_WITH_KIND_QUERY
         } else {
-            MANGAS_QUERY.to_string()
+            MANGAS_QUERY
         };
 
-        self.fetch(query, || vars, "mangas").await
+        Ok((query, vars))
     }
 
-    pub async fn manga_detail(&self, id: i64) -> Result<Option<Manga>> {
+    /// Получение манги по списку ID.
+    ///
+    /// Работает как `animes_by_ids()`: длинные списки разбиваются на запросы
+    /// по 50 ID, результат упорядочен как `ids`.
+    pub async fn mangas_by_ids(&self, ids: &[i64]) -> Result<Vec<Manga>> {
+        Self::val_num_ids(Some(ids))?;
+
+        let mut mangas = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(IDS_BATCH_SIZE) {
+            let params = MangaSearchParams {
+                ids: Some(chunk.to_vec()),
+                limit: Some(chunk.len() as i32),
+                ..Default::default()
+            };
+            mangas.extend(self.mangas(params).await?);
+        }
+        Ok(Self::in_input_order(ids, mangas, |manga| manga.id))
+    }
+
+    /// Получение полной информации о манге по ID.
+    ///
+    /// Возвращает `None`, если манга не найдена.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// if let Some(manga) = client.manga_by_id(2).await? {
+    ///     println!("{}: {:?} глав", manga.name, manga.chapters);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn manga_by_id(&self, id: i64) -> Result<Option<Manga>> {
         let mut mangas = self.fetch(
             MANGA_DETAILS_QUERY.to_string(),
             || json!({ "ids": id.to_string() }),
@@ -773,20 +2202,87 @@ impl ShikicrateClient {
         Ok(mangas.pop())
     }
 
+    #[deprecated(note = "используйте manga_by_id")]
+    pub async fn manga_detail(&self, id: i64) -> Result<Option<Manga>> {
+        self.manga_by_id(id).await
+    }
+
     pub async fn people(&self, params: PeopleSearchParams) -> Result<Vec<PersonFull>> {
         Self::val_lim(params.limit)?;
+        if params.ids.is_some() {
+            Self::val_num_ids(params.ids.as_deref())?;
+            if let Some(ids) = &params.ids
+                && ids.len() > IDS_BATCH_SIZE
+            {
+                return Err(ValidationError::new(
+                    "ids",
+                    Constraint::Range { min: 1, max: IDS_BATCH_SIZE as i64 },
+                    "Не больше 50 ID за запрос",
+                )
+                .value(ids.len())
+                .into());
+            }
+            let ignored = [("search", params.search.is_some()), ("page", params.page.is_some())];
+            for (filter, _) in ignored.into_iter().filter(|(_, set)| *set) {
+                self.warn(Warning::FilterIgnored {
+                    filter,
+                    reason: "не поддерживается вместе с ids",
+                });
+            }
+        } else {
+            Self::val_pg(params.page)?;
+        }
 
         self.fetch(
             PEOPLE_QUERY.to_string(),
-            || Self::build_vars(params.search.clone(), None, params.limit),
+            || Self::people_vars(&params),
             "people",
         )
         .await
     }
 
+    /// Получение полной информации о человеке по ID, включая работы и роли.
+    ///
+    /// Работы и роли приходят в том же GraphQL запросе. Возвращает `None`,
+    /// если человек не найден.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// if let Some(person) = client.person_by_id(1).await? {
+    ///     for roles in person.roles.unwrap_or_default() {
+    ///         for character in roles.characters {
+    ///             println!("{}", character.name);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn person_by_id(&self, id: i64) -> Result<Option<PersonFull>> {
+        let mut people: Vec<PersonFull> = self.fetch(
+            PERSON_DETAILS_QUERY.to_string(),
+            || json!({ "ids": id.to_string() }),
+            "people",
+        )
+        .await?;
+        Ok(people.pop())
+    }
+
     pub async fn characters(&self, params: CharacterSearchParams) -> Result<Vec<CharacterFull>> {
         if params.ids.is_some() {
             Self::val_ids(params.ids.as_ref())?;
+            let ignored = [("search", params.search.is_some()), ("page", params.page.is_some()), ("limit", params.limit.is_some())];
+            for (filter, _) in ignored.into_iter().filter(|(_, set)| *set) {
+                self.warn(Warning::FilterIgnored {
+                    filter,
+                    reason: "не поддерживается вместе с ids",
+                });
+            }
         } else {
             Self::val_pg(params.page)?;
             Self::val_lim(params.limit)?;
@@ -812,16 +2308,88 @@ impl ShikicrateClient {
         .await
     }
 
-    pub async fn character_detail(&self, id: i64) -> Result<Option<CharacterFull>> {
+    /// Получение полной информации о персонаже по ID.
+    ///
+    /// В отличие от `characters()` с `ids`, запрашивает все поля `CharacterFull`
+    /// (описания, постер, флаги, сейю и тайтлы с персонажем). Возвращает `None`,
+    /// если персонаж не найден.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// if let Some(character) = client.character_by_id(22037).await? {
+    ///     println!("{}: {:?}", character.name, character.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn character_by_id(&self, id: i64) -> Result<Option<CharacterFull>> {
         let mut characters = self.fetch(
             CHARACTER_DETAILS_QUERY.to_string(),
-            || json!({ "ids": id.to_string() }),
+            || json!({ "ids": id.to_string(), "limit": 1 }),
             "characters",
         )
         .await?;
         Ok(characters.pop())
     }
 
+    #[deprecated(note = "используйте character_by_id")]
+    pub async fn character_detail(&self, id: i64) -> Result<Option<CharacterFull>> {
+        self.character_by_id(id).await
+    }
+
+    /// Получение только внешних ссылок тайтла (официальный сайт, Wikipedia, Kinopoisk и т.д.).
+    ///
+    /// `target_type` — `"Anime"` или `"Manga"`. В отличие от `anime_by_id()`,
+    /// запрашивает только ссылки. Для несуществующего тайтла возвращает пустой список.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// for link in client.external_links("Anime", 1).await? {
+    ///     println!("{}: {}", link.kind, link.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn external_links(&self, target_type: &str, id: i64) -> Result<Vec<ExternalLink>> {
+        let (query, key) = match target_type {
+            "Anime" => (ANIME_EXTERNAL_LINKS_QUERY, "animes"),
+            "Manga" => (MANGA_EXTERNAL_LINKS_QUERY, "mangas"),
+            _ => {
+                return Err(ValidationError::new(
+                    "target_type",
+                    Constraint::OneOf(vec!["Anime".to_string(), "Manga".to_string()]),
+                    "target_type должен быть \"Anime\" или \"Manga\"",
+                )
+                .value(target_type)
+                .into());
+            }
+        };
+        if id <= 0 {
+            return Err(ValidationError::new("id", Constraint::Min(1), "ID тайтла должен быть больше 0")
+                .value(id)
+                .into());
+        }
+
+        let titles: Vec<serde_json::Value> = self
+            .fetch(query.to_string(), || json!({ "ids": id.to_string() }), key)
+            .await?;
+
+        match titles.into_iter().next().and_then(|mut title| title.get_mut("externalLinks").map(serde_json::Value::take)) {
+            Some(links) if !links.is_null() => serde_json::from_value(links).map_err(ShikicrateError::Serialization),
+            _ => Ok(Vec::new()),
+        }
+    }
+
     pub async fn studios(&self, search: Option<String>) -> Result<Vec<Studio>> {
         let all_studios: Vec<Studio> = self.get_rest("studios", None::<serde_json::Value>).await?;
         if let Some(s) = search {
@@ -869,12 +2437,14 @@ impl ShikicrateClient {
             .ok_or_else(|| ShikicrateError::GraphQL {
                 message: "No animes in response".to_string(),
                 errors: None,
+                details: Vec::new(),
             })?;
 
         let anime = animes.first()
             .ok_or_else(|| ShikicrateError::GraphQL {
                 message: "Anime not found".to_string(),
                 errors: None,
+                details: Vec::new(),
             })?;
 
         let related = anime.get("related")
@@ -894,12 +2464,14 @@ impl ShikicrateClient {
             .ok_or_else(|| ShikicrateError::GraphQL {
                 message: "No mangas in response".to_string(),
                 errors: None,
+                details: Vec::new(),
             })?;
 
         let manga = mangas.first()
             .ok_or_else(|| ShikicrateError::GraphQL {
                 message: "Manga not found".to_string(),
                 errors: None,
+                details: Vec::new(),
             })?;
 
         let related = manga.get("related")
@@ -910,25 +2482,184 @@ impl ShikicrateClient {
         serde_json::from_value(json!(related)).map_err(ShikicrateError::Serialization)
     }
 
+    /// Поиск пользователей по никнейму.
+    ///
+    /// Позволяет, например, получить ID пользователя по его никнейму.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::{ShikicrateClient, queries::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let users = client.users(UserSearchParams::builder().search("morr").limit(5)).await?;
+    ///
+    /// for user in users {
+    ///     println!("{} (ID: {})", user.nickname, user.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn users(&self, params: UserSearchParams) -> Result<Vec<User>> {
+        Self::val_lim(params.limit)?;
+        Self::val_pg(params.page)?;
+
+        self.fetch(
+            USERS_QUERY.to_string(),
+            || Self::build_vars(params.search.clone(), params.page, params.limit),
+            "users",
+        )
+        .await
+    }
+
+    /// Получение профиля пользователя по ID через REST API Shikimori.
+    ///
+    /// Возвращает `None`, если пользователь не найден.
+    pub async fn user_by_id(&self, id: i64) -> Result<Option<User>> {
+        self.user_profile(&id.to_string(), false).await
+    }
+
+    /// Получение профиля пользователя по никнейму через REST API Shikimori.
+    ///
+    /// Возвращает `None`, если пользователь не найден.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// if let Some(user) = client.user_by_nickname("morr").await? {
+    ///     println!("{} (ID: {}), {:?}", user.nickname, user.id, user.last_online);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_by_nickname(&self, nickname: &str) -> Result<Option<User>> {
+        if nickname.trim().is_empty() {
+            return Err(ValidationError::new("nickname", Constraint::NotEmpty, "Никнейм не должен быть пустым").into());
+        }
+        self.user_profile(nickname, true).await
+    }
+
+    async fn user_profile(&self, key: &str, is_nickname: bool) -> Result<Option<User>> {
+        let encoded: String = url::form_urlencoded::byte_serialize(key.as_bytes()).collect();
+        let path = format!("users/{}", encoded.replace('+', "%20"));
+        let query = is_nickname.then(|| json!({ "is_nickname": 1 }));
+
+        match self.get_rest(&path, query).await {
+            Ok(user) => Ok(Some(user)),
+            Err(ShikicrateError::Api { status: 404, .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Получение статистики профиля пользователя через REST API Shikimori.
+    ///
+    /// Возвращает распределения по статусам, оценкам, типам, рейтингам и жанрам,
+    /// которые нельзя получить через GraphQL `userRates` без обхода всего списка.
+    /// Сводку с подсчитанными статусами и временем просмотра возвращает
+    /// [`user_summary`](Self::user_summary).
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let stats = client.user_stats(1).await?;
+    ///
+    /// for score in &stats.scores.anime {
+    ///     println!("{}: {}", score.name, score.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_stats(&self, user_id: i64) -> Result<UserStats> {
+        if user_id <= 0 {
+            return Err(ValidationError::new("user_id", Constraint::Min(1), "ID пользователя должен быть больше 0")
+                .value(user_id)
+                .into());
+        }
+
+        let path = format!("users/{}", user_id);
+        let profile: serde_json::Value = self.get_rest(&path, None::<serde_json::Value>).await?;
+
+        let stats = profile.get("stats").cloned().ok_or_else(|| ShikicrateError::EmptyResult {
+            context: format!("stats в профиле пользователя {}", user_id),
+        })?;
+
+        serde_json::from_value(stats).map_err(ShikicrateError::Serialization)
+    }
+
+    fn anime_vars(params: &AnimeSearchParams) -> serde_json::Value {
+        let mut vars = Self::build_vars(params.search.clone(), params.page, params.limit);
+        if let Some(kind) = &params.kind { vars["kind"] = json!(kind); }
+        if let Some(status) = &params.status { vars["status"] = json!(status); }
+        if let Some(season) = &params.season { vars["season"] = json!(season); }
+        if let Some(score) = params.score { vars["score"] = json!(score); }
+        if let Some(rating) = &params.rating { vars["rating"] = json!(rating); }
+        if let Some(duration) = &params.duration { vars["duration"] = json!(duration); }
+        if let Some(genre) = &params.genre { vars["genre"] = json!(genre); }
+        if let Some(studio) = &params.studio { vars["studio"] = json!(studio); }
+        if let Some(franchise) = &params.franchise { vars["franchise"] = json!(franchise); }
+        if let Some(ids) = &params.ids { vars["ids"] = json!(Self::join_ids(ids)); }
+        if let Some(exclude_ids) = &params.exclude_ids { vars["excludeIds"] = json!(Self::join_ids(exclude_ids)); }
+        if let Some(order) = &params.order { vars["order"] = json!(order); }
+        if let Some(censored) = params.censored { vars["censored"] = json!(censored); }
+        vars
+    }
+
+    fn people_vars(params: &PeopleSearchParams) -> serde_json::Value {
+        let mut variables = match &params.ids {
+            Some(ids) => json!({
+                "ids": Self::join_ids(ids),
+                "limit": params.limit.unwrap_or(ids.len() as i32),
+            }),
+            None => Self::build_vars(params.search.clone(), params.page, params.limit),
+        };
+        if let Some(is_seyu) = params.is_seyu {
+            variables["isSeyu"] = json!(is_seyu);
+        }
+        if let Some(is_mangaka) = params.is_mangaka {
+            variables["isMangaka"] = json!(is_mangaka);
+        }
+        if let Some(is_producer) = params.is_producer {
+            variables["isProducer"] = json!(is_producer);
+        }
+        variables
+    }
+
+    fn user_rate_vars(params: &UserRateSearchParams) -> serde_json::Value {
+        let mut variables = Self::build_vars(None, params.page, params.limit);
+        if let Some(user_id) = params.user_id {
+            variables["userId"] = json!(user_id.to_string());
+        }
+        if let Some(status) = &params.status {
+            variables["status"] = json!(status);
+        }
+        if let Some(target_type) = &params.target_type {
+            variables["targetType"] = json!(target_type);
+        }
+        if let Some(order) = params.order {
+            variables["order"] = json!({
+                "field": order,
+                "order": params.direction.unwrap_or_default()
+            });
+        }
+        variables
+    }
+
     pub async fn user_rates(&self, params: UserRateSearchParams) -> Result<Vec<UserRate>> {
         Self::val_pg(params.page)?;
         Self::val_lim(params.limit)?;
 
         self.fetch(
             USER_RATES_QUERY.to_string(),
-            || {
-                let mut variables = Self::build_vars(None, params.page, params.limit);
-                if let Some(target_type) = params.target_type {
-                    variables["targetType"] = json!(target_type);
-                }
-                if let Some(order_field) = params.order_field {
-                    variables["order"] = json!({
-                        "field": order_field,
-                        "order": params.order.unwrap_or_else(|| "desc".to_string())
-                    });
-                }
-                variables
-            },
+            || Self::user_rate_vars(&params),
             "userRates",
         )
         .await
@@ -939,6 +2670,52 @@ impl ShikicrateClient {
 mod tests {
     use super::*;
     use crate::error::ShikicrateError;
+    use crate::transport::{MockTransport, mock_client};
+
+    #[test]
+    fn test_anime_params_builder() {
+        let params = AnimeSearchParams::builder()
+            .search("naruto")
+            .limit(10)
+            .kind(AnimeKind::TvSpecial)
+            .status(AnimeStatus::Ongoing)
+            .rating(AgeRating::Pg13)
+            .ids([1, 2]);
+        assert_eq!(params.search.as_deref(), Some("naruto"));
+        assert_eq!(params.limit, Some(10));
+        assert_eq!(params.kind.as_deref(), Some("tv_special"));
+        assert_eq!(params.status.as_deref(), Some("ongoing"));
+        assert_eq!(params.rating.as_deref(), Some("pg_13"));
+        assert_eq!(params.ids, Some(vec![1, 2]));
+        assert!(params.page.is_none());
+
+        let params = AnimeSearchParams::builder().kind(AnimeKind::Tv).kind(AnimeKind::Movie);
+        assert_eq!(params.kind.as_deref(), Some("tv,movie"));
+        let params = AnimeSearchParams::builder().exclude_kind(AnimeKind::Special);
+        assert_eq!(params.kind.as_deref(), Some("!special"));
+        let params = AnimeSearchParams::builder()
+            .kind(AnimeKind::Tv)
+            .exclude_kind(AnimeKind::Special)
+            .exclude_kind(AnimeKind::Music);
+        assert_eq!(params.kind.as_deref(), Some("tv,!special,!music"));
+
+        let params = AnimeSearchParams::builder()
+            .exclude_rating(AgeRating::Rx)
+            .exclude_rating(AgeRating::RPlus)
+            .duration(EpisodeDuration::Short)
+            .duration(EpisodeDuration::Medium)
+            .season(Season::Winter, 2024)
+            .years(2020..=2023);
+        assert_eq!(params.rating.as_deref(), Some("!rx,!r_plus"));
+        assert_eq!(params.duration.as_deref(), Some("S,D"));
+        assert_eq!(params.season.as_deref(), Some("winter_2024,2020_2023"));
+        let params = AnimeSearchParams::builder().year(2023).exclude_duration(EpisodeDuration::Long);
+        assert_eq!(params.season.as_deref(), Some("2023"));
+        assert_eq!(params.duration.as_deref(), Some("!F"));
+
+        let params = CharacterSearchParams::builder().ids([5]);
+        assert_eq!(params.ids, Some(vec!["5".to_string()]));
+    }
 
     #[test]
     fn test_val_lim_valid() {
@@ -957,6 +2734,13 @@ mod tests {
             ShikicrateClient::val_lim(Some(-1)),
             Err(ShikicrateError::Validation(_))
         ));
+
+        let Err(ShikicrateError::Validation(error)) = ShikicrateClient::val_lim(Some(0)) else {
+            panic!("ожидалась ошибка валидации");
+        };
+        assert_eq!(error.field, "limit");
+        assert_eq!(error.value.as_deref(), Some("0"));
+        assert_eq!(error.constraint, Constraint::Min(1));
     }
 
     #[test]
@@ -994,6 +2778,226 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_user_rate_vars_order() {
+        let vars = ShikicrateClient::user_rate_vars(&UserRateSearchParams {
+            user_id: Some(42),
+            order: Some(UserRateOrder::UpdatedAt),
+            ..Default::default()
+        });
+        assert_eq!(vars["userId"], "42");
+        assert_eq!(vars["order"], json!({ "field": "updated_at", "order": "desc" }));
+
+        let vars = ShikicrateClient::user_rate_vars(&UserRateSearchParams {
+            direction: Some(SortDirection::Asc),
+            ..Default::default()
+        });
+        assert!(vars.get("order").is_none());
+
+        let vars = ShikicrateClient::user_rate_vars(&UserRateSearchParams {
+            status: Some(WatchStatus::OnHold),
+            ..Default::default()
+        });
+        assert_eq!(vars["status"], "on_hold");
+    }
+
+    #[test]
+    fn test_val_num_ids() {
+        assert!(ShikicrateClient::val_num_ids(None).is_ok());
+        assert!(ShikicrateClient::val_num_ids(Some(&[1, 2])).is_ok());
+        assert!(matches!(
+            ShikicrateClient::val_num_ids(Some(&[])),
+            Err(ShikicrateError::Validation(_))
+        ));
+        assert_eq!(ShikicrateClient::join_ids(&[1, 22, 333]), "1,22,333");
+    }
+
+    #[test]
+    fn test_val_vars() {
+        assert!(ShikicrateClient::val_vars(&json!({})).is_ok());
+        assert!(ShikicrateClient::val_vars(&json!({ "limit": 10, "page": 2 })).is_ok());
+
+        for invalid in [json!({ "limit": 0 }), json!({ "page": 0 }), json!({ "page": "1" }), json!([])] {
+            assert!(matches!(
+                ShikicrateClient::val_vars(&invalid),
+                Err(ShikicrateError::Validation(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_many_keeps_input_order() {
+        let client = ShikicrateClient::new().unwrap();
+        let params = vec![
+            AnimeSearchParams { limit: Some(0), ..Default::default() },
+            AnimeSearchParams { score: Some(11), ..Default::default() },
+        ];
+
+        let results = client.search_many_bounded(params, 2).await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(&results[0], Err(ShikicrateError::Validation(e)) if e.field == "limit"));
+        assert!(matches!(&results[1], Err(ShikicrateError::Validation(e)) if e.field == "score"));
+    }
+
+    #[test]
+    fn test_genre_filter() {
+        assert_eq!(genre_filter(&[], &[]), "");
+        assert_eq!(genre_filter(&[4, 2], &[]), "4,2");
+        assert_eq!(genre_filter(&[4], &[9, 12]), "4,!9,!12");
+
+        let params = AnimeSearchParams {
+            genre: Some(genre_filter(&[4], &[9])),
+            ..Default::default()
+        };
+        assert_eq!(ShikicrateClient::anime_vars(&params)["genre"], "4,!9");
+    }
+
+    #[test]
+    fn test_anime_vars_studio() {
+        let params = AnimeSearchParams {
+            kind: Some("tv".to_string()),
+            studio: Some("2".to_string()),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::anime_vars(&params);
+        assert_eq!(vars["studio"], "2");
+        assert_eq!(vars["kind"], "tv");
+    }
+
+    #[test]
+    fn test_anime_vars_franchise() {
+        let params = AnimeSearchParams {
+            franchise: Some("monogatari".to_string()),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::anime_vars(&params);
+        assert_eq!(vars["franchise"], "monogatari");
+    }
+
+    #[test]
+    fn test_anime_vars_ids() {
+        let params = AnimeSearchParams {
+            ids: Some(vec![1, 5]),
+            exclude_ids: Some(vec![20]),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::anime_vars(&params);
+        assert_eq!(vars["ids"], "1,5");
+        assert_eq!(vars["excludeIds"], "20");
+    }
+
+    #[test]
+    fn test_anime_vars_order() {
+        let params = AnimeSearchParams {
+            order: Some(AnimeOrder::RankedShiki),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::anime_vars(&params);
+        assert_eq!(vars["order"], "ranked_shiki");
+        assert_eq!(json!(AnimeOrder::AiredOn), "aired_on");
+    }
+
+    #[test]
+    fn test_val_rating() {
+        assert!(ShikicrateClient::val_rating(None).is_ok());
+        assert!(ShikicrateClient::val_rating(Some("pg_13")).is_ok());
+        assert!(ShikicrateClient::val_rating(Some("!rx,!r_plus")).is_ok());
+        assert!(matches!(
+            ShikicrateClient::val_rating(Some("pg13")),
+            Err(ShikicrateError::Validation(_))
+        ));
+        assert!(matches!(
+            ShikicrateClient::val_rating(Some("")),
+            Err(ShikicrateError::Validation(_))
+        ));
+        assert!(matches!(
+            ShikicrateClient::val_rating(Some("pg, nc_17")),
+            Err(ShikicrateError::Validation(e)) if e.field == "rating" && e.value.as_deref() == Some("nc_17")
+        ));
+
+        // Значения из AgeRating проходят проверку, включая "none"
+        for rating in [AgeRating::Unrated, AgeRating::Pg13, AgeRating::RPlus, AgeRating::Rx] {
+            let params = AnimeSearchParams::builder().rating(rating);
+            assert!(ShikicrateClient::val_rating(params.rating.as_deref()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_val_duration() {
+        assert!(ShikicrateClient::val_duration(None).is_ok());
+        assert!(ShikicrateClient::val_duration(Some("S,D")).is_ok());
+        assert!(ShikicrateClient::val_duration(Some("!F")).is_ok());
+        assert!(matches!(
+            ShikicrateClient::val_duration(Some("short")),
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_external_links_validation() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(
+            client.external_links("Ranobe", 1).await,
+            Err(ShikicrateError::Validation(_))
+        ));
+        assert!(matches!(
+            client.external_links("Anime", 0).await,
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_val_score() {
+        assert!(ShikicrateClient::val_score(None).is_ok());
+        assert!(ShikicrateClient::val_score(Some(7)).is_ok());
+        assert!(matches!(
+            ShikicrateClient::val_score(Some(0)),
+            Err(ShikicrateError::Validation(_))
+        ));
+        assert!(matches!(
+            ShikicrateClient::val_score(Some(11)),
+            Err(ShikicrateError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_anime_vars_season() {
+        let params = AnimeSearchParams {
+            season: Some("winter_2024".to_string()),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::anime_vars(&params);
+        assert_eq!(vars["season"], "winter_2024");
+        assert!(vars.get("score").is_none());
+        assert!(vars.get("rating").is_none());
+    }
+
+    #[test]
+    fn test_people_vars() {
+        let params = PeopleSearchParams {
+            limit: Some(5),
+            page: Some(2),
+            is_seyu: Some(true),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::people_vars(&params);
+        assert_eq!(vars["limit"], 5);
+        assert_eq!(vars["page"], 2);
+        assert_eq!(vars["isSeyu"], true);
+        assert!(vars.get("isMangaka").is_none());
+        assert!(vars.get("isProducer").is_none());
+
+        let params = PeopleSearchParams {
+            ids: Some(vec![1, 2, 3]),
+            search: Some("ignored".to_string()),
+            ..Default::default()
+        };
+        let vars = ShikicrateClient::people_vars(&params);
+        assert_eq!(vars["ids"], "1,2,3");
+        assert_eq!(vars["limit"], 3);
+        assert!(vars.get("search").is_none());
+    }
+
     #[test]
     fn test_build_vars() {
         let vars = ShikicrateClient::build_vars(None, None, None);
@@ -1004,4 +3008,89 @@ mod tests {
         assert_eq!(vars["page"], 2);
         assert_eq!(vars["limit"], 10);
     }
+
+    #[tokio::test]
+    async fn test_search_all_validation() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(client.search_all("", 5).await, Err(ShikicrateError::Validation(_))));
+        assert!(matches!(client.search_all("bebop", 0).await, Err(ShikicrateError::Validation(_))));
+
+        let result: SearchAllResult = serde_json::from_value(json!({
+            "foundAnimes": [{ "id": "1", "name": "Cowboy Bebop" }],
+            "foundPeople": [{ "id": "2", "name": "Yamadera Kouichi" }]
+        }))
+        .unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.mangas.is_empty());
+    }
+
+    #[test]
+    fn test_season_filter() {
+        assert_eq!(Season::Winter.filter(2025), "winter_2025");
+        assert_eq!(Season::Fall.filter(1998), "fall_1998");
+    }
+
+    #[tokio::test]
+    async fn test_user_stats_errors() {
+        let transport = MockTransport::new().rest("users/1", json!({ "id": 1, "nickname": "morr" }));
+        let client = mock_client(&transport);
+
+        assert!(matches!(client.user_stats(0).await, Err(ShikicrateError::Validation(e)) if e.field == "user_id"));
+        assert!(transport.requests().is_empty());
+
+        assert!(matches!(client.user_stats(1).await, Err(ShikicrateError::EmptyResult { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_person_by_id_single_request() {
+        let transport = MockTransport::new().graphql(
+            "GetPersonDetails",
+            json!({ "people": [{
+                "id": "1",
+                "name": "Hayao Miyazaki",
+                "works": [{ "anime": { "id": "199", "name": "Sen to Chihiro no Kamikakushi" }, "role": "Director" }],
+                "roles": null
+            }] }),
+        );
+        let client = mock_client(&transport);
+
+        let person = client.person_by_id(1).await.unwrap().unwrap();
+        let works = person.works.unwrap();
+        assert_eq!(works[0].anime.as_ref().unwrap().id, 199);
+        assert_eq!(works[0].role.as_deref(), Some("Director"));
+        assert!(person.roles.is_none());
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_top_animes_kind() {
+        let transport = MockTransport::new().graphql("TopAnimes", json!({ "animes": [] }));
+        let client = mock_client(&transport);
+
+        client.top_animes(5, Some(AnimeKind::TvSpecial)).await.unwrap();
+        client.top_animes(5, None).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].body.as_ref().unwrap()["variables"]["kind"], "tv_special");
+        assert!(requests[1].body.as_ref().unwrap()["variables"].get("kind").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_people_rejects_too_many_ids() {
+        let transport = MockTransport::new();
+        let client = mock_client(&transport);
+
+        let params = PeopleSearchParams::builder().ids(1..=51);
+        assert!(matches!(
+            client.people(params).await,
+            Err(ShikicrateError::Validation(e)) if e.field == "ids" && e.value.as_deref() == Some("51")
+        ));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn test_in_input_order() {
+        let ordered = ShikicrateClient::in_input_order(&[3, 1, 4, 3], vec![1, 3, 5], |id| *id);
+        assert_eq!(ordered, vec![3, 1, 3]);
+    }
 }
@@ -0,0 +1,641 @@
+//! Клиентское ограничение частоты запросов.
+//!
+//! По умолчанию клиент выдерживает фиксированный интервал между запросами.
+//! В адаптивном режиме ([`AdaptiveRateLimit`]) частота регулируется по схеме AIMD:
+//! после каждого успешного ответа она плавно растет, а после ответа 429 —
+//! уменьшается в несколько раз. Это полезно, когда несколько независимых
+//! процессов делят один IP и статический лимит подобрать невозможно.
+//!
+//! Режим token bucket ([`TokenBucket`]) соответствует лимитам Shikimori
+//! (5 запросов в секунду и 90 в минуту): короткие всплески проходят без
+//! задержки, а пагинаторы и параллельные задачи не выходят за минутный лимит.
+//!
+//! Независимо от режима клиент читает заголовки `X-RateLimit-Remaining` и
+//! `X-RateLimit-Reset` (а для ответа 429 — `Retry-After`): когда бюджет
+//! запросов исчерпан, следующие запросы ждут его восстановления, а не получают 429.
+//! Если ответ 429 пришел без `Retry-After`, все запросы клиента (и его клонов)
+//! выжидают общую паузу, которая удваивается при повторных 429 (от 1 до 60 секунд),
+//! вместо того чтобы каждый повторял запрос независимо.
+//!
+//! Запросы имеют приоритет ([`Priority`]): фоновые запросы занимают слот
+//! лимитера только если он свободен прямо сейчас, поэтому интерактивные запросы
+//! всегда обслуживаются первыми. Клиент с фоновым приоритетом создается через
+//! `ShikicrateClient::with_priority()` и разделяет лимитер с исходным клиентом.
+
+use crate::error::{Constraint, Result, ValidationError};
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Остаток бюджета `X-RateLimit-Remaining`, при котором запросы приостанавливаются.
+const BUDGET_RESERVE: u64 = 1;
+
+/// Значения `X-RateLimit-Reset` больше этого считаются Unix-временем, меньше — секундами.
+const RESET_EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Общая пауза после первого 429 без `Retry-After`.
+const COOLDOWN_BASE: Duration = Duration::from_secs(1);
+
+/// Максимальная общая пауза после серии 429.
+const COOLDOWN_MAX: Duration = Duration::from_secs(60);
+
+/// Настройки адаптивного ограничения частоты запросов (AIMD).
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{ShikicrateClientBuilder, rate_limit::AdaptiveRateLimit};
+///
+/// # fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClientBuilder::new()
+///     .adaptive_rate_limit(AdaptiveRateLimit {
+///         max_rps: 3.0,
+///         ..Default::default()
+///     })
+///     .build()?;
+///
+/// println!("Текущая частота: {:.2} rps", client.stats().current_rps);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AdaptiveRateLimit {
+    /// Начальная частота запросов в секунду.
+    pub initial_rps: f64,
+
+    /// Минимальная частота, ниже которой лимитер не опускается.
+    pub min_rps: f64,
+
+    /// Максимальная частота, выше которой лимитер не поднимается.
+    pub max_rps: f64,
+
+    /// Прибавка к частоте после каждого успешного ответа (additive increase).
+    pub increase_step: f64,
+
+    /// Множитель частоты после ответа 429 (multiplicative decrease), от 0 до 1.
+    pub decrease_factor: f64,
+}
+
+impl Default for AdaptiveRateLimit {
+    fn default() -> Self {
+        Self {
+            initial_rps: 1.0,
+            min_rps: 0.1,
+            max_rps: 5.0,
+            increase_step: 0.05,
+            decrease_factor: 0.5,
+        }
+    }
+}
+
+impl AdaptiveRateLimit {
+    /// Проверяет настройки: частоты конечны и больше 0, `min_rps` не больше
+    /// `max_rps`, `decrease_factor` в диапазоне (0, 1].
+    pub(crate) fn validate(&self) -> Result<()> {
+        let invalid = |field, constraint, message: &str, value: f64| -> Result<()> {
+            Err(ValidationError::new(field, constraint, format!("AdaptiveRateLimit: {}", message))
+                .value(value)
+                .into())
+        };
+        for (field, value) in [
+            ("initial_rps", self.initial_rps),
+            ("min_rps", self.min_rps),
+            ("max_rps", self.max_rps),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return invalid(field, Constraint::Other, &format!("{} должен быть больше 0", field), value);
+            }
+        }
+        if self.min_rps > self.max_rps {
+            return invalid(
+                "min_rps",
+                Constraint::Other,
+                "min_rps должен быть не больше max_rps",
+                self.min_rps,
+            );
+        }
+        if !self.increase_step.is_finite() || self.increase_step < 0.0 {
+            return invalid(
+                "increase_step",
+                Constraint::Other,
+                "increase_step должен быть не меньше 0",
+                self.increase_step,
+            );
+        }
+        if !(self.decrease_factor > 0.0 && self.decrease_factor <= 1.0) {
+            return invalid(
+                "decrease_factor",
+                Constraint::Other,
+                "decrease_factor должен быть в диапазоне (0, 1]",
+                self.decrease_factor,
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Настройки ограничения частоты по схеме token bucket.
+///
+/// Для каждого окна (секунда и минута) ведется отдельная «корзина» токенов;
+/// запрос отправляется, когда токен есть в обеих. По умолчанию используются
+/// лимиты Shikimori: 5 запросов в секунду и 90 в минуту.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{ShikicrateClientBuilder, rate_limit::TokenBucket};
+///
+/// # fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClientBuilder::new()
+///     .token_bucket(TokenBucket::default())
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBucket {
+    /// Максимум запросов в секунду (размер всплеска).
+    pub per_second: u32,
+
+    /// Максимум запросов в минуту.
+    pub per_minute: u32,
+}
+
+impl Default for TokenBucket {
+    fn default() -> Self {
+        Self {
+            per_second: 5,
+            per_minute: 90,
+        }
+    }
+}
+
+/// Приоритет запросов клиента.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{ShikicrateClient, rate_limit::Priority};
+///
+/// # fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClient::new()?;
+/// // Фоновое зеркалирование не будет задерживать пользовательские запросы
+/// let mirror = client.with_priority(Priority::Background);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    /// Запросы, которых ждет пользователь. Обслуживаются в первую очередь.
+    #[default]
+    Interactive,
+
+    /// Фоновые запросы (краулинг, зеркалирование). Используют только свободные слоты.
+    Background,
+}
+
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    /// Токенов в секунду.
+    refill: f64,
+}
+
+impl Bucket {
+    fn new(capacity: u32, window: Duration) -> Self {
+        let capacity = capacity as f64;
+        Self {
+            capacity,
+            tokens: capacity,
+            refill: capacity / window.as_secs_f64(),
+        }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.refill).min(self.capacity);
+    }
+
+    /// Сколько токенов нужно для запроса: фоновые запросы оставляют один
+    /// токен интерактивным (если корзина больше одного токена).
+    fn needed(&self, priority: Priority) -> f64 {
+        match priority {
+            Priority::Background if self.capacity > 1.0 => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Время до появления `needed` токенов.
+    fn wait(&self, needed: f64) -> Duration {
+        Duration::from_secs_f64(((needed - self.tokens) / self.refill).max(0.0))
+    }
+}
+
+struct LimiterState {
+    next_slot: Instant,
+    rps: f64,
+    /// Корзины режима token bucket (пусто в режиме фиксированного интервала).
+    buckets: Vec<Bucket>,
+    refilled_at: Instant,
+    /// Запросы приостановлены до этого момента (бюджет сервера исчерпан).
+    paused_until: Option<Instant>,
+    /// Количество 429 подряд, вызвавших общую паузу.
+    strikes: u32,
+}
+
+pub(crate) struct RateLimiter {
+    state: Mutex<LimiterState>,
+    adaptive: Option<AdaptiveRateLimit>,
+    requests_sent: AtomicU64,
+    rate_limited: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Лимитер с фиксированным интервалом между запросами.
+    pub(crate) fn fixed(interval: Duration) -> Self {
+        Self::with_rps(1.0 / interval.as_secs_f64(), None)
+    }
+
+    pub(crate) fn adaptive(config: AdaptiveRateLimit) -> Self {
+        let rps = config.initial_rps.clamp(config.min_rps, config.max_rps);
+        Self::with_rps(rps, Some(config))
+    }
+
+    /// Лимитер token bucket с посекундным и поминутным окнами.
+    pub(crate) fn token_bucket(config: TokenBucket) -> Self {
+        let limiter = Self::with_rps(config.per_second as f64, None);
+        limiter.lock().buckets = vec![
+            Bucket::new(config.per_second, Duration::from_secs(1)),
+            Bucket::new(config.per_minute, Duration::from_secs(60)),
+        ];
+        limiter
+    }
+
+    fn with_rps(rps: f64, adaptive: Option<AdaptiveRateLimit>) -> Self {
+        Self {
+            state: Mutex::new(LimiterState {
+                next_slot: Instant::now(),
+                rps,
+                buckets: Vec::new(),
+                refilled_at: Instant::now(),
+                paused_until: None,
+                strikes: 0,
+            }),
+            adaptive,
+            requests_sent: AtomicU64::new(0),
+            rate_limited: AtomicU64::new(0),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LimiterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Резервирует слот для следующего запроса и ждет его наступления.
+    ///
+    /// Интерактивные запросы резервируют ближайший слот сразу. Фоновые запросы
+    /// ждут, пока слот не освободится, и не резервируют слоты в будущем, поэтому
+    /// пришедший позже интерактивный запрос обгоняет их.
+    ///
+    /// В режиме token bucket запрос забирает токен из каждой корзины; если
+    /// токенов нет, он ждет их пополнения. Фоновые запросы оставляют в каждой
+    /// корзине один токен для интерактивных.
+    pub(crate) async fn acquire(&self, priority: Priority) {
+        loop {
+            let (wait, reserved) = {
+                let mut state = self.lock();
+                let now = Instant::now();
+
+                if let Some(until) = state.paused_until.filter(|until| *until > now) {
+                    (until - now, false)
+                } else if !state.buckets.is_empty() {
+                    let elapsed = now - state.refilled_at;
+                    state.refilled_at = now;
+                    for bucket in &mut state.buckets {
+                        bucket.refill(elapsed);
+                    }
+
+                    let wait = state
+                        .buckets
+                        .iter()
+                        .map(|b| b.wait(b.needed(priority)))
+                        .max()
+                        .unwrap_or_default();
+                    if wait.is_zero() {
+                        for bucket in &mut state.buckets {
+                            bucket.tokens -= 1.0;
+                        }
+                        (Duration::ZERO, true)
+                    } else {
+                        (wait, false)
+                    }
+                } else if priority == Priority::Interactive || state.next_slot <= now {
+                    let slot = state.next_slot.max(now);
+                    state.next_slot = slot + Duration::from_secs_f64(1.0 / state.rps);
+                    (slot - now, true)
+                } else {
+                    (state.next_slot - now, false)
+                }
+            };
+
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+            if reserved {
+                self.requests_sent.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+
+    /// Учитывает успешный ответ сервера.
+    pub(crate) fn on_success(&self) {
+        let mut state = self.lock();
+        state.strikes = 0;
+        if let Some(config) = &self.adaptive {
+            state.rps = (state.rps + config.increase_step).min(config.max_rps);
+        }
+    }
+
+    /// Учитывает ответ 429 Too Many Requests.
+    ///
+    /// Если пауза еще не назначена (например, по `Retry-After`), назначает общую
+    /// паузу для всех запросов. Ответы 429 на запросы, отправленные до начала
+    /// паузы, ее не удлиняют.
+    pub(crate) fn on_rate_limited(&self) {
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        let mut state = self.lock();
+        if let Some(config) = &self.adaptive {
+            state.rps = (state.rps * config.decrease_factor).max(config.min_rps);
+        }
+
+        let now = Instant::now();
+        if state.paused_until.is_some_and(|until| until > now) {
+            return;
+        }
+        let cooldown = COOLDOWN_BASE
+            .saturating_mul(2u32.saturating_pow(state.strikes))
+            .min(COOLDOWN_MAX);
+        state.strikes = state.strikes.saturating_add(1);
+        state.paused_until = Some(now + cooldown);
+    }
+
+    /// Оставшееся время общей паузы.
+    pub(crate) fn cooldown_remaining(&self) -> Duration {
+        self.lock()
+            .paused_until
+            .map_or(Duration::ZERO, |until| until.saturating_duration_since(Instant::now()))
+    }
+
+    /// Учитывает заголовки бюджета запросов из ответа сервера.
+    pub(crate) fn on_headers(&self, status: u16, headers: &HeaderMap) {
+        if let Some(pause) = server_pause(status, headers, SystemTime::now())
+            && let Some(until) = Instant::now().checked_add(pause)
+        {
+            let mut state = self.lock();
+            state.paused_until = Some(state.paused_until.map_or(until, |current| current.max(until)));
+        }
+    }
+
+    pub(crate) fn current_rps(&self) -> f64 {
+        self.lock().rps
+    }
+
+    pub(crate) fn requests_sent(&self) -> u64 {
+        self.requests_sent.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn rate_limited(&self) -> u64 {
+        self.rate_limited.load(Ordering::Relaxed)
+    }
+}
+
+/// Сколько ждать до следующего запроса по заголовкам ответа.
+///
+/// Для 429 используется `Retry-After`; иначе пауза берется из
+/// `X-RateLimit-Reset`, если `X-RateLimit-Remaining` не больше `BUDGET_RESERVE`.
+/// Пауза не превышает `COOLDOWN_MAX`, чтобы огромное значение заголовка не
+/// остановило клиент на часы.
+fn server_pause(status: u16, headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    raw_server_pause(status, headers, now).map(|pause| pause.min(COOLDOWN_MAX))
+}
+
+fn raw_server_pause(status: u16, headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let number = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+
+    if status == 429
+        && let Some(retry_after) = number("Retry-After")
+    {
+        return Some(Duration::from_secs(retry_after));
+    }
+
+    if number("X-RateLimit-Remaining")? > BUDGET_RESERVE {
+        return None;
+    }
+    let reset = number("X-RateLimit-Reset")?;
+    if reset >= RESET_EPOCH_THRESHOLD {
+        let now = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    } else {
+        Some(Duration::from_secs(reset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_cooldown_after_429() {
+        let limiter = RateLimiter::fixed(Duration::from_secs(1));
+
+        limiter.on_rate_limited();
+        let first = limiter.cooldown_remaining();
+        assert!(first > Duration::from_millis(900) && first <= COOLDOWN_BASE);
+
+        // 429 на запросы, отправленные до паузы, ее не удлиняют
+        limiter.on_rate_limited();
+        assert!(limiter.cooldown_remaining() <= first);
+
+        // Следующий 429 после окончания паузы удваивает ее
+        limiter.lock().paused_until = Some(Instant::now());
+        limiter.on_rate_limited();
+        assert!(limiter.cooldown_remaining() > COOLDOWN_BASE);
+
+        limiter.on_success();
+        limiter.lock().paused_until = None;
+        limiter.on_rate_limited();
+        assert!(limiter.cooldown_remaining() <= COOLDOWN_BASE);
+    }
+
+    #[test]
+    fn test_fixed_limiter_ignores_feedback() {
+        let limiter = RateLimiter::fixed(Duration::from_secs(2));
+        limiter.on_rate_limited();
+        limiter.on_success();
+        assert!((limiter.current_rps() - 0.5).abs() < f64::EPSILON);
+        assert_eq!(limiter.rate_limited(), 1);
+    }
+
+    #[test]
+    fn test_adaptive_limiter_aimd() {
+        let limiter = RateLimiter::adaptive(AdaptiveRateLimit {
+            initial_rps: 4.0,
+            min_rps: 0.5,
+            max_rps: 5.0,
+            increase_step: 0.5,
+            decrease_factor: 0.5,
+        });
+
+        limiter.on_rate_limited();
+        assert!((limiter.current_rps() - 2.0).abs() < 1e-9);
+
+        limiter.on_success();
+        assert!((limiter.current_rps() - 2.5).abs() < 1e-9);
+
+        for _ in 0..10 {
+            limiter.on_success();
+        }
+        assert!((limiter.current_rps() - 5.0).abs() < 1e-9);
+
+        for _ in 0..10 {
+            limiter.on_rate_limited();
+        }
+        assert!((limiter.current_rps() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_adaptive_config_validation() {
+        assert!(AdaptiveRateLimit::default().validate().is_ok());
+
+        let invalid = [
+            AdaptiveRateLimit { min_rps: 10.0, ..Default::default() },
+            AdaptiveRateLimit { max_rps: f64::NAN, ..Default::default() },
+            AdaptiveRateLimit { initial_rps: 0.0, ..Default::default() },
+            AdaptiveRateLimit { min_rps: -1.0, ..Default::default() },
+            AdaptiveRateLimit { decrease_factor: 0.0, ..Default::default() },
+            AdaptiveRateLimit { decrease_factor: 1.5, ..Default::default() },
+        ];
+        for config in invalid {
+            assert!(config.validate().is_err(), "{:?}", config);
+        }
+
+        let error = crate::ShikicrateClientBuilder::new()
+            .adaptive_rate_limit(AdaptiveRateLimit { min_rps: 10.0, ..Default::default() })
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(error, crate::ShikicrateError::Validation(e) if e.field == "min_rps"));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_first_request_is_immediate() {
+        let limiter = RateLimiter::fixed(Duration::from_secs(3));
+        let start = Instant::now();
+        limiter.acquire(Priority::Interactive).await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+        assert_eq!(limiter.requests_sent(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_interactive_overtakes_background() {
+        use std::sync::Arc;
+
+        let limiter = Arc::new(RateLimiter::fixed(Duration::from_millis(200)));
+        limiter.acquire(Priority::Interactive).await;
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let background = {
+            let (limiter, order) = (Arc::clone(&limiter), Arc::clone(&order));
+            tokio::spawn(async move {
+                limiter.acquire(Priority::Background).await;
+                order.lock().unwrap().push("background");
+            })
+        };
+        tokio::task::yield_now().await;
+
+        let interactive = {
+            let (limiter, order) = (Arc::clone(&limiter), Arc::clone(&order));
+            tokio::spawn(async move {
+                limiter.acquire(Priority::Interactive).await;
+                order.lock().unwrap().push("interactive");
+            })
+        };
+
+        background.await.unwrap();
+        interactive.await.unwrap();
+        assert_eq!(*order.lock().unwrap(), vec!["interactive", "background"]);
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket_allows_burst_then_waits() {
+        let limiter = RateLimiter::token_bucket(TokenBucket {
+            per_second: 3,
+            per_minute: 90,
+        });
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire(Priority::Interactive).await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        limiter.acquire(Priority::Interactive).await;
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(limiter.requests_sent(), 4);
+    }
+
+    #[test]
+    fn test_bucket_background_reserve() {
+        let mut bucket = Bucket::new(5, Duration::from_secs(1));
+        bucket.tokens = 1.0;
+        assert!(bucket.wait(bucket.needed(Priority::Interactive)).is_zero());
+        assert_eq!(bucket.wait(bucket.needed(Priority::Background)), Duration::from_millis(200));
+
+        bucket.refill(Duration::from_secs(10));
+        assert!((bucket.tokens - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_server_pause() {
+        fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+            pairs.iter().map(|(k, v)| (k.parse().unwrap(), v.parse().unwrap())).collect()
+        }
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let exhausted = headers(&[("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "7")]);
+        assert_eq!(server_pause(200, &exhausted, now), Some(Duration::from_secs(7)));
+
+        let epoch = headers(&[("x-ratelimit-remaining", "1"), ("x-ratelimit-reset", "1700000030")]);
+        assert_eq!(server_pause(200, &epoch, now), Some(Duration::from_secs(30)));
+
+        let plenty = headers(&[("X-RateLimit-Remaining", "40"), ("X-RateLimit-Reset", "7")]);
+        assert_eq!(server_pause(200, &plenty, now), None);
+
+        let retry_after = headers(&[("Retry-After", "3")]);
+        assert_eq!(server_pause(429, &retry_after, now), Some(Duration::from_secs(3)));
+        assert_eq!(server_pause(200, &retry_after, now), None);
+
+        let huge = headers(&[("Retry-After", "18446744073709551615")]);
+        assert_eq!(server_pause(429, &huge, now), Some(COOLDOWN_MAX));
+        let day = headers(&[("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "86400")]);
+        assert_eq!(server_pause(200, &day, now), Some(COOLDOWN_MAX));
+
+        let limiter = RateLimiter::fixed(Duration::from_secs(1));
+        limiter.on_headers(429, &huge);
+        assert!(limiter.cooldown_remaining() <= COOLDOWN_MAX);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_server_budget() {
+        let limiter = RateLimiter::token_bucket(TokenBucket::default());
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+        headers.insert("X-RateLimit-Reset", "1".parse().unwrap());
+        limiter.on_headers(200, &headers);
+
+        let start = Instant::now();
+        limiter.acquire(Priority::Interactive).await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+}
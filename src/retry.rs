@@ -0,0 +1,226 @@
+//! Настройки повторов запросов.
+//!
+//! Повторяются только временные ошибки: таймауты, ошибки подключения и
+//! 429 Too Many Requests. Задержка перед `n`-м повтором равна
+//! `base_delay * multiplier^n`, но не больше `max_delay`. Чтобы много
+//! экземпляров клиента не повторяли запросы одновременно после общего 429,
+//! к задержке можно добавить случайный разброс ([`Jitter`]).
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::retry::{Jitter, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! # fn example() -> shikicrate::Result<()> {
+//! // Краулер: больше попыток и длинные паузы
+//! let crawler = ShikicrateClientBuilder::new()
+//!     .retry_policy(RetryPolicy {
+//!         max_attempts: 8,
+//!         max_delay: Duration::from_secs(120),
+//!         jitter: Jitter::Full,
+//!         ..Default::default()
+//!     })
+//!     .build()?;
+//!
+//! // Бот: ошибка сразу, без ожидания
+//! let bot = ShikicrateClientBuilder::new()
+//!     .retry_policy(RetryPolicy::none())
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Условие повтора запроса после ошибки (см. `ShikicrateClientBuilder::retry_if`).
+pub type RetryPredicate = Arc<dyn Fn(&ShikicrateError) -> bool + Send + Sync>;
+
+/// Случайный разброс задержки между повторами.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Jitter {
+    /// Без разброса: задержки строго экспоненциальные.
+    #[default]
+    None,
+    /// Случайная задержка от нуля до рассчитанной.
+    Full,
+    /// Половина рассчитанной задержки плюс случайная часть от нуля до второй половины.
+    Equal,
+}
+
+/// Политика повторов запросов.
+///
+/// По умолчанию — 4 попытки (первая и 3 повтора) с задержками 1, 2 и 4 секунды.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Общее количество попыток, включая первую (не меньше 1).
+    pub max_attempts: u32,
+
+    /// Задержка перед первым повтором.
+    pub base_delay: Duration,
+
+    /// Максимальная задержка между попытками.
+    pub max_delay: Duration,
+
+    /// Множитель задержки для каждого следующего повтора (не меньше 1.0).
+    pub multiplier: f64,
+
+    /// Случайный разброс задержки (по умолчанию отключен).
+    pub jitter: Jitter,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: Jitter::None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Политика без повторов: ошибка возвращается после первой попытки.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Количество повторов после первой попытки.
+    pub fn max_retries(&self) -> u32 {
+        self.max_attempts.saturating_sub(1)
+    }
+
+    /// Задержка перед повтором с номером `retry` (с 0) с учетом `jitter`.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self.backoff(retry);
+        match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => delay.mul_f64(random_unit()),
+            Jitter::Equal => delay / 2 + (delay / 2).mul_f64(random_unit()),
+        }
+    }
+
+    /// Задержка перед повтором с номером `retry` без разброса.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.powi(retry.min(i32::MAX as u32) as i32);
+        let delay = self.base_delay.as_secs_f64() * factor;
+        if !delay.is_finite() || delay >= self.max_delay.as_secs_f64() {
+            self.max_delay
+        } else {
+            Duration::from_secs_f64(delay)
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.max_attempts == 0 {
+            return Err(ValidationError::new(
+                "max_attempts",
+                Constraint::Min(1),
+                "RetryPolicy: max_attempts должен быть не меньше 1",
+            )
+            .value(self.max_attempts)
+            .into());
+        }
+        if !self.multiplier.is_finite() || self.multiplier < 1.0 {
+            return Err(ValidationError::new(
+                "multiplier",
+                Constraint::Other,
+                "RetryPolicy: multiplier должен быть не меньше 1.0",
+            )
+            .value(self.multiplier)
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Случайное число в диапазоне `[0, 1)`.
+///
+/// Ключи `RandomState` случайны для каждого процесса, а счетчик делает значения
+/// разными внутри процесса, поэтому отдельный генератор не нужен.
+fn random_unit() -> f64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_delays() {
+        let policy = RetryPolicy::default();
+        let delays: Vec<Duration> = (0..policy.max_retries()).map(|n| policy.delay(n)).collect();
+        assert_eq!(
+            delays,
+            [1, 2, 4].map(Duration::from_secs).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_delay_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 100,
+            max_delay: Duration::from_secs(10),
+            ..Default::default()
+        };
+        assert_eq!(policy.delay(3), Duration::from_secs(8));
+        assert_eq!(policy.delay(4), Duration::from_secs(10));
+        assert_eq!(policy.delay(5000), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_jitter_bounds() {
+        for (jitter, min) in [(Jitter::Full, Duration::ZERO), (Jitter::Equal, Duration::from_secs(2))] {
+            let policy = RetryPolicy { jitter, ..Default::default() };
+            let delays: Vec<Duration> = (0..100).map(|_| policy.delay(2)).collect();
+            assert!(delays.iter().all(|d| *d >= min && *d <= Duration::from_secs(4)));
+            assert!(delays.iter().any(|d| *d != delays[0]));
+        }
+    }
+
+    #[test]
+    fn test_retry_if() {
+        use crate::{ShikicrateClient, ShikicrateClientBuilder};
+
+        let rate_limit = ShikicrateError::RateLimit { message: String::new(), retry_after: None };
+        let server_error = ShikicrateError::Api {
+            status: 503,
+            message: String::new(),
+            headers: Default::default(),
+            operation: None,
+        };
+
+        let client = ShikicrateClient::new().unwrap();
+        assert!(client.should_retry(&rate_limit));
+        assert!(!client.should_retry(&server_error));
+
+        let client = ShikicrateClientBuilder::new()
+            .retry_if(|e| matches!(e, ShikicrateError::Api { status: 503, .. }))
+            .build()
+            .unwrap();
+        assert!(!client.should_retry(&rate_limit));
+        assert!(client.should_retry(&server_error));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(RetryPolicy::default().validate().is_ok());
+        assert_eq!(RetryPolicy::none().max_retries(), 0);
+        assert!(RetryPolicy { max_attempts: 0, ..Default::default() }.validate().is_err());
+        assert!(RetryPolicy { multiplier: 0.5, ..Default::default() }.validate().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Отзывы пользователей на аниме и мангу.
+//!
+//! Отзывы загружаются через REST API Shikimori
+//! (`/api/animes/:id/reviews` и `/api/mangas/:id/reviews`).
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::{User, deser_id, deser_opt_id};
+use serde::{Deserialize, Serialize};
+
+/// Максимальный `limit` для списка отзывов.
+const REVIEWS_MAX_LIMIT: i32 = 30;
+
+/// Мнение автора отзыва.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Opinion {
+    Positive,
+    Neutral,
+    Negative,
+    /// Значение, неизвестное этой версии библиотеки.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Отзыв на аниме или мангу.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    /// ID отзыва.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// ID аниме (для отзывов на аниме).
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub anime_id: Option<i64>,
+
+    /// ID манги (для отзывов на мангу и ранобэ).
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub manga_id: Option<i64>,
+
+    /// Мнение автора.
+    pub opinion: Option<Opinion>,
+
+    /// Текст отзыва (BBCode).
+    pub body: Option<String>,
+
+    /// Текст отзыва (HTML).
+    pub html_body: Option<String>,
+
+    /// Автор отзыва.
+    pub user: Option<User>,
+
+    /// Количество голосов «полезно».
+    #[serde(alias = "cached_votes_up")]
+    pub votes_up: Option<i32>,
+
+    /// Количество голосов «бесполезно».
+    #[serde(alias = "cached_votes_down")]
+    pub votes_down: Option<i32>,
+
+    /// Отзыв написан до окончания выхода тайтла.
+    pub is_written_before_release: Option<bool>,
+
+    /// Количество комментариев к отзыву.
+    pub comments_count: Option<i32>,
+
+    /// Дата создания.
+    pub created_at: Option<String>,
+
+    /// Дата последнего изменения.
+    pub updated_at: Option<String>,
+}
+
+crate::types::identity_by_id!(Review);
+crate::types::utc_accessors!(Review { created_at_utc => created_at, updated_at_utc => updated_at });
+
+impl ShikicrateClient {
+    /// Получение отзывов на аниме.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use shikicrate::reviews::Opinion;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let reviews = client.anime_reviews(1, Some(1), Some(10)).await?;
+    ///
+    /// let positive = reviews.iter().filter(|r| r.opinion == Some(Opinion::Positive)).count();
+    /// println!("Положительных отзывов: {}", positive);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn anime_reviews(&self, anime_id: i64, page: Option<i32>, limit: Option<i32>) -> Result<Vec<Review>> {
+        self.reviews("animes", anime_id, page, limit).await
+    }
+
+    /// Получение отзывов на мангу или ранобэ.
+    pub async fn manga_reviews(&self, manga_id: i64, page: Option<i32>, limit: Option<i32>) -> Result<Vec<Review>> {
+        self.reviews("mangas", manga_id, page, limit).await
+    }
+
+    async fn reviews(&self, target: &str, id: i64, page: Option<i32>, limit: Option<i32>) -> Result<Vec<Review>> {
+        Self::val_reviews(id, page, limit)?;
+
+        let mut query = Vec::new();
+        if let Some(page) = page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        self.get_rest(&format!("{}/{}/reviews", target, id), Some(query)).await
+    }
+
+    fn val_reviews(id: i64, page: Option<i32>, limit: Option<i32>) -> Result<()> {
+        if id <= 0 {
+            return Err(ValidationError::new("id", Constraint::Min(1), "ID тайтла должен быть больше 0").value(id).into());
+        }
+        if page.is_some_and(|page| page < 1) {
+            return Err(ValidationError::new("page", Constraint::Min(1), "Страница должна быть не меньше 1").into());
+        }
+        if limit.is_some_and(|limit| !(1..=REVIEWS_MAX_LIMIT).contains(&limit)) {
+            return Err(ValidationError::new(
+                "limit",
+                Constraint::Range { min: 1, max: REVIEWS_MAX_LIMIT as i64 },
+                format!("Лимит отзывов должен быть от 1 до {}", REVIEWS_MAX_LIMIT),
+            )
+            .value(limit.unwrap_or_default())
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use serde_json::json;
+
+    #[test]
+    fn test_val_reviews() {
+        assert!(ShikicrateClient::val_reviews(1, Some(1), Some(30)).is_ok());
+        for (id, page, limit) in [(0, None, None), (1, Some(0), None), (1, None, Some(31))] {
+            assert!(matches!(
+                ShikicrateClient::val_reviews(id, page, limit),
+                Err(ShikicrateError::Validation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_review_deserialize() {
+        let review: Review = serde_json::from_value(json!({
+            "id": 1,
+            "anime_id": "5081",
+            "opinion": "positive",
+            "cached_votes_up": 12,
+            "user": { "id": 1, "nickname": "morr" }
+        }))
+        .unwrap();
+        assert_eq!(review.anime_id, Some(5081));
+        assert_eq!(review.opinion, Some(Opinion::Positive));
+        assert_eq!(review.votes_up, Some(12));
+
+        let review: Review = serde_json::from_value(json!({ "id": 2, "opinion": "mixed" })).unwrap();
+        assert_eq!(review.opinion, Some(Opinion::Unknown));
+    }
+}
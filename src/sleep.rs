@@ -0,0 +1,96 @@
+//! Ожидание между повторами запросов.
+//!
+//! Паузы между повторами клиент выдерживает через [`Sleeper`]. По умолчанию
+//! это [`TokioSleeper`], а в тестах его можно заменить на [`RecordingSleeper`],
+//! который не ждет, а только запоминает запрошенные задержки, — так поведение
+//! повторов проверяется детерминированно и без реального ожидания.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::ShikicrateClientBuilder;
+//! use shikicrate::sleep::RecordingSleeper;
+//!
+//! # fn example() -> shikicrate::Result<()> {
+//! let sleeper = RecordingSleeper::new();
+//! let client = ShikicrateClientBuilder::new()
+//!     .sleeper(sleeper.clone())
+//!     .build()?;
+//!
+//! // ... запросы с повторами ...
+//! println!("{:?}", sleeper.delays());
+//! # Ok(())
+//! # }
+//! ```
+
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Асинхронное ожидание заданное время.
+pub trait Sleeper: Send + Sync {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// Ожидание через `tokio::time::sleep` (поддерживает `tokio::time::pause`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Завершается сразу и запоминает запрошенные задержки. Клоны разделяют журнал.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingSleeper {
+    delays: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl RecordingSleeper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Запрошенные задержки в порядке вызова.
+    pub fn delays(&self) -> Vec<Duration> {
+        self.delays.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl Sleeper for RecordingSleeper {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.delays.lock().unwrap_or_else(|e| e.into_inner()).push(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use crate::queries::AnimeSearchParams;
+    use crate::retry::RetryPolicy;
+    use crate::transport::{MockTransport, mock_builder};
+
+    #[tokio::test]
+    async fn test_retry_backoff_without_waiting() {
+        let transport = MockTransport::new().status("SearchAnimes", 503);
+        let sleeper = RecordingSleeper::new();
+        let client = mock_builder(&transport)
+            .retry_policy(RetryPolicy {
+                max_attempts: 4,
+                ..Default::default()
+            })
+            .retry_if(|e| matches!(e, ShikicrateError::Api { status: 503, .. }))
+            .sleeper(sleeper.clone())
+            .build()
+            .unwrap();
+
+        let error = client.animes(AnimeSearchParams::default()).await.unwrap_err();
+        assert!(matches!(error, ShikicrateError::Api { status: 503, .. }));
+        assert_eq!(transport.requests().len(), 4);
+        assert_eq!(sleeper.delays(), [1, 2, 4].map(Duration::from_secs));
+    }
+}
@@ -0,0 +1,100 @@
+//! Друзья и игнор-лист пользователя.
+//!
+//! Список друзей доступен без авторизации. Добавление в друзья и управление
+//! игнор-листом выполняются от имени владельца `access_token`.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::User;
+
+impl ShikicrateClient {
+    /// Получение списка друзей пользователя.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    ///
+    /// for friend in client.user_friends(1).await? {
+    ///     println!("{} (был в сети {:?})", friend.nickname, friend.last_online_at);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_friends(&self, user_id: i64) -> Result<Vec<User>> {
+        Self::val_user_id(user_id)?;
+        self.get_rest(&format!("users/{}/friends", user_id), None::<serde_json::Value>)
+            .await
+    }
+
+    /// Добавляет пользователя в друзья. Требует `access_token`.
+    pub async fn add_friend(&self, user_id: i64) -> Result<()> {
+        Self::val_user_id(user_id)?;
+        self.mutate_rest(reqwest::Method::POST, &format!("friends/{}", user_id), None, "friends")
+            .await?;
+        Ok(())
+    }
+
+    /// Удаляет пользователя из друзей. Требует `access_token`.
+    pub async fn remove_friend(&self, user_id: i64) -> Result<()> {
+        Self::val_user_id(user_id)?;
+        self.mutate_rest(reqwest::Method::DELETE, &format!("friends/{}", user_id), None, "friends")
+            .await?;
+        Ok(())
+    }
+
+    /// Добавляет пользователя в игнор-лист. Требует `access_token`.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClientBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClientBuilder::new()
+    ///     .access_token("token".to_string())
+    ///     .build()?;
+    ///
+    /// client.ignore_user(42).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ignore_user(&self, user_id: i64) -> Result<()> {
+        Self::val_user_id(user_id)?;
+        self.mutate_rest(reqwest::Method::POST, &format!("v2/users/{}/ignore", user_id), None, "ignore")
+            .await?;
+        Ok(())
+    }
+
+    /// Удаляет пользователя из игнор-листа. Требует `access_token`.
+    pub async fn unignore_user(&self, user_id: i64) -> Result<()> {
+        Self::val_user_id(user_id)?;
+        self.mutate_rest(reqwest::Method::DELETE, &format!("v2/users/{}/ignore", user_id), None, "ignore")
+            .await?;
+        Ok(())
+    }
+
+    fn val_user_id(user_id: i64) -> Result<()> {
+        if user_id <= 0 {
+            return Err(ValidationError::new("user_id", Constraint::Min(1), "ID пользователя должен быть больше 0").value(user_id).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+
+    #[tokio::test]
+    async fn test_social_validation() {
+        let client = ShikicrateClient::new().unwrap();
+        assert!(matches!(client.user_friends(0).await, Err(ShikicrateError::Validation(_))));
+        assert!(matches!(client.ignore_user(1).await, Err(ShikicrateError::Auth(_))));
+        assert!(matches!(client.unignore_user(-1).await, Err(ShikicrateError::Validation(_))));
+    }
+}
@@ -0,0 +1,205 @@
+//! Очередь синхронизации списка пользователя.
+//!
+//! [`SyncQueue`] применяет набор [`UserRateInput`] к списку пользователя через
+//! REST API: мутации одного пользователя выполняются строго последовательно,
+//! временные ошибки повторяются, а после применения состояние на сервере
+//! сверяется с желаемым.
+//!
+//! Перед каждой попыткой очередь заново читает текущую запись с сервера, поэтому
+//! повтор после потерянного ответа не создает дубликат: если запись уже создана
+//! или обновлена, попытка завершается без изменений.
+
+use crate::client::ShikicrateClient;
+use crate::error::{Result, ShikicrateError};
+use crate::types::{UserRate, UserRateInput};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Расхождение между желаемым и фактическим состоянием записи после синхронизации.
+#[derive(Debug, Clone)]
+pub struct SyncMismatch {
+    /// Желаемое состояние.
+    pub intended: UserRateInput,
+    /// Запись на сервере (`None`, если записи нет).
+    pub actual: Option<UserRate>,
+}
+
+/// Итог синхронизации.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// Количество созданных или обновленных записей.
+    pub applied: usize,
+    /// Количество записей, которые уже были в нужном состоянии.
+    pub skipped: usize,
+    /// Записи, которые не удалось применить, и последняя ошибка.
+    pub failed: Vec<(UserRateInput, ShikicrateError)>,
+    /// Записи, состояние которых на сервере после синхронизации отличается от желаемого.
+    pub mismatches: Vec<SyncMismatch>,
+}
+
+impl SyncReport {
+    /// Возвращает `true`, если все записи применены и сверка не нашла расхождений.
+    pub fn is_consistent(&self) -> bool {
+        self.failed.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+/// Очередь мутаций списка пользователя.
+///
+/// Клоны очереди разделяют блокировки, поэтому параллельные вызовы
+/// [`SyncQueue::sync`] для одного пользователя выполняются по очереди, а для
+/// разных пользователей — независимо.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::{ShikicrateClientBuilder, UserRateInput, WatchStatus};
+/// use shikicrate::sync::SyncQueue;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ShikicrateClientBuilder::new()
+///     .access_token("token".to_string())
+///     .build()?;
+/// let queue = SyncQueue::new(&client);
+///
+/// let mut input = UserRateInput::new(1, "Anime");
+/// input.status = Some(WatchStatus::Completed);
+/// input.score = Some(9);
+///
+/// let report = queue.sync(12345, vec![input]).await;
+/// if !report.is_consistent() {
+///     eprintln!("Расхождения: {:?}", report.mismatches);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SyncQueue {
+    client: Arc<ShikicrateClient>,
+    locks: Arc<Mutex<HashMap<i64, Arc<tokio::sync::Mutex<()>>>>>,
+    max_retries: usize,
+}
+
+enum Applied {
+    Changed,
+    Unchanged,
+}
+
+impl SyncQueue {
+    /// Создает очередь поверх клиента (клиент должен иметь `access_token`).
+    pub fn new(client: &ShikicrateClient) -> Self {
+        Self {
+            client: client.to_arc(),
+            locks: Arc::new(Mutex::new(HashMap::new())),
+            max_retries: client.retry_policy().max_retries() as usize,
+        }
+    }
+
+    /// Устанавливает максимальное количество повторов для одной записи.
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Применяет записи к списку пользователя и сверяет результат с сервером.
+    pub async fn sync(&self, user_id: i64, inputs: Vec<UserRateInput>) -> SyncReport {
+        let lock = self.user_lock(user_id);
+        let _guard = lock.lock().await;
+
+        let mut report = SyncReport::default();
+        let mut done = Vec::new();
+
+        for input in inputs {
+            match self.apply_with_retry(user_id, &input).await {
+                Ok(Applied::Changed) => {
+                    report.applied += 1;
+                    done.push(input);
+                }
+                Ok(Applied::Unchanged) => {
+                    report.skipped += 1;
+                    done.push(input);
+                }
+                Err(e) => report.failed.push((input, e)),
+            }
+        }
+
+        for input in done {
+            match self.current(user_id, &input).await {
+                Ok(actual) if actual.as_ref().is_some_and(|rate| input.is_applied_to(rate)) => {}
+                Ok(actual) => report.mismatches.push(SyncMismatch { intended: input, actual }),
+                Err(e) => report.failed.push((input, e)),
+            }
+        }
+
+        report
+    }
+
+    fn user_lock(&self, user_id: i64) -> Arc<tokio::sync::Mutex<()>> {
+        let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(locks.entry(user_id).or_default())
+    }
+
+    async fn apply_with_retry(&self, user_id: i64, input: &UserRateInput) -> Result<Applied> {
+        ShikicrateClient::val_rate_input(input)?;
+
+        let mut attempt = 0;
+        loop {
+            match self.apply(user_id, input).await {
+                Err(e) if attempt < self.max_retries && self.client.should_retry(&e) => {
+                    self.client.sleep(self.client.retry_delay(attempt as u32)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn apply(&self, user_id: i64, input: &UserRateInput) -> Result<Applied> {
+        match self.current(user_id, input).await? {
+            Some(rate) if input.is_applied_to(&rate) => Ok(Applied::Unchanged),
+            Some(rate) => {
+                self.client.update_user_rate(rate.id, input).await?;
+                Ok(Applied::Changed)
+            }
+            None => {
+                self.client.create_user_rate(user_id, input).await?;
+                Ok(Applied::Changed)
+            }
+        }
+    }
+
+    async fn current(&self, user_id: i64, input: &UserRateInput) -> Result<Option<UserRate>> {
+        let query = [
+            ("user_id", user_id.to_string()),
+            ("target_id", input.target_id.to_string()),
+            ("target_type", input.target_type.clone()),
+        ];
+        let rates: Vec<UserRate> = self.client.get_rest("v2/user_rates", Some(query)).await?;
+        Ok(rates.into_iter().next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_lock_is_shared_between_clones() {
+        let queue = SyncQueue::new(&ShikicrateClient::new().unwrap());
+        let clone = queue.clone();
+
+        assert!(Arc::ptr_eq(&queue.user_lock(1), &clone.user_lock(1)));
+        assert!(!Arc::ptr_eq(&queue.user_lock(1), &queue.user_lock(2)));
+    }
+
+    #[tokio::test]
+    async fn test_sync_reports_invalid_input() {
+        let queue = SyncQueue::new(&ShikicrateClient::new().unwrap()).max_retries(0);
+        let mut input = UserRateInput::new(1, "Anime");
+        input.score = Some(11);
+
+        let report = queue.sync(1, vec![input]).await;
+        assert_eq!(report.failed.len(), 1);
+        assert!(!report.is_consistent());
+    }
+}
@@ -0,0 +1,231 @@
+//! Локальный mock сервер Shikimori для интеграционных тестов (feature `test-util`).
+//!
+//! [`MockServer`] поднимает HTTP сервер на `127.0.0.1` со случайным портом и
+//! отвечает заготовленными ответами [`MockTransport`]: успешными данными,
+//! GraphQL ошибками, 429 с `Retry-After` или 5xx. В отличие от подмены
+//! транспорта, запросы проходят через настоящий HTTP стек клиента (таймауты,
+//! пул соединений, заголовки).
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::queries::*;
+//! use shikicrate::testing::MockServer;
+//! use shikicrate::transport::MockTransport;
+//! use serde_json::json;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockServer::start(
+//!     MockTransport::new()
+//!         .rate_limited("SearchAnimes", 1)
+//!         .graphql("SearchAnimes", json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop" }] })),
+//! )
+//! .await?;
+//!
+//! let client = server.client_builder().build()?;
+//! // Первая попытка получает 429, повтор — данные
+//! let animes = client.animes(AnimeSearchParams::default()).await?;
+//! assert_eq!(server.requests().len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::ShikicrateClientBuilder;
+use crate::error::Result;
+use crate::rate_limit::TokenBucket;
+use crate::transport::{MockTransport, RecordedRequest, Transport};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// HTTP сервер, отвечающий через [`MockTransport`]. Останавливается при удалении.
+pub struct MockServer {
+    url: String,
+    transport: MockTransport,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Запускает сервер с ответами `transport`.
+    pub async fn start(transport: MockTransport) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let task = tokio::spawn({
+            let transport = transport.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, transport.clone()));
+                }
+            }
+        });
+        Ok(Self { url, transport, task })
+    }
+
+    /// Адрес сервера (`http://127.0.0.1:<порт>`).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Адрес GraphQL API сервера.
+    pub fn graphql_url(&self) -> String {
+        format!("{}/api/graphql", self.url)
+    }
+
+    /// Builder клиента, направленного на сервер (GraphQL и REST), без
+    /// ожидания между запросами.
+    pub fn client_builder(&self) -> ShikicrateClientBuilder {
+        ShikicrateClientBuilder::new()
+            .mirrors(vec![self.url.clone()])
+            .token_bucket(TokenBucket {
+                per_second: 1000,
+                per_minute: 60_000,
+            })
+    }
+
+    /// Запросы, полученные сервером, в порядке поступления.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.transport.requests()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Обслуживает запросы одного соединения (HTTP/1.1 с keep-alive).
+async fn serve(stream: TcpStream, transport: MockTransport) {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    while let Some(request) = read_request(&mut reader).await {
+        let Ok(response) = transport.execute(request).await else {
+            return;
+        };
+        if writer.write_all(&encode_response(response).await).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Читает запрос из соединения. `None` при закрытии соединения или некорректном запросе.
+async fn read_request<R>(reader: &mut R) -> Option<reqwest::Request>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    let mut line = String::new();
+    reader.read_line(&mut line).await.ok().filter(|&read| read > 0)?;
+    let mut parts = line.split_whitespace();
+    let method = reqwest::Method::from_bytes(parts.next()?.as_bytes()).ok()?;
+    let target = parts.next()?.to_string();
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).await.ok().filter(|&read| read > 0)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':')?;
+        headers.append(
+            reqwest::header::HeaderName::from_bytes(name.trim().as_bytes()).ok()?,
+            reqwest::header::HeaderValue::from_str(value.trim()).ok()?,
+        );
+    }
+
+    let length = headers
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok()?.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await.ok()?;
+
+    let host = headers.get(reqwest::header::HOST)?.to_str().ok()?;
+    let url = url::Url::parse(&format!("http://{}{}", host, target)).ok()?;
+    let mut request = reqwest::Request::new(method, url);
+    *request.headers_mut() = headers;
+    *request.body_mut() = Some(body.into());
+    Some(request)
+}
+
+async fn encode_response(response: reqwest::Response) -> Vec<u8> {
+    let status = response.status();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or("")
+    );
+    for (name, value) in response.headers() {
+        if name != reqwest::header::CONTENT_LENGTH {
+            head.push_str(&format!("{}: {}\r\n", name, String::from_utf8_lossy(value.as_bytes())));
+        }
+    }
+    let body = response.bytes().await.unwrap_or_default();
+    head.push_str(&format!("content-length: {}\r\n\r\n", body.len()));
+
+    let mut bytes = head.into_bytes();
+    bytes.extend_from_slice(&body);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use crate::queries::AnimeSearchParams;
+    use crate::retry::RetryPolicy;
+    use crate::sleep::RecordingSleeper;
+    use serde_json::json;
+
+    fn bebop() -> serde_json::Value {
+        json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop" }] })
+    }
+
+    #[tokio::test]
+    async fn test_success_and_errors() {
+        let server = MockServer::start(
+            MockTransport::new()
+                .graphql("SearchAnimes", bebop())
+                .graphql_errors("SearchMangas", json!([{ "message": "Internal error" }]))
+                .status("genres", 502),
+        )
+        .await
+        .unwrap();
+        let client = server.client_builder().retry_policy(RetryPolicy::none()).build().unwrap();
+
+        let animes = client.animes(AnimeSearchParams::default()).await.unwrap();
+        assert_eq!(animes[0].name, "Cowboy Bebop");
+
+        let error = client.mangas(Default::default()).await.unwrap_err();
+        let ShikicrateError::GraphQL { errors: Some(errors), details, .. } = error else {
+            panic!("ожидалась GraphQL ошибка: {:?}", error);
+        };
+        assert_eq!(errors[0]["message"], "Internal error");
+        assert_eq!(details[0].message, "Internal error");
+
+        let error = client.genres().await.unwrap_err();
+        assert!(matches!(error, ShikicrateError::Api { status: 502, .. }));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "POST");
+        assert!(requests[2].url.ends_with("/api/genres"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_then_success() {
+        let server = MockServer::start(
+            MockTransport::new()
+                .rate_limited("SearchAnimes", 1)
+                .graphql("SearchAnimes", bebop()),
+        )
+        .await
+        .unwrap();
+        let client = server.client_builder().sleeper(RecordingSleeper::new()).build().unwrap();
+
+        let animes = client.animes(AnimeSearchParams::default()).await.unwrap();
+        assert_eq!(animes.len(), 1);
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(client.stats().rate_limited, 1);
+    }
+}
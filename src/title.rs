@@ -0,0 +1,108 @@
+//! Выбор названия на предпочитаемом языке.
+//!
+//! У тайтлов, персонажей и людей несколько названий: русское, английское,
+//! ромадзи (поле `name`) и японское, и любое из них, кроме `name`, может
+//! отсутствовать. [`TitlePreference`] задает порядок языков, а методы `title()`
+//! у [`Anime`](crate::types::Anime), [`Manga`](crate::types::Manga),
+//! [`Character`](crate::types::Character), [`Person`](crate::types::Person)
+//! и их полных версий возвращают первое непустое название из этого порядка.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClient, queries::*};
+//! use shikicrate::title::{TitleLanguage, TitlePreference};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ShikicrateClient::new()?;
+//! let animes = client.animes(AnimeSearchParams::builder().search("bebop")).await?;
+//!
+//! // Русское → английское → ромадзи
+//! let preference = TitlePreference::default();
+//! // Для англоязычного интерфейса
+//! let english = TitlePreference::new([TitleLanguage::English, TitleLanguage::Romaji]);
+//!
+//! for anime in &animes {
+//!     println!("{} / {}", anime.title(&preference), anime.title(&english));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// Язык названия.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitleLanguage {
+    /// Русское название (`russian`).
+    Russian,
+    /// Английское название (`english`); есть только у аниме и манги.
+    English,
+    /// Название латиницей (`name`).
+    Romaji,
+    /// Японское название (`japanese`).
+    Japanese,
+}
+
+/// Порядок языков при выборе названия.
+///
+/// Если ни одного названия на перечисленных языках нет, используется `name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitlePreference {
+    languages: Vec<TitleLanguage>,
+}
+
+impl TitlePreference {
+    pub fn new(languages: impl IntoIterator<Item = TitleLanguage>) -> Self {
+        Self {
+            languages: languages.into_iter().collect(),
+        }
+    }
+
+    /// Языки в порядке приоритета.
+    pub fn languages(&self) -> &[TitleLanguage] {
+        &self.languages
+    }
+
+    /// Первое непустое название по приоритету, иначе `name`.
+    pub(crate) fn resolve<'a>(
+        &self,
+        name: &'a str,
+        russian: Option<&'a str>,
+        english: Option<&'a str>,
+        japanese: Option<&'a str>,
+    ) -> &'a str {
+        self.languages
+            .iter()
+            .filter_map(|language| match language {
+                TitleLanguage::Russian => russian,
+                TitleLanguage::English => english,
+                TitleLanguage::Romaji => Some(name),
+                TitleLanguage::Japanese => japanese,
+            })
+            .find(|title| !title.trim().is_empty())
+            .unwrap_or(name)
+    }
+}
+
+/// Русское → английское → ромадзи.
+impl Default for TitlePreference {
+    fn default() -> Self {
+        Self::new([TitleLanguage::Russian, TitleLanguage::English, TitleLanguage::Romaji])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let preference = TitlePreference::default();
+        assert_eq!(preference.resolve("Bebop", Some("Бибоп"), Some("Cowboy Bebop"), None), "Бибоп");
+        assert_eq!(preference.resolve("Bebop", Some(" "), Some("Cowboy Bebop"), None), "Cowboy Bebop");
+        assert_eq!(preference.resolve("Bebop", None, None, Some("カウボーイビバップ")), "Bebop");
+
+        let japanese = TitlePreference::new([TitleLanguage::Japanese]);
+        assert_eq!(japanese.resolve("Bebop", None, None, Some("カウボーイビバップ")), "カウボーイビバップ");
+        assert_eq!(japanese.resolve("Bebop", Some("Бибоп"), None, None), "Bebop");
+    }
+}
@@ -0,0 +1,265 @@
+//! Топики форума и новости.
+//!
+//! Топики загружаются через REST API Shikimori (`/api/topics`): можно выбрать
+//! раздел форума, тип топика или связанную сущность (например, все топики
+//! аниме с заданным ID).
+
+use crate::client::ShikicrateClient;
+use crate::error::{Constraint, Result, ValidationError};
+use crate::types::{EntryRef, User, deser_id, deser_opt_id};
+use serde::{Deserialize, Serialize};
+
+/// Максимальный `limit` для `/api/topics`.
+const TOPICS_MAX_LIMIT: i32 = 30;
+
+/// Параметры поиска топиков.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct TopicSearchParams {
+    pub page: Option<i32>,
+    /// Количество топиков на странице (не больше 30).
+    pub limit: Option<i32>,
+    /// Раздел форума: `"all"`, `"animanga"`, `"site"`, `"news"`, `"clubs"` и т.д.
+    pub forum: Option<String>,
+    /// ID связанной сущности (вместе с `linked_type`).
+    pub linked_id: Option<i64>,
+    /// Тип связанной сущности: `"Anime"`, `"Manga"`, `"Ranobe"`, `"Character"`, `"Person"`, `"Club"` и т.д.
+    pub linked_type: Option<String>,
+    /// Тип топика, например `"Topics::NewsTopic"`.
+    pub topic_type: Option<String>,
+}
+
+/// Раздел форума.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Forum {
+    /// ID раздела.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// Название раздела.
+    pub name: String,
+
+    /// Короткое имя раздела в URL (например, `"animanga"`).
+    pub permalink: Option<String>,
+
+    /// URL раздела на Shikimori.
+    pub url: Option<String>,
+}
+
+/// Топик форума или новость.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Topic {
+    /// ID топика.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// Заголовок топика.
+    pub topic_title: String,
+
+    /// Текст топика (BBCode).
+    pub body: Option<String>,
+
+    /// Текст топика (HTML).
+    pub html_body: Option<String>,
+
+    /// Дата создания.
+    pub created_at: Option<String>,
+
+    /// Количество комментариев.
+    pub comments_count: Option<i32>,
+
+    /// Раздел форума.
+    pub forum: Option<Forum>,
+
+    /// Автор топика.
+    pub user: Option<User>,
+
+    /// Тип топика, например `"Topics::NewsTopic"`.
+    #[serde(rename = "type")]
+    pub topic_type: Option<String>,
+
+    /// ID связанной сущности.
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub linked_id: Option<i64>,
+
+    /// Тип связанной сущности (`"Anime"`, `"Manga"`, `"Club"` и т.д.).
+    pub linked_type: Option<String>,
+
+    /// Связанная сущность в исходном виде (структура зависит от `linked_type`).
+    pub linked: Option<serde_json::Value>,
+
+    /// Событие новости (например, `"released"` или `"episode"`).
+    pub event: Option<String>,
+
+    /// Номер эпизода для новостей о выходе серии.
+    pub episode: Option<i32>,
+}
+
+crate::types::identity_by_id!(Topic);
+crate::types::utc_accessors!(Topic { created_at_utc => created_at });
+
+impl Topic {
+    /// Краткая ссылка на связанную сущность, если у нее есть ID и название.
+    pub fn linked_ref(&self) -> Option<EntryRef> {
+        self.linked.clone().and_then(|linked| serde_json::from_value(linked).ok())
+    }
+}
+
+impl TopicSearchParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn forum(mut self, forum: impl Into<String>) -> Self {
+        self.forum = Some(forum.into());
+        self
+    }
+
+    /// Топики, связанные с сущностью (например, `linked(1, "Anime")`).
+    pub fn linked(mut self, linked_id: i64, linked_type: impl Into<String>) -> Self {
+        self.linked_id = Some(linked_id);
+        self.linked_type = Some(linked_type.into());
+        self
+    }
+
+    pub fn topic_type(mut self, topic_type: impl Into<String>) -> Self {
+        self.topic_type = Some(topic_type.into());
+        self
+    }
+
+    fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(forum) = &self.forum {
+            query.push(("forum", forum.clone()));
+        }
+        if let Some(linked_id) = self.linked_id {
+            query.push(("linked_id", linked_id.to_string()));
+        }
+        if let Some(linked_type) = &self.linked_type {
+            query.push(("linked_type", linked_type.clone()));
+        }
+        if let Some(topic_type) = &self.topic_type {
+            query.push(("type", topic_type.clone()));
+        }
+        query
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.page.is_some_and(|page| page < 1) {
+            return Err(ValidationError::new("page", Constraint::Min(1), "Страница должна быть не меньше 1").into());
+        }
+        if self.limit.is_some_and(|limit| !(1..=TOPICS_MAX_LIMIT).contains(&limit)) {
+            return Err(ValidationError::new(
+                "limit",
+                Constraint::Range { min: 1, max: TOPICS_MAX_LIMIT as i64 },
+                format!("Лимит топиков должен быть от 1 до {}", TOPICS_MAX_LIMIT),
+            )
+            .value(self.limit.unwrap_or_default())
+            .into());
+        }
+        if self.linked_id.is_some() != self.linked_type.is_some() {
+            let field = if self.linked_id.is_some() { "linked_id" } else { "linked_type" };
+            let other = if self.linked_id.is_some() { "linked_type" } else { "linked_id" };
+            return Err(
+                ValidationError::new(field, Constraint::Requires(other), "linked_id и linked_type указываются вместе").into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl ShikicrateClient {
+    /// Получение топиков форума и новостей.
+    ///
+    /// # Примеры
+    ///
+    /// ```no_run
+    /// use shikicrate::ShikicrateClient;
+    /// use shikicrate::topics::TopicSearchParams;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ShikicrateClient::new()?;
+    /// let news = client
+    ///     .topics(TopicSearchParams::builder().forum("news").limit(10))
+    ///     .await?;
+    ///
+    /// for topic in news {
+    ///     println!("{} ({} комментариев)", topic.topic_title, topic.comments_count.unwrap_or(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn topics(&self, params: TopicSearchParams) -> Result<Vec<Topic>> {
+        params.validate()?;
+        self.get_rest("topics", Some(params.to_query())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ShikicrateError;
+    use serde_json::json;
+
+    #[test]
+    fn test_topic_params() {
+        let params = TopicSearchParams {
+            linked_id: Some(1),
+            linked_type: Some("Anime".to_string()),
+            topic_type: Some("Topics::NewsTopic".to_string()),
+            ..Default::default()
+        };
+        assert!(params.validate().is_ok());
+        assert_eq!(
+            params.to_query(),
+            vec![
+                ("linked_id", "1".to_string()),
+                ("linked_type", "Anime".to_string()),
+                ("type", "Topics::NewsTopic".to_string()),
+            ]
+        );
+
+        let invalid = [
+            TopicSearchParams { limit: Some(31), ..Default::default() },
+            TopicSearchParams { page: Some(0), ..Default::default() },
+            TopicSearchParams { linked_id: Some(1), ..Default::default() },
+        ];
+        for params in invalid {
+            assert!(matches!(params.validate(), Err(ShikicrateError::Validation(_))));
+        }
+    }
+
+    #[test]
+    fn test_topic_deserialize() {
+        let topic: Topic = serde_json::from_value(json!({
+            "id": 1,
+            "topic_title": "Cowboy Bebop",
+            "forum": { "id": 1, "name": "Аниме и манга", "permalink": "animanga" },
+            "user": { "id": 1, "nickname": "morr", "avatar": "/avatar.png" },
+            "type": "Topics::EntryTopics::AnimeTopic",
+            "linked_id": 1,
+            "linked_type": "Anime",
+            "linked": { "id": 1, "name": "Cowboy Bebop", "kind": "tv" }
+        }))
+        .unwrap();
+
+        assert_eq!(topic.user.as_ref().unwrap().avatar_url.as_deref(), Some("/avatar.png"));
+        assert_eq!(topic.linked_ref().unwrap().kind.as_deref(), Some("tv"));
+    }
+}
@@ -0,0 +1,381 @@
+//! Транспорт HTTP запросов.
+//!
+//! Клиент отправляет готовые запросы через [`Transport`]. По умолчанию это
+//! `reqwest::Client`, а в тестах его можно заменить на [`MockTransport`],
+//! который возвращает заготовленные ответы без обращения к сети.
+//! [`FixtureTransport`] отвечает содержимым файлов из каталога и позволяет
+//! запускать примеры и демо без сети и токенов.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClientBuilder, queries::*};
+//! use shikicrate::transport::MockTransport;
+//! use serde_json::json;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let transport = MockTransport::new()
+//!     .graphql("SearchAnimes", json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop" }] }));
+//!
+//! let client = ShikicrateClientBuilder::new()
+//!     .transport(transport.clone())
+//!     .build()?;
+//!
+//! let animes = client.animes(AnimeSearchParams::default()).await?;
+//! assert_eq!(animes[0].name, "Cowboy Bebop");
+//! assert_eq!(transport.requests().len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::meta;
+use futures::future::BoxFuture;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Отправка HTTP запроса и получение ответа.
+pub trait Transport: Send + Sync {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>>;
+}
+
+impl Transport for reqwest::Client {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        Box::pin(async move { Ok(reqwest::Client::execute(self, request).await?) })
+    }
+}
+
+/// Запрос, полученный [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+
+    /// Имя GraphQL операции (для REST запросов — `None`).
+    pub operation: Option<String>,
+
+    /// Тело запроса, если это JSON.
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Clone)]
+enum Matcher {
+    Operation(String),
+    Path(String),
+}
+
+#[derive(Clone)]
+struct CannedResponse {
+    matcher: Matcher,
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: serde_json::Value,
+    used: bool,
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: Vec<CannedResponse>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// Транспорт с заготовленными ответами для тестов.
+///
+/// GraphQL запросы сопоставляются по имени операции, REST — по окончанию пути.
+/// Несколько ответов для одной операции выдаются по очереди, а последний
+/// повторяется (например, 429, затем успешный ответ). На запрос без
+/// заготовленного ответа возвращается 404. Клоны разделяют ответы и журнал
+/// запросов.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(self, matcher: Matcher, status: u16, body: serde_json::Value) -> Self {
+        self.with_headers(matcher, status, Vec::new(), body)
+    }
+
+    fn with_headers(
+        self,
+        matcher: Matcher,
+        status: u16,
+        headers: Vec<(&'static str, String)>,
+        body: serde_json::Value,
+    ) -> Self {
+        self.lock().responses.push(CannedResponse {
+            matcher,
+            status,
+            headers,
+            body,
+            used: false,
+        });
+        self
+    }
+
+    fn matcher(target: &str) -> Matcher {
+        if target.contains('/') || target.chars().next().is_some_and(char::is_lowercase) {
+            Matcher::Path(target.trim_start_matches('/').to_string())
+        } else {
+            Matcher::Operation(target.to_string())
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Ответ `{"data": data}` на GraphQL операцию `operation` (например, `SearchAnimes`).
+    pub fn graphql(self, operation: &str, data: serde_json::Value) -> Self {
+        self.with(Matcher::Operation(operation.to_string()), 200, serde_json::json!({ "data": data }))
+    }
+
+    /// Ответ с GraphQL ошибками `{"errors": errors}` на операцию `operation`.
+    pub fn graphql_errors(self, operation: &str, errors: serde_json::Value) -> Self {
+        self.with(Matcher::Operation(operation.to_string()), 200, serde_json::json!({ "errors": errors }))
+    }
+
+    /// JSON ответ на REST запрос, путь которого заканчивается на `path` (например, `users/1/favourites`).
+    pub fn rest(self, path: &str, body: serde_json::Value) -> Self {
+        self.with(Matcher::Path(path.trim_start_matches('/').to_string()), 200, body)
+    }
+
+    /// Ответ с HTTP статусом `status` на GraphQL операцию или REST путь `target`.
+    pub fn status(self, target: &str, status: u16) -> Self {
+        self.with(Self::matcher(target), status, serde_json::Value::Null)
+    }
+
+    /// Ответ 429 с заголовком `Retry-After: retry_after` на GraphQL операцию или REST путь `target`.
+    pub fn rate_limited(self, target: &str, retry_after: u64) -> Self {
+        self.with_headers(
+            Self::matcher(target),
+            429,
+            vec![("retry-after", retry_after.to_string())],
+            serde_json::json!({ "message": "Retry later" }),
+        )
+    }
+
+    /// Запросы, полученные транспортом, в порядке поступления.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    fn respond(&self, request: &reqwest::Request) -> reqwest::Response {
+        let (body, operation) = json_body(request);
+        let path = request.url().path().trim_end_matches('/').to_string();
+
+        let mut state = self.lock();
+        state.requests.push(RecordedRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            operation: operation.clone(),
+            body,
+        });
+
+        let matching: Vec<usize> = state
+            .responses
+            .iter()
+            .enumerate()
+            .filter(|(_, canned)| match &canned.matcher {
+                Matcher::Operation(name) => operation.as_deref() == Some(name),
+                Matcher::Path(suffix) => operation.is_none() && path.ends_with(suffix.as_str()),
+            })
+            .map(|(index, _)| index)
+            .collect();
+        let index = matching
+            .iter()
+            .copied()
+            .find(|&index| !state.responses[index].used)
+            .or(matching.last().copied());
+        let Some(index) = index else {
+            let body = serde_json::json!({ "message": "MockTransport: нет заготовленного ответа" });
+            return json_response(404, &[], body.to_string());
+        };
+
+        let canned = &mut state.responses[index];
+        canned.used = true;
+        json_response(canned.status, &canned.headers, canned.body.to_string())
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        let response = self.respond(&request);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// Транспорт, отвечающий содержимым JSON файлов из каталога.
+///
+/// Ответ на GraphQL запрос берется из файла `<операция>.json`
+/// (например, `SearchAnimes.json`), на REST запрос — из файла с путем после
+/// `/api/`, в котором `/` заменены на `_` (`users_1_favourites.json`).
+/// Файл содержит тело ответа целиком, как его возвращает API
+/// (`{"data": ...}` для GraphQL). Если файла нет, возвращается 404 с именем
+/// ожидаемого файла в сообщении.
+///
+/// Обычно подключается через
+/// [`ShikicrateClientBuilder::offline_fixtures`](crate::ShikicrateClientBuilder::offline_fixtures).
+#[derive(Debug, Clone)]
+pub struct FixtureTransport {
+    dir: PathBuf,
+}
+
+impl FixtureTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Имя файла с ответом на запрос.
+    fn file_name(request: &reqwest::Request) -> String {
+        if let (_, Some(operation)) = json_body(request) {
+            return format!("{}.json", operation);
+        }
+        let path = request.url().path();
+        let path = path.split_once("/api/").map_or(path, |(_, rest)| rest);
+        format!("{}.json", path.trim_matches('/').replace('/', "_"))
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn execute(&self, request: reqwest::Request) -> BoxFuture<'_, Result<reqwest::Response>> {
+        let name = Self::file_name(&request);
+        let path = self.dir.join(&name);
+        Box::pin(async move {
+            let response = match std::fs::read_to_string(&path) {
+                Ok(body) => json_response(200, &[], body),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => json_response(
+                    404,
+                    &[],
+                    serde_json::json!({ "message": format!("нет файла {}", path.display()) }).to_string(),
+                ),
+                Err(e) => return Err(e.into()),
+            };
+            Ok(response)
+        })
+    }
+}
+
+/// JSON тело запроса и имя GraphQL операции из него.
+fn json_body(request: &reqwest::Request) -> (Option<serde_json::Value>, Option<String>) {
+    let body: Option<serde_json::Value> = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .and_then(|bytes| serde_json::from_slice(bytes).ok());
+    let operation = body
+        .as_ref()
+        .and_then(|body| body.get("query"))
+        .and_then(|query| query.as_str())
+        .and_then(meta::operation_name);
+    (body, operation)
+}
+
+fn json_response(status: u16, headers: &[(&'static str, String)], body: String) -> reqwest::Response {
+    let mut builder = http::Response::builder()
+        .status(status)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    for (name, value) in headers {
+        builder = builder.header(*name, value);
+    }
+    let response = builder.body(body).expect("valid status and header");
+    reqwest::Response::from(response)
+}
+
+/// Builder клиента поверх `transport` без ожидания в ограничителе частоты.
+#[cfg(test)]
+pub(crate) fn mock_builder(transport: &MockTransport) -> crate::ShikicrateClientBuilder {
+    crate::ShikicrateClientBuilder::new()
+        .transport(transport.clone())
+        .token_bucket(crate::rate_limit::TokenBucket::default())
+}
+
+/// Клиент поверх `transport` для тестов.
+#[cfg(test)]
+pub(crate) fn mock_client(transport: &MockTransport) -> crate::ShikicrateClient {
+    mock_builder(transport).build().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShikicrateClientBuilder;
+    use crate::error::ShikicrateError;
+    use crate::queries::AnimeSearchParams;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let transport = MockTransport::new()
+            .graphql("SearchAnimes", json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop" }] }))
+            .status("genres", 503);
+        let client = mock_builder(&transport)
+            .retry_policy(crate::retry::RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let animes = client
+            .animes(AnimeSearchParams {
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(animes[0].name, "Cowboy Bebop");
+
+        let error = client.genres().await.unwrap_err();
+        assert!(matches!(error, ShikicrateError::Api { status: 503, .. }));
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].operation.as_deref(), Some("SearchAnimes"));
+        assert_eq!(requests[0].body.as_ref().unwrap()["variables"]["limit"], 1);
+        assert!(requests[1].url.ends_with("/api/genres"));
+    }
+
+    #[tokio::test]
+    async fn test_api_error_context() {
+        let transport = MockTransport::new().with_headers(
+            Matcher::Operation("SearchAnimes".to_string()),
+            500,
+            vec![("x-request-id", "req-1".to_string()), ("cf-ray", "8f1c-AMS".to_string())],
+            json!({ "message": "Internal error" }),
+        );
+        let client = mock_builder(&transport)
+            .retry_policy(crate::retry::RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let error = client.animes(AnimeSearchParams::default()).await.unwrap_err();
+        let ShikicrateError::Api { status, headers, operation, .. } = error else {
+            panic!("ожидалась ошибка API: {:?}", error);
+        };
+        assert_eq!(status, 500);
+        assert_eq!(headers.request_id.as_deref(), Some("req-1"));
+        assert_eq!(headers.cf_ray.as_deref(), Some("8f1c-AMS"));
+        assert_eq!(headers.content_type.as_deref(), Some("application/json"));
+        assert_eq!(operation.as_deref(), Some("SearchAnimes"));
+    }
+
+    #[tokio::test]
+    async fn test_offline_fixtures() {
+        let dir = std::env::temp_dir().join(format!("shikicrate-fixtures-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("SearchAnimes.json"),
+            r#"{"data": {"animes": [{"id": "1", "name": "Cowboy Bebop"}]}}"#,
+        )
+        .unwrap();
+
+        let client = ShikicrateClientBuilder::new().offline_fixtures(&dir).build().unwrap();
+        let animes = client.animes(AnimeSearchParams::default()).await.unwrap();
+        assert_eq!(animes[0].name, "Cowboy Bebop");
+
+        let error = client.genres().await.unwrap_err();
+        assert!(matches!(error, ShikicrateError::Api { status: 404, message, .. } if message.contains("genres.json")));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -2,7 +2,159 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use ts_rs::TS;
 
-fn deser_id<'de, D>(deserializer: D) -> Result<i64, D::Error>
+use crate::description;
+use crate::title::TitlePreference;
+use crate::urls::absolute_url;
+
+pub use crate::date::PartialDate;
+
+/// Разбирает метку времени ISO 8601 из ответа API
+/// (`"2024-02-03T17:30:00.000+03:00"`) в UTC.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&chrono::Utc))
+}
+
+/// Поле с меткой времени: `String` или `Option<String>`.
+#[cfg(feature = "chrono")]
+pub(crate) trait TimestampField {
+    fn timestamp(&self) -> Option<&str>;
+}
+
+#[cfg(feature = "chrono")]
+impl TimestampField for String {
+    fn timestamp(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TimestampField for Option<String> {
+    fn timestamp(&self) -> Option<&str> {
+        self.as_deref()
+    }
+}
+
+/// Методы `*_utc()` (feature `chrono`), возвращающие строковые метки времени
+/// как `chrono::DateTime<Utc>`. Сами поля остаются строками при любом наборе
+/// features.
+macro_rules! utc_accessors {
+    ($ty:ty { $($method:ident => $field:ident),* $(,)? }) => {
+        #[cfg(feature = "chrono")]
+        impl $ty {
+            $(
+                #[doc = concat!(
+                    "`", stringify!($field), "` в UTC (`None`, если значения нет или его не удалось разобрать)."
+                )]
+                pub fn $method(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                    crate::types::parse_timestamp(crate::types::TimestampField::timestamp(&self.$field)?)
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use utc_accessors;
+
+/// `PartialEq`, `Eq` и `Hash` по полю `id`.
+///
+/// Две версии одной сущности (например, из разных страниц или запросов с
+/// разным набором полей) считаются равными, поэтому результаты можно
+/// складывать в `HashSet` и убирать дубликаты.
+macro_rules! identity_by_id {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl PartialEq for $ty {
+                fn eq(&self, other: &Self) -> bool {
+                    self.id == other.id
+                }
+            }
+
+            impl Eq for $ty {}
+
+            impl std::hash::Hash for $ty {
+                fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                    self.id.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use identity_by_id;
+
+/// `as_str`, `From<String>`, `From<Enum> for String` и `Display` для
+/// перечисления, которое в API передается строкой.
+///
+/// Значения, неизвестные этой версии библиотеки, попадают в вариант-строку
+/// `$fallback`, а при сериализации возвращаются как есть.
+macro_rules! string_enum {
+    ($ty:ident, $fallback:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        impl $ty {
+            /// Значение в формате API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::$fallback(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $ty {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => Self::$variant,)*
+                    _ => Self::$fallback(value),
+                }
+            }
+        }
+
+        impl From<$ty> for String {
+            fn from(value: $ty) -> Self {
+                match value {
+                    $ty::$fallback(value) => value,
+                    value => value.as_str().to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+identity_by_id!(
+    Anime,
+    Manga,
+    Character,
+    CharacterFull,
+    Person,
+    PersonFull,
+    Genre,
+    Studio,
+    Publisher,
+    User,
+    UserRate,
+);
+
+utc_accessors!(ExternalLink { created_at_utc => created_at, updated_at_utc => updated_at });
+utc_accessors!(Anime {
+    created_at_utc => created_at,
+    updated_at_utc => updated_at,
+    next_episode_at_utc => next_episode_at,
+});
+utc_accessors!(Manga { created_at_utc => created_at, updated_at_utc => updated_at });
+utc_accessors!(CharacterFull { created_at_utc => created_at, updated_at_utc => updated_at });
+utc_accessors!(PersonFull { created_at_utc => created_at, updated_at_utc => updated_at });
+utc_accessors!(User { last_online_at_utc => last_online_at });
+utc_accessors!(UserRate { created_at_utc => created_at, updated_at_utc => updated_at });
+
+pub(crate) fn deser_id<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -42,7 +194,7 @@ where
     deserializer.deserialize_any(IdVisitor)
 }
 
-fn deser_opt_id<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+pub(crate) fn deser_opt_id<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -89,24 +241,6 @@ where
     deserializer.deserialize_option(OptionIdVisitor)
 }
 
-/// Дата с опциональными компонентами.
-///
-/// Используется для дат выхода аниме/манги, дат рождения людей и т.д.
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
-pub struct Date {
-    /// Год (например, 2024).
-    pub year: Option<i32>,
-
-    /// Месяц (1-12).
-    pub month: Option<i32>,
-
-    /// День месяца (1-31).
-    pub day: Option<i32>,
-
-    /// Полная дата в формате строки (ISO 8601 или другой формат API).
-    pub date: Option<String>,
-}
-
 /// Постер (изображение) для аниме, манги, персонажа или человека.
 ///
 /// Содержит ссылки на изображения разных размеров.
@@ -137,6 +271,30 @@ pub struct Poster {
     pub x48_url: Option<String>,
 }
 
+/// Абсолютные ссылки на изображения: относительные дополняются адресом
+/// сайта `base` (например, `"https://shikimori.io"`).
+impl Poster {
+    pub fn original_absolute(&self, base: &str) -> Option<String> {
+        self.original_url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    pub fn main_absolute(&self, base: &str) -> Option<String> {
+        self.main_url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    pub fn preview_absolute(&self, base: &str) -> Option<String> {
+        self.preview_url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    pub fn x96_absolute(&self, base: &str) -> Option<String> {
+        self.x96_url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    pub fn x48_absolute(&self, base: &str) -> Option<String> {
+        self.x48_url.as_deref().map(|url| absolute_url(url, base))
+    }
+}
+
 /// Жанр аниме или манги.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct Genre {
@@ -150,10 +308,33 @@ pub struct Genre {
     /// Название жанра на русском.
     pub russian: Option<String>,
 
-    /// Тип жанра (например, "anime", "manga").
+    /// Тип жанра: `"genre"`, `"theme"` или `"demographic"`.
     pub kind: Option<String>,
 }
 
+impl Genre {
+    /// Жанр в узком смысле (комедия, драма и т.д.).
+    ///
+    /// Записи без `kind` считаются жанрами.
+    pub fn is_genre(&self) -> bool {
+        matches!(self.kind.as_deref(), None | Some("genre"))
+    }
+
+    /// Тема (школа, музыка, исекай и т.д.).
+    pub fn is_theme(&self) -> bool {
+        self.kind.as_deref() == Some("theme")
+    }
+
+    /// Демография (сёнэн, сёдзё, сэйнэн, дзёсэй, детское).
+    pub fn is_demographic(&self) -> bool {
+        self.kind.as_deref() == Some("demographic")
+    }
+}
+
+fn filter_genres(genres: &Option<Vec<Genre>>, predicate: fn(&Genre) -> bool) -> Vec<&Genre> {
+    genres.iter().flatten().filter(|g| predicate(g)).collect()
+}
+
 /// Студия аниме.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct Studio {
@@ -180,11 +361,103 @@ pub struct Publisher {
     pub name: String,
 }
 
+/// Вид внешней ссылки.
+///
+/// Значения, неизвестные этой версии библиотеки, сохраняются в [`ExternalLinkKind::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(from = "String", into = "String")]
+#[ts(type = "string")]
+pub enum ExternalLinkKind {
+    /// Официальный сайт.
+    OfficialSite,
+    /// Wikipedia.
+    Wikipedia,
+    /// Anime News Network.
+    AnimeNewsNetwork,
+    /// MyAnimeList.
+    MyAnimeList,
+    /// AniDB.
+    AniDb,
+    /// World Art.
+    WorldArt,
+    /// Кинопоиск.
+    Kinopoisk,
+    /// Кинопоиск HD.
+    KinopoiskHd,
+    /// Kage Project.
+    KageProject,
+    /// Twitter (X).
+    Twitter,
+    /// Smotret Anime.
+    SmotretAnime,
+    /// Crunchyroll.
+    Crunchyroll,
+    /// Amazon Prime Video.
+    AmazonPrimeVideo,
+    /// HIDIVE.
+    Hidive,
+    /// Hulu.
+    Hulu,
+    /// Netflix.
+    Netflix,
+    /// Иви.
+    Ivi,
+    /// Okko.
+    Okko,
+    /// Wink.
+    Wink,
+    /// YouTube.
+    Youtube,
+    /// MangaUpdates.
+    MangaUpdates,
+    /// MangaDex.
+    MangaDex,
+    /// ReadManga.
+    ReadManga,
+    /// MangaLib.
+    MangaLib,
+    /// RanobeLib.
+    RanobeLib,
+    /// Novel Updates.
+    NovelUpdates,
+    /// Значение, неизвестное этой версии библиотеки.
+    Unknown(String),
+}
+
+string_enum!(ExternalLinkKind, Unknown {
+    OfficialSite => "official_site",
+    Wikipedia => "wikipedia",
+    AnimeNewsNetwork => "anime_news_network",
+    MyAnimeList => "myanimelist",
+    AniDb => "anime_db",
+    WorldArt => "world_art",
+    Kinopoisk => "kinopoisk",
+    KinopoiskHd => "kinopoisk_hd",
+    KageProject => "kage_project",
+    Twitter => "twitter",
+    SmotretAnime => "smotret_anime",
+    Crunchyroll => "crunchyroll",
+    AmazonPrimeVideo => "amazon_prime_video",
+    Hidive => "hidive",
+    Hulu => "hulu",
+    Netflix => "netflix",
+    Ivi => "ivi",
+    Okko => "okko",
+    Wink => "wink",
+    Youtube => "youtube",
+    MangaUpdates => "mangaupdates",
+    MangaDex => "mangadex",
+    ReadManga => "readmanga",
+    MangaLib => "mangalib",
+    RanobeLib => "ranobelib",
+    NovelUpdates => "novelupdates",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ExternalLink {
     #[serde(deserialize_with = "deser_opt_id")]
     pub id: Option<i64>,
-    pub kind: String,
+    pub kind: ExternalLinkKind,
     pub url: String,
     #[serde(rename = "createdAt")]
     pub created_at: Option<String>,
@@ -192,6 +465,22 @@ pub struct ExternalLink {
     pub updated_at: Option<String>,
 }
 
+/// Лучшая официальная ссылка из `links`: официальный сайт (с `https`, если есть
+/// несколько), иначе официальный аккаунт в Twitter.
+pub fn best_official_link(links: &[ExternalLink]) -> Option<&ExternalLink> {
+    let rank = |link: &ExternalLink| match link.kind {
+        ExternalLinkKind::OfficialSite if link.url.starts_with("https://") => Some(0),
+        ExternalLinkKind::OfficialSite => Some(1),
+        ExternalLinkKind::Twitter => Some(2),
+        _ => None,
+    };
+    links
+        .iter()
+        .filter_map(|link| Some((rank(link)?, link)))
+        .min_by_key(|&(rank, _)| rank)
+        .map(|(_, link)| link)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct Person {
     #[serde(deserialize_with = "deser_id")]
@@ -199,6 +488,19 @@ pub struct Person {
     pub name: String,
     pub russian: Option<String>,
     pub poster: Option<Poster>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Person {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), None, None)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -219,6 +521,19 @@ pub struct Character {
     pub name: String,
     pub russian: Option<String>,
     pub poster: Option<Poster>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Character {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), None, None)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -242,7 +557,7 @@ pub struct RelatedAnime {
     pub russian: Option<String>,
     pub poster: Option<Poster>,
     #[serde(rename = "airedOn")]
-    pub aired_on: Option<Date>,
+    pub aired_on: Option<PartialDate>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -253,7 +568,7 @@ pub struct RelatedManga {
     pub russian: Option<String>,
     pub poster: Option<Poster>,
     #[serde(rename = "airedOn")]
-    pub aired_on: Option<Date>,
+    pub aired_on: Option<PartialDate>,
 }
 
 /// Похожее аниме из REST API Shikimori (/api/animes/{id}/similar)
@@ -277,6 +592,62 @@ pub struct SimilarAnimeImage {
     pub x96: Option<String>,
 }
 
+/// Вид связи между тайтлами.
+///
+/// Значение API `"other"` и значения, неизвестные этой версии библиотеки,
+/// сохраняются в [`RelationKind::Other`] как есть.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(from = "String", into = "String")]
+#[ts(type = "\"adaptation\" | \"alternative_setting\" | \"alternative_version\" | \"character\" | \"full_story\" | \"parent_story\" | \"prequel\" | \"sequel\" | \"side_story\" | \"spin_off\" | \"summary\" | string")]
+pub enum RelationKind {
+    /// Адаптация.
+    Adaptation,
+    /// Альтернативная вселенная.
+    AlternativeSetting,
+    /// Альтернативная версия.
+    AlternativeVersion,
+    /// Общий персонаж.
+    Character,
+    /// Развернутая история.
+    FullStory,
+    /// Оригинальная история.
+    ParentStory,
+    /// Предыстория.
+    Prequel,
+    /// Продолжение.
+    Sequel,
+    /// Другая история.
+    SideStory,
+    /// Ответвление от оригинала.
+    SpinOff,
+    /// Обобщение.
+    Summary,
+    /// `"other"` или значение, неизвестное этой версии библиотеки.
+    Other(String),
+}
+
+string_enum!(RelationKind, Other {
+    Adaptation => "adaptation",
+    AlternativeSetting => "alternative_setting",
+    AlternativeVersion => "alternative_version",
+    Character => "character",
+    FullStory => "full_story",
+    ParentStory => "parent_story",
+    Prequel => "prequel",
+    Sequel => "sequel",
+    SideStory => "side_story",
+    SpinOff => "spin_off",
+    Summary => "summary",
+});
+
+impl RelationKind {
+    /// Связь по основной сюжетной линии (продолжение или предыстория),
+    /// важная для порядка просмотра.
+    pub fn is_main_story(&self) -> bool {
+        matches!(self, Self::Sequel | Self::Prequel)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct Related {
     #[serde(deserialize_with = "deser_id")]
@@ -284,7 +655,7 @@ pub struct Related {
     pub anime: Option<RelatedAnime>,
     pub manga: Option<RelatedManga>,
     #[serde(rename = "relationKind")]
-    pub relation_kind: String,
+    pub relation_kind: RelationKind,
     #[serde(rename = "relationText")]
     pub relation_text: Option<String>,
 }
@@ -320,12 +691,167 @@ pub struct ScoreStat {
     pub count: i32,
 }
 
+/// Статус тайтла в списке пользователя.
+///
+/// Используется в статистике тайтлов, в оценках пользователя ([`UserRate`]) и
+/// при их изменении ([`UserRateInput`]).
+///
+/// Значения, неизвестные этой версии библиотеки, сохраняются в [`WatchStatus::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(from = "String", into = "String")]
+#[ts(type = "\"planned\" | \"watching\" | \"rewatching\" | \"completed\" | \"on_hold\" | \"dropped\" | string")]
+pub enum WatchStatus {
+    Planned,
+    Watching,
+    Rewatching,
+    Completed,
+    OnHold,
+    Dropped,
+    /// Значение, неизвестное этой версии библиотеки.
+    Unknown(String),
+}
+
+string_enum!(WatchStatus, Unknown {
+    Planned => "planned",
+    Watching => "watching",
+    Rewatching => "rewatching",
+    Completed => "completed",
+    OnHold => "on_hold",
+    Dropped => "dropped",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct StatusStat {
-    pub status: String,
+    pub status: WatchStatus,
     pub count: i32,
 }
 
+/// Количество голосов в распределении оценок.
+pub(crate) fn score_votes(stats: &[ScoreStat]) -> i64 {
+    stats.iter().map(|s| s.count as i64).sum()
+}
+
+/// Средняя оценка, взвешенная по количеству голосов (`None`, если голосов нет).
+pub(crate) fn mean_score(stats: &[ScoreStat]) -> Option<f64> {
+    let votes = score_votes(stats);
+    if votes == 0 {
+        return None;
+    }
+    let sum: i64 = stats.iter().map(|s| s.score as i64 * s.count as i64).sum();
+    Some(sum as f64 / votes as f64)
+}
+
+/// Доля голосов за каждую оценку в процентах, по возрастанию оценки.
+fn score_distribution(stats: &[ScoreStat]) -> Vec<(i32, f64)> {
+    let votes = score_votes(stats);
+    let mut distribution: Vec<(i32, f64)> = stats
+        .iter()
+        .map(|s| {
+            let share = if votes == 0 { 0.0 } else { s.count as f64 * 100.0 / votes as f64 };
+            (s.score, share)
+        })
+        .collect();
+    distribution.sort_by_key(|&(score, _)| score);
+    distribution
+}
+
+/// Наименьшая оценка, до которой (включительно) набирается `percentile`
+/// процентов голосов.
+fn score_percentile(stats: &[ScoreStat], percentile: f64) -> Option<i32> {
+    let votes = score_votes(stats);
+    if votes == 0 {
+        return None;
+    }
+    let threshold = percentile.clamp(0.0, 100.0) * votes as f64 / 100.0;
+    let mut sorted: Vec<&ScoreStat> = stats.iter().filter(|s| s.count > 0).collect();
+    sorted.sort_by_key(|s| s.score);
+    let mut cumulative = 0;
+    for stat in &sorted {
+        cumulative += stat.count as i64;
+        if cumulative as f64 >= threshold {
+            return Some(stat.score);
+        }
+    }
+    sorted.last().map(|s| s.score)
+}
+
+/// Количество пользователей со статусом `status`; `None`, если статистика не загружена.
+fn status_count(stats: &Option<Vec<StatusStat>>, status: WatchStatus) -> Option<i32> {
+    stats
+        .as_ref()
+        .map(|stats| stats.iter().filter(|s| s.status == status).map(|s| s.count).sum())
+}
+
+/// Статус выхода аниме.
+///
+/// Значения, неизвестные этой версии библиотеки, сохраняются в [`AnimeStatus::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(from = "String", into = "String")]
+#[ts(type = "\"anons\" | \"ongoing\" | \"released\" | string")]
+pub enum AnimeStatus {
+    /// Анонсировано.
+    Anons,
+    /// Выходит.
+    Ongoing,
+    /// Вышло.
+    Released,
+    /// Значение, неизвестное этой версии библиотеки.
+    Unknown(String),
+}
+
+string_enum!(AnimeStatus, Unknown {
+    Anons => "anons",
+    Ongoing => "ongoing",
+    Released => "released",
+});
+
+/// Возрастной рейтинг аниме.
+///
+/// Значения, неизвестные этой версии библиотеки, сохраняются в [`AgeRating::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(from = "String", into = "String")]
+#[ts(type = "\"none\" | \"g\" | \"pg\" | \"pg_13\" | \"r\" | \"r_plus\" | \"rx\" | string")]
+pub enum AgeRating {
+    /// Рейтинг не указан (`"none"`).
+    Unrated,
+    /// G — нет возрастных ограничений.
+    G,
+    /// PG — рекомендуется присутствие родителей.
+    Pg,
+    /// PG-13 — детям до 13 лет просмотр не желателен.
+    Pg13,
+    /// R-17 — лицам до 17 лет обязательно присутствие взрослого.
+    R,
+    /// R+ — лицам до 17 лет просмотр запрещен.
+    RPlus,
+    /// Rx — хентай.
+    Rx,
+    /// Значение, неизвестное этой версии библиотеки.
+    Unknown(String),
+}
+
+string_enum!(AgeRating, Unknown {
+    Unrated => "none",
+    G => "g",
+    Pg => "pg",
+    Pg13 => "pg_13",
+    R => "r",
+    RPlus => "r_plus",
+    Rx => "rx",
+});
+
+impl AgeRating {
+    /// Контент только для взрослых: R+ и Rx.
+    pub fn is_adult(&self) -> bool {
+        matches!(self, Self::RPlus | Self::Rx)
+    }
+
+    /// Откровенный контент (Rx).
+    pub fn is_explicit(&self) -> bool {
+        matches!(self, Self::Rx)
+    }
+}
+
 /// Полная информация об аниме.
 ///
 /// Содержит все доступные данные об аниме: названия, оценки, студии, жанры,
@@ -338,12 +864,7 @@ pub struct StatusStat {
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = ShikicrateClient::new()?;
-/// let animes = client.animes(AnimeSearchParams {
-///     search: Some("naruto".to_string()),
-///     limit: Some(1),
-///     kind: None,
-///     page: None,
-/// }).await?;
+/// let animes = client.animes(AnimeSearchParams::builder().search("naruto").limit(1)).await?;
 ///
 /// if let Some(anime) = animes.first() {
 ///     println!("Название: {}", anime.name);
@@ -389,14 +910,14 @@ pub struct Anime {
     /// Тип аниме: `"tv"`, `"movie"`, `"ova"`, `"ona"`, `"special"`, `"music"`.
     pub kind: Option<String>,
 
-    /// Возрастной рейтинг: `"g"`, `"pg"`, `"pg_13"`, `"r"`, `"r_plus"`, `"rx"`.
-    pub rating: Option<String>,
+    /// Возрастной рейтинг.
+    pub rating: Option<AgeRating>,
 
     /// Средняя оценка пользователей (0.0 - 10.0).
     pub score: Option<f64>,
 
-    /// Статус: `"anons"`, `"ongoing"`, `"released"`.
-    pub status: Option<String>,
+    /// Статус выхода.
+    pub status: Option<AnimeStatus>,
 
     /// Общее количество эпизодов (планируемое).
     pub episodes: Option<i32>,
@@ -410,11 +931,11 @@ pub struct Anime {
 
     /// Дата начала показа.
     #[serde(rename = "airedOn")]
-    pub aired_on: Option<Date>,
+    pub aired_on: Option<PartialDate>,
 
     /// Дата релиза.
     #[serde(rename = "releasedOn")]
-    pub released_on: Option<Date>,
+    pub released_on: Option<PartialDate>,
 
     /// URL страницы аниме на Shikimori.
     pub url: Option<String>,
@@ -495,6 +1016,94 @@ pub struct Anime {
     /// Источник описания.
     #[serde(rename = "descriptionSource")]
     pub description_source: Option<String>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Anime {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), self.english.as_deref(), self.japanese.as_deref())
+    }
+
+    /// Абсолютный URL страницы на сайте `base` (например, `"https://shikimori.io"`).
+    pub fn url_absolute(&self, base: &str) -> Option<String> {
+        self.url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    /// Описание без разметки: из `description`, а если его нет — из `description_html`.
+    pub fn plain_description(&self) -> Option<String> {
+        description::plain_description(self.description.as_deref(), self.description_html.as_deref())
+    }
+
+    /// Описание как безопасный HTML со ссылками на страницы `base_url`
+    /// (см. [`description::to_html`]).
+    pub fn description_to_html(&self, base_url: &str) -> Option<String> {
+        self.description.as_deref().map(|text| description::to_html(text, base_url))
+    }
+
+    /// Лучшая официальная ссылка из `external_links` (см. [`best_official_link`]).
+    pub fn official_link(&self) -> Option<&ExternalLink> {
+        best_official_link(self.external_links.as_deref()?)
+    }
+
+    /// Количество голосов по `scores_stats` (`None`, если статистика не была запрошена).
+    pub fn score_votes(&self) -> Option<i64> {
+        self.scores_stats.as_deref().map(score_votes)
+    }
+
+    /// Средняя пользовательская оценка по `scores_stats`, взвешенная по голосам.
+    ///
+    /// Отличается от `score`, который Shikimori считает со своими поправками.
+    pub fn mean_user_score(&self) -> Option<f64> {
+        mean_score(self.scores_stats.as_deref()?)
+    }
+
+    /// Доля голосов за каждую оценку в процентах, по возрастанию оценки.
+    pub fn score_distribution(&self) -> Option<Vec<(i32, f64)>> {
+        self.scores_stats.as_deref().map(score_distribution)
+    }
+
+    /// Оценка на процентиле `percentile` (0-100): например, `score_percentile(50.0)` — медиана.
+    pub fn score_percentile(&self, percentile: f64) -> Option<i32> {
+        score_percentile(self.scores_stats.as_deref()?, percentile)
+    }
+
+    /// Количество пользователей с указанным статусом по `statuses_stats`.
+    ///
+    /// `None`, если статистика статусов не была запрошена.
+    pub fn status_count(&self, status: WatchStatus) -> Option<i32> {
+        status_count(&self.statuses_stats, status)
+    }
+
+    /// Сколько пользователей сейчас смотрят тайтл.
+    pub fn watchers_count(&self) -> Option<i32> {
+        self.status_count(WatchStatus::Watching)
+    }
+
+    /// Сколько пользователей бросили тайтл.
+    pub fn dropped_count(&self) -> Option<i32> {
+        self.status_count(WatchStatus::Dropped)
+    }
+
+    /// Жанры без тем и демографий.
+    pub fn genres_only(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_genre)
+    }
+
+    /// Темы из списка жанров.
+    pub fn themes(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_theme)
+    }
+
+    /// Демографии из списка жанров.
+    pub fn demographics(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_demographic)
+    }
 }
 
 /// Полная информация о манге.
@@ -550,11 +1159,11 @@ pub struct Manga {
 
     /// Дата начала публикации.
     #[serde(rename = "airedOn")]
-    pub aired_on: Option<Date>,
+    pub aired_on: Option<PartialDate>,
 
     /// Дата релиза.
     #[serde(rename = "releasedOn")]
-    pub released_on: Option<Date>,
+    pub released_on: Option<PartialDate>,
 
     /// URL страницы манги на Shikimori.
     pub url: Option<String>,
@@ -616,6 +1225,94 @@ pub struct Manga {
     /// Источник описания.
     #[serde(rename = "descriptionSource")]
     pub description_source: Option<String>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Manga {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), self.english.as_deref(), self.japanese.as_deref())
+    }
+
+    /// Абсолютный URL страницы на сайте `base` (например, `"https://shikimori.io"`).
+    pub fn url_absolute(&self, base: &str) -> Option<String> {
+        self.url.as_deref().map(|url| absolute_url(url, base))
+    }
+
+    /// Описание без разметки: из `description`, а если его нет — из `description_html`.
+    pub fn plain_description(&self) -> Option<String> {
+        description::plain_description(self.description.as_deref(), self.description_html.as_deref())
+    }
+
+    /// Описание как безопасный HTML со ссылками на страницы `base_url`
+    /// (см. [`description::to_html`]).
+    pub fn description_to_html(&self, base_url: &str) -> Option<String> {
+        self.description.as_deref().map(|text| description::to_html(text, base_url))
+    }
+
+    /// Лучшая официальная ссылка из `external_links` (см. [`best_official_link`]).
+    pub fn official_link(&self) -> Option<&ExternalLink> {
+        best_official_link(self.external_links.as_deref()?)
+    }
+
+    /// Количество голосов по `scores_stats` (`None`, если статистика не была запрошена).
+    pub fn score_votes(&self) -> Option<i64> {
+        self.scores_stats.as_deref().map(score_votes)
+    }
+
+    /// Средняя пользовательская оценка по `scores_stats`, взвешенная по голосам.
+    ///
+    /// Отличается от `score`, который Shikimori считает со своими поправками.
+    pub fn mean_user_score(&self) -> Option<f64> {
+        mean_score(self.scores_stats.as_deref()?)
+    }
+
+    /// Доля голосов за каждую оценку в процентах, по возрастанию оценки.
+    pub fn score_distribution(&self) -> Option<Vec<(i32, f64)>> {
+        self.scores_stats.as_deref().map(score_distribution)
+    }
+
+    /// Оценка на процентиле `percentile` (0-100): например, `score_percentile(50.0)` — медиана.
+    pub fn score_percentile(&self, percentile: f64) -> Option<i32> {
+        score_percentile(self.scores_stats.as_deref()?, percentile)
+    }
+
+    /// Количество пользователей с указанным статусом по `statuses_stats`.
+    ///
+    /// `None`, если статистика статусов не была запрошена.
+    pub fn status_count(&self, status: WatchStatus) -> Option<i32> {
+        status_count(&self.statuses_stats, status)
+    }
+
+    /// Сколько пользователей сейчас читают тайтл.
+    pub fn readers_count(&self) -> Option<i32> {
+        self.status_count(WatchStatus::Watching)
+    }
+
+    /// Сколько пользователей бросили тайтл.
+    pub fn dropped_count(&self) -> Option<i32> {
+        self.status_count(WatchStatus::Dropped)
+    }
+
+    /// Жанры без тем и демографий.
+    pub fn genres_only(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_genre)
+    }
+
+    /// Темы из списка жанров.
+    pub fn themes(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_theme)
+    }
+
+    /// Демографии из списка жанров.
+    pub fn demographics(&self) -> Vec<&Genre> {
+        filter_genres(&self.genres, Genre::is_demographic)
+    }
 }
 
 /// Полная информация о персонаже.
@@ -680,6 +1377,42 @@ pub struct CharacterFull {
     /// Источник описания.
     #[serde(rename = "descriptionSource")]
     pub description_source: Option<String>,
+
+    /// Сейю, озвучивавшие персонажа.
+    #[serde(default)]
+    pub seyu: Option<Vec<Person>>,
+
+    /// Аниме, в которых появляется персонаж.
+    #[serde(default)]
+    pub animes: Option<Vec<Anime>>,
+
+    /// Манга, в которой появляется персонаж.
+    #[serde(default)]
+    pub mangas: Option<Vec<Manga>>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CharacterFull {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), None, self.japanese.as_deref())
+    }
+
+    /// Описание без разметки: из `description`, а если его нет — из `description_html`.
+    pub fn plain_description(&self) -> Option<String> {
+        description::plain_description(self.description.as_deref(), self.description_html.as_deref())
+    }
+
+    /// Описание как безопасный HTML со ссылками на страницы `base_url`
+    /// (см. [`description::to_html`]).
+    pub fn description_to_html(&self, base_url: &str) -> Option<String> {
+        self.description.as_deref().map(|text| description::to_html(text, base_url))
+    }
 }
 
 /// Полная информация о человеке (сейю, мангака, продюсер и т.д.).
@@ -736,14 +1469,160 @@ pub struct PersonFull {
 
     /// Дата рождения.
     #[serde(rename = "birthOn")]
-    pub birth_on: Option<Date>,
+    pub birth_on: Option<PartialDate>,
 
     /// Дата смерти (если есть).
     #[serde(rename = "deceasedOn")]
-    pub deceased_on: Option<Date>,
+    pub deceased_on: Option<PartialDate>,
 
     /// Постер человека.
     pub poster: Option<Poster>,
+
+    /// Работы над тайтлами: аниме или манга и название роли.
+    #[serde(default)]
+    pub works: Option<Vec<PersonWork>>,
+
+    /// Озвученные персонажи и аниме, в которых они появляются.
+    #[serde(default)]
+    pub roles: Option<Vec<PersonCharacterRoles>>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    #[ts(skip)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PersonFull {
+    /// Название на первом доступном языке из `preference` (см. [`crate::title`]).
+    pub fn title(&self, preference: &TitlePreference) -> &str {
+        preference.resolve(&self.name, self.russian.as_deref(), None, self.japanese.as_deref())
+    }
+}
+
+/// Результат `search_all()`: аниме, манга, персонажи и люди, найденные одним запросом.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+pub struct SearchAllResult {
+    #[serde(rename = "foundAnimes", default)]
+    pub animes: Vec<Anime>,
+    #[serde(rename = "foundMangas", default)]
+    pub mangas: Vec<Manga>,
+    #[serde(rename = "foundCharacters", default)]
+    pub characters: Vec<Character>,
+    #[serde(rename = "foundPeople", default)]
+    pub people: Vec<Person>,
+}
+
+impl SearchAllResult {
+    /// Общее количество найденных записей.
+    pub fn len(&self) -> usize {
+        self.animes.len() + self.mangas.len() + self.characters.len() + self.people.len()
+    }
+
+    /// Ничего не найдено.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Краткая ссылка на аниме, мангу или персонажа в ответах REST API.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct EntryRef {
+    /// ID записи в системе Shikimori.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// Основное название или имя.
+    pub name: String,
+
+    /// Русское название или имя (если есть).
+    pub russian: Option<String>,
+
+    /// Тип тайтла (`tv`, `manga` и т.д.; для персонажей отсутствует).
+    pub kind: Option<String>,
+
+    /// Относительный URL страницы на Shikimori.
+    pub url: Option<String>,
+}
+
+/// Работа человека над тайтлом: аниме или манга и название роли.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct PersonWork {
+    /// Аниме (если работа относится к аниме).
+    pub anime: Option<EntryRef>,
+
+    /// Манга (если работа относится к манге).
+    pub manga: Option<EntryRef>,
+
+    /// Название роли (например, `"Original Creator"`).
+    pub role: Option<String>,
+}
+
+/// Роль сейю: озвученные персонажи и аниме, в которых они появляются.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct PersonCharacterRoles {
+    /// Озвученные персонажи.
+    #[serde(default)]
+    pub characters: Vec<EntryRef>,
+
+    /// Аниме с этими персонажами.
+    #[serde(default)]
+    pub animes: Vec<EntryRef>,
+}
+
+/// Пользователь Shikimori.
+///
+/// Результаты поиска `users()` содержат только основные поля. Поля профиля
+/// (`last_online`, `common_info`, `stats` и т.д.) заполняются методами
+/// `user_by_id()` и `user_by_nickname()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    /// ID пользователя в системе Shikimori.
+    #[serde(deserialize_with = "deser_id")]
+    pub id: i64,
+
+    /// Никнейм пользователя.
+    pub nickname: String,
+
+    /// URL аватара.
+    #[serde(rename = "avatarUrl", alias = "avatar")]
+    pub avatar_url: Option<String>,
+
+    /// Время последнего посещения сайта.
+    #[serde(rename = "lastOnlineAt", alias = "last_online_at")]
+    pub last_online_at: Option<String>,
+
+    /// URL профиля на Shikimori.
+    pub url: Option<String>,
+
+    /// Имя пользователя (если указано в профиле).
+    pub name: Option<String>,
+
+    /// Пол пользователя.
+    pub sex: Option<String>,
+
+    /// Полных лет (если указана дата рождения).
+    pub full_years: Option<i32>,
+
+    /// Сайт пользователя.
+    pub website: Option<String>,
+
+    /// Локаль интерфейса (например, `"ru"`).
+    pub locale: Option<String>,
+
+    /// Последнее посещение в человекочитаемом виде (например, `"онлайн 5 минут назад"`).
+    pub last_online: Option<String>,
+
+    /// Общая информация профиля (строки из шапки профиля).
+    pub common_info: Option<Vec<String>>,
+
+    /// Статистика списков пользователя.
+    pub stats: Option<UserStats>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Пользовательская оценка аниме или манги.
@@ -759,7 +1638,7 @@ pub struct UserRate {
     pub score: Option<f64>,
 
     /// Статус просмотра.
-    pub status: String,
+    pub status: WatchStatus,
 
     /// Количество просмотренных эпизодов (для аниме).
     pub episodes: Option<i32>,
@@ -770,13 +1649,360 @@ pub struct UserRate {
     /// Количество прочитанных глав (для манги).
     pub chapters: Option<i32>,
 
+    /// Количество повторных просмотров/прочтений.
+    pub rewatches: Option<i32>,
+
+    /// Комментарий пользователя к записи.
+    pub text: Option<String>,
+
     /// Аниме (если оценка относится к аниме).
     pub anime: Option<Anime>,
 
     /// Манга (если оценка относится к манге).
     pub manga: Option<Manga>,
 
+    /// ID тайтла (заполняется в ответах REST API).
+    #[serde(rename = "targetId", alias = "target_id", default, deserialize_with = "deser_opt_id")]
+    pub target_id: Option<i64>,
+
+    /// Тип тайтла: `"Anime"` или `"Manga"` (заполняется в ответах REST API).
+    #[serde(rename = "targetType", alias = "target_type")]
+    pub target_type: Option<String>,
+
     /// Дата создания оценки.
-    #[serde(rename = "createdAt")]
+    #[serde(rename = "createdAt", alias = "created_at")]
     pub created_at: Option<String>,
+
+    /// Дата последнего изменения оценки.
+    #[serde(rename = "updatedAt", alias = "updated_at")]
+    pub updated_at: Option<String>,
+
+    /// Поля ответа, которых еще нет в структуре (feature `extra-fields`).
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Данные для создания или обновления пользовательской оценки.
+///
+/// Поля со значением `None` не отправляются на сервер.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserRateInput {
+    /// ID аниме или манги.
+    pub target_id: i64,
+
+    /// Тип тайтла: `"Anime"` или `"Manga"`.
+    pub target_type: String,
+
+    /// Статус записи.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<WatchStatus>,
+
+    /// Оценка (0-10, 0 — без оценки).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+
+    /// Количество просмотренных эпизодов.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episodes: Option<i32>,
+
+    /// Количество прочитанных томов.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<i32>,
+
+    /// Количество прочитанных глав.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapters: Option<i32>,
+
+    /// Количество повторных просмотров/прочтений.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewatches: Option<i32>,
+
+    /// Комментарий к записи.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl UserRateInput {
+    /// Создает пустое изменение для тайтла.
+    pub fn new(target_id: i64, target_type: &str) -> Self {
+        Self {
+            target_id,
+            target_type: target_type.to_string(),
+            status: None,
+            score: None,
+            episodes: None,
+            volumes: None,
+            chapters: None,
+            rewatches: None,
+            text: None,
+        }
+    }
+
+    /// Проверяет, что все заданные поля совпадают с состоянием оценки на сервере.
+    pub fn is_applied_to(&self, rate: &UserRate) -> bool {
+        fn same<T: PartialEq>(wanted: &Option<T>, actual: Option<T>) -> bool {
+            wanted.as_ref().is_none_or(|w| Some(w) == actual.as_ref())
+        }
+
+        same(&self.status, Some(rate.status.clone()))
+            && same(&self.score, rate.score.map(|s| s as i32))
+            && same(&self.episodes, rate.episodes)
+            && same(&self.volumes, rate.volumes)
+            && same(&self.chapters, rate.chapters)
+            && same(&self.rewatches, rate.rewatches)
+            && same(&self.text, rate.text.clone())
+    }
+}
+
+/// Подтверждение удаления пользовательской оценки.
+///
+/// Возвращается методом `delete_user_rate()` после успешного запроса.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRateDeleted {
+    /// ID удаленной оценки.
+    pub id: i64,
+}
+
+/// Значение статистики пользователя: название категории и количество.
+///
+/// Используется для распределения оценок, типов, рейтингов и жанров.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserStatValue {
+    /// Название категории (оценка, тип, рейтинг, жанр).
+    pub name: String,
+
+    /// Количество записей в категории.
+    pub value: i64,
+}
+
+/// Количество записей пользователя с определенным статусом.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserStatusCount {
+    /// ID статуса в системе Shikimori.
+    #[serde(default, deserialize_with = "deser_opt_id")]
+    pub id: Option<i64>,
+
+    /// Сгруппированный статус (например, `"planned"`, `"watching"`).
+    pub grouped_id: Option<String>,
+
+    /// Название статуса.
+    pub name: String,
+
+    /// Количество записей.
+    pub size: i64,
+
+    /// Тип записей: `"Anime"` или `"Manga"`.
+    #[serde(rename = "type")]
+    pub target_type: Option<String>,
+}
+
+/// Статистика, разделенная на аниме и мангу.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct UserStatsByTarget<T> {
+    /// Статистика по аниме.
+    #[serde(default)]
+    pub anime: Vec<T>,
+
+    /// Статистика по манге.
+    #[serde(default)]
+    pub manga: Vec<T>,
+}
+
+impl<T> Default for UserStatsByTarget<T> {
+    fn default() -> Self {
+        Self {
+            anime: Vec::new(),
+            manga: Vec::new(),
+        }
+    }
+}
+
+/// Статистика профиля пользователя из REST API Shikimori (`/api/users/{id}`).
+///
+/// Содержит распределения по статусам, оценкам, типам, рейтингам и жанрам.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserStats {
+    /// Количество записей по статусам (сгруппированные).
+    #[serde(default)]
+    pub statuses: UserStatsByTarget<UserStatusCount>,
+
+    /// Количество записей по статусам (полный список, включая пересмотр).
+    #[serde(default)]
+    pub full_statuses: UserStatsByTarget<UserStatusCount>,
+
+    /// Распределение оценок пользователя.
+    #[serde(default)]
+    pub scores: UserStatsByTarget<UserStatValue>,
+
+    /// Распределение по типам (TV, Movie, Manga и т.д.).
+    #[serde(default)]
+    pub types: UserStatsByTarget<UserStatValue>,
+
+    /// Распределение по возрастным рейтингам.
+    #[serde(default)]
+    pub ratings: UserStatsByTarget<UserStatValue>,
+
+    /// Распределение по жанрам.
+    #[serde(default)]
+    pub genres: Vec<UserStatValue>,
+
+    /// Есть ли у пользователя аниме в списке.
+    #[serde(rename = "has_anime?", default)]
+    pub has_anime: bool,
+
+    /// Есть ли у пользователя манга в списке.
+    #[serde(rename = "has_manga?", default)]
+    pub has_manga: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn anime_with(extra: serde_json::Value) -> Anime {
+        let mut value = json!({ "id": "1", "name": "Cowboy Bebop" });
+        value.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_timestamps_stay_strings() {
+        let anime: Anime = serde_json::from_value(json!({
+            "id": "1",
+            "name": "Cowboy Bebop",
+            "createdAt": "2024-02-03T17:30:00.000+03:00",
+            "nextEpisodeAt": "not a date"
+        }))
+        .unwrap();
+        assert_eq!(anime.created_at.as_deref(), Some("2024-02-03T17:30:00.000+03:00"));
+
+        #[cfg(feature = "chrono")]
+        {
+            let created_at = anime.created_at_utc().unwrap();
+            assert_eq!(created_at.to_rfc3339(), "2024-02-03T14:30:00+00:00");
+            assert_eq!(anime.updated_at_utc(), None);
+            assert_eq!(anime.next_episode_at_utc(), None);
+        }
+    }
+
+    #[test]
+    fn test_anime_status() {
+        let statuses: Vec<AnimeStatus> = serde_json::from_value(json!(["anons", "ongoing", "released", "paused"])).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                AnimeStatus::Anons,
+                AnimeStatus::Ongoing,
+                AnimeStatus::Released,
+                AnimeStatus::Unknown("paused".to_string()),
+            ]
+        );
+        assert_eq!(serde_json::to_value(&statuses).unwrap(), json!(["anons", "ongoing", "released", "paused"]));
+        assert_eq!(AnimeStatus::Ongoing.to_string(), "ongoing");
+
+        let anime = anime_with(json!({ "status": "released" }));
+        assert_eq!(anime.status, Some(AnimeStatus::Released));
+    }
+
+    #[test]
+    fn test_watch_status() {
+        let statuses: Vec<WatchStatus> = serde_json::from_value(json!(["on_hold", "rewatching", "paused"])).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                WatchStatus::OnHold,
+                WatchStatus::Rewatching,
+                WatchStatus::Unknown("paused".to_string()),
+            ]
+        );
+        assert_eq!(serde_json::to_value(&statuses).unwrap(), json!(["on_hold", "rewatching", "paused"]));
+        assert_eq!(WatchStatus::Planned.as_str(), "planned");
+    }
+
+    #[test]
+    fn test_age_rating() {
+        let ratings: Vec<AgeRating> = serde_json::from_value(json!(["none", "pg_13", "r_plus", "rx", "nc_17"])).unwrap();
+        assert_eq!(ratings[0], AgeRating::Unrated);
+        assert_eq!(ratings[1], AgeRating::Pg13);
+        assert_eq!(ratings[4], AgeRating::Unknown("nc_17".to_string()));
+        assert_eq!(serde_json::to_value(&ratings).unwrap(), json!(["none", "pg_13", "r_plus", "rx", "nc_17"]));
+
+        let adult: Vec<bool> = ratings.iter().map(AgeRating::is_adult).collect();
+        assert_eq!(adult, vec![false, false, true, true, false]);
+        let explicit: Vec<bool> = ratings.iter().map(AgeRating::is_explicit).collect();
+        assert_eq!(explicit, vec![false, false, false, true, false]);
+    }
+
+    #[test]
+    fn test_score_stats() {
+        assert_eq!(anime_with(json!({})).score_votes(), None);
+
+        let anime = anime_with(json!({
+            "scoresStats": [
+                { "score": 10, "count": 6 },
+                { "score": 8, "count": 3 },
+                { "score": 5, "count": 1 }
+            ]
+        }));
+        assert_eq!(anime.score_votes(), Some(10));
+        assert_eq!(anime.mean_user_score(), Some(8.9));
+        assert_eq!(anime.score_distribution().unwrap(), vec![(5, 10.0), (8, 30.0), (10, 60.0)]);
+        assert_eq!(anime.score_percentile(50.0), Some(10));
+        assert_eq!(anime.score_percentile(40.0), Some(8));
+        assert_eq!(anime.score_percentile(0.0), Some(5));
+
+        assert_eq!(anime_with(json!({ "scoresStats": [] })).mean_user_score(), None);
+    }
+
+    #[test]
+    fn test_relation_kind() {
+        let related: Vec<Related> = serde_json::from_value(json!([
+            { "id": "1", "relationKind": "sequel", "anime": null, "manga": null },
+            { "id": "2", "relationKind": "other", "anime": null, "manga": null }
+        ]))
+        .unwrap();
+        assert_eq!(related[0].relation_kind, RelationKind::Sequel);
+        assert!(related[0].relation_kind.is_main_story());
+        assert_eq!(related[1].relation_kind, RelationKind::Other("other".to_string()));
+        assert_eq!(serde_json::to_value(&related[1]).unwrap()["relationKind"], "other");
+    }
+
+    #[test]
+    fn test_official_link() {
+        assert!(anime_with(json!({})).official_link().is_none());
+
+        let anime = anime_with(json!({
+            "externalLinks": [
+                { "id": "1", "kind": "wikipedia", "url": "https://en.wikipedia.org/wiki/Cowboy_Bebop" },
+                { "id": "2", "kind": "twitter", "url": "https://twitter.com/bebop" },
+                { "id": "3", "kind": "official_site", "url": "http://www.cowboybebop.org" },
+                { "id": "4", "kind": "some_new_site", "url": "https://example.com" }
+            ]
+        }));
+        let links = anime.external_links.as_ref().unwrap();
+        assert_eq!(links[0].kind, ExternalLinkKind::Wikipedia);
+        assert_eq!(links[3].kind, ExternalLinkKind::Unknown("some_new_site".to_string()));
+        assert_eq!(anime.official_link().unwrap().url, "http://www.cowboybebop.org");
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn test_extra_fields() {
+        let anime = anime_with(json!({ "newField": { "a": 1 } }));
+        assert_eq!(anime.extra["newField"]["a"], 1);
+        assert!(!anime.extra.contains_key("name"));
+        assert_eq!(serde_json::to_value(&anime).unwrap()["newField"]["a"], 1);
+    }
+
+    #[test]
+    fn test_identity_by_id() {
+        let renamed = anime_with(json!({ "name": "Kaubōi Bibappu" }));
+        assert_eq!(renamed, anime_with(json!({})));
+
+        let unique: std::collections::HashSet<Anime> = [anime_with(json!({})), renamed, anime_with(json!({}))].into();
+        assert_eq!(unique.len(), 1);
+    }
 }
@@ -0,0 +1,99 @@
+//! Абсолютные URL.
+//!
+//! Shikimori иногда возвращает ссылки относительно корня сайта
+//! (`/system/animes/original/1.jpg`, `/animes/1-cowboy-bebop`) или без схемы
+//! (`//shikimori.io/...`). [`absolute_url`] дополняет такие ссылки адресом сайта,
+//! а у [`Poster`](crate::types::Poster), [`Anime`](crate::types::Anime) и
+//! [`Manga`](crate::types::Manga) есть методы `*_absolute(base)`.
+//!
+//! Клиент может сам приводить все ссылки в ответах к абсолютным относительно
+//! текущего хоста — см. `ShikicrateClientBuilder::absolute_urls`.
+//!
+//! # Примеры
+//!
+//! ```
+//! use shikicrate::urls::absolute_url;
+//!
+//! let base = "https://shikimori.io";
+//! assert_eq!(absolute_url("/animes/1", base), "https://shikimori.io/animes/1");
+//! assert_eq!(absolute_url("//shikimori.io/animes/1", base), "https://shikimori.io/animes/1");
+//! assert_eq!(absolute_url("https://example.com/1.jpg", base), "https://example.com/1.jpg");
+//! ```
+
+use serde_json::Value;
+
+/// Ссылка `url`, дополненная адресом сайта `base` (`https://shikimori.io`),
+/// если она относительная. Абсолютные ссылки возвращаются без изменений.
+pub fn absolute_url(url: &str, base: &str) -> String {
+    if url.starts_with("//") {
+        let scheme = base.split_once("://").map_or("https", |(scheme, _)| scheme);
+        format!("{}:{}", scheme, url)
+    } else if url.starts_with('/') {
+        format!("{}{}", base.trim_end_matches('/'), url)
+    } else {
+        url.to_string()
+    }
+}
+
+/// Поле ответа со ссылкой: `url`, `originalUrl`, `image_url` и т.д.
+fn is_url_field(key: &str) -> bool {
+    key == "url" || key.ends_with("Url") || key.ends_with("_url")
+}
+
+/// Приводит к абсолютным ссылки во всех URL полях JSON ответа.
+pub(crate) fn absolutize(value: &mut Value, base: &str) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(url) if is_url_field(key) => *url = absolute_url(url, base),
+                    _ => absolutize(value, base),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| absolutize(item, base)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_absolutize() {
+        let mut data = json!({
+            "animes": [{
+                "url": "/animes/1",
+                "name": "/not/a/url",
+                "poster": { "originalUrl": "/system/1.jpg", "mainUrl": "https://cdn.example/1.jpg" },
+                "image": { "x96_url": "//shikimori.one/1.jpg" }
+            }]
+        });
+        absolutize(&mut data, "https://shikimori.one/");
+
+        let anime = &data["animes"][0];
+        assert_eq!(anime["url"], "https://shikimori.one/animes/1");
+        assert_eq!(anime["name"], "/not/a/url");
+        assert_eq!(anime["poster"]["originalUrl"], "https://shikimori.one/system/1.jpg");
+        assert_eq!(anime["poster"]["mainUrl"], "https://cdn.example/1.jpg");
+        assert_eq!(anime["image"]["x96_url"], "https://shikimori.one/1.jpg");
+    }
+
+    #[tokio::test]
+    async fn test_client_absolute_urls() {
+        let transport = crate::transport::MockTransport::new().graphql(
+            "SearchAnimes",
+            json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop", "url": "/animes/1" }] }),
+        );
+        let client = crate::transport::mock_builder(&transport)
+            .mirrors(vec!["https://shikimori.one".to_string()])
+            .absolute_urls(true)
+            .build()
+            .unwrap();
+
+        let animes = client.animes(Default::default()).await.unwrap();
+        assert_eq!(animes[0].url.as_deref(), Some("https://shikimori.one/animes/1"));
+    }
+}
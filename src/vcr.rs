@@ -0,0 +1,325 @@
+//! Запись и воспроизведение HTTP ответов (кассеты в стиле VCR).
+//!
+//! [`Cassette`] — middleware с двумя режимами:
+//!
+//! - [`Cassette::record`] отправляет запросы в сеть и сохраняет каждый обмен
+//!   в JSON файл кассеты;
+//! - [`Cassette::replay`] отвечает на запросы из кассеты без обращения к сети.
+//!
+//! Запросы сопоставляются по методу, пути с параметрами и JSON телу, поэтому
+//! кассета, записанная с одного зеркала, воспроизводится и с другого. Заголовки
+//! запроса в кассету не попадают, а токены и пароли в теле и параметрах URL
+//! маскируются — кассеты можно хранить в репозитории.
+//!
+//! Кассета должна быть последним обработчиком цепочки, чтобы остальные
+//! middleware работали и при воспроизведении.
+//!
+//! # Примеры
+//!
+//! ```no_run
+//! use shikicrate::{ShikicrateClientBuilder, queries::*};
+//! use shikicrate::vcr::Cassette;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Один раз записать реальные ответы:
+//! // let cassette = Cassette::record("tests/cassettes/animes.json");
+//! let cassette = Cassette::replay("tests/cassettes/animes.json")?;
+//!
+//! let client = ShikicrateClientBuilder::new()
+//!     .middleware(cassette)
+//!     .build()?;
+//!
+//! let animes = client.animes(AnimeSearchParams::default()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Constraint, Result, ShikicrateError, ValidationError};
+use crate::logging::{is_secret, redact};
+use crate::middleware::{Middleware, Next};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Записанный запрос (без заголовков, секреты замаскированы).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteRequest {
+    pub method: String,
+
+    /// Путь с параметрами запроса, без адреса сайта (`/api/graphql`).
+    pub path: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// Записанный ответ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CassetteResponse {
+    pub status: u16,
+
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// Тело ответа: JSON как есть, остальное — строкой.
+    pub body: Value,
+}
+
+/// Один обмен запрос-ответ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: CassetteRequest,
+    pub response: CassetteResponse,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// Заголовки ответа, которые сохраняются в кассету.
+const KEPT_HEADERS: [&str; 3] = ["content-type", "retry-after", "x-total-count"];
+
+enum Mode {
+    Record,
+    Replay { used: Vec<bool> },
+}
+
+struct State {
+    mode: Mode,
+    interactions: Vec<Interaction>,
+}
+
+/// Кассета: middleware записи или воспроизведения ответов.
+///
+/// Клоны разделяют состояние кассеты.
+#[derive(Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    state: Arc<Mutex<State>>,
+}
+
+impl Cassette {
+    /// Режим записи: файл `path` перезаписывается после каждого обмена.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::with_mode(path.into(), Mode::Record, Vec::new())
+    }
+
+    /// Режим воспроизведения из файла `path`.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file: CassetteFile = serde_json::from_slice(&std::fs::read(&path)?)?;
+        let used = vec![false; file.interactions.len()];
+        Ok(Self::with_mode(path, Mode::Replay { used }, file.interactions))
+    }
+
+    /// Воспроизведение, если файл `path` существует, иначе запись.
+    pub fn replay_or_record(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path))
+        }
+    }
+
+    fn with_mode(path: PathBuf, mode: Mode, interactions: Vec<Interaction>) -> Self {
+        Self {
+            path,
+            state: Arc::new(Mutex::new(State { mode, interactions })),
+        }
+    }
+
+    /// Путь к файлу кассеты.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Обмены, записанные или загруженные кассетой.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Ищет ответ для запроса. Одинаковые запросы получают записанные ответы
+    /// по очереди, а после последнего — снова последний.
+    fn find(&self, request: &CassetteRequest) -> Option<CassetteResponse> {
+        let mut state = self.lock();
+        let State { mode, interactions } = &mut *state;
+        let Mode::Replay { used } = mode else {
+            return None;
+        };
+        let matching: Vec<usize> = interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request == *request)
+            .map(|(index, _)| index)
+            .collect();
+        let index = matching
+            .iter()
+            .copied()
+            .find(|&index| !used[index])
+            .or(matching.last().copied())?;
+        used[index] = true;
+        Some(interactions[index].response.clone())
+    }
+
+    fn save(&self, interaction: Interaction) -> Result<()> {
+        let mut state = self.lock();
+        state.interactions.push(interaction);
+        let file = CassetteFile {
+            interactions: state.interactions.clone(),
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(&file)?)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn is_recording(&self) -> bool {
+        matches!(self.lock().mode, Mode::Record)
+    }
+}
+
+fn cassette_request(request: &reqwest::Request) -> CassetteRequest {
+    let url = request.url();
+    let mut path = url.path().to_string();
+    if url.query().is_some() {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(url.query_pairs().map(|(key, value)| {
+                let value = if is_secret(&key) { "***".into() } else { value };
+                (key, value)
+            }))
+            .finish();
+        path = format!("{}?{}", path, query);
+    }
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(|bytes| match serde_json::from_slice::<Value>(bytes) {
+            Ok(json) => redact(&json),
+            Err(_) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+        });
+
+    CassetteRequest {
+        method: request.method().to_string(),
+        path,
+        body,
+    }
+}
+
+fn into_response(recorded: CassetteResponse) -> Result<reqwest::Response> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name, value);
+    }
+    let body = match recorded.body {
+        Value::String(text) => text,
+        json => json.to_string(),
+    };
+    let response = builder
+        .body(body)
+        .map_err(|e| ValidationError::new("cassette", Constraint::Format, format!("Некорректный ответ в кассете: {}", e)))?;
+    Ok(reqwest::Response::from(response))
+}
+
+impl Middleware for Cassette {
+    fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> BoxFuture<'a, Result<reqwest::Response>> {
+        let recorded = cassette_request(&request);
+
+        if !self.is_recording() {
+            let result = match self.find(&recorded) {
+                Some(response) => into_response(response),
+                None => Err(ShikicrateError::from(ValidationError::new(
+                    "cassette",
+                    Constraint::Other,
+                    format!(
+                        "В кассете {} нет ответа на {} {}",
+                        self.path.display(),
+                        recorded.method,
+                        recorded.path
+                    ),
+                ))),
+            };
+            return Box::pin(async move { result });
+        }
+
+        Box::pin(async move {
+            let response = next.run(request).await?;
+            let status = response.status().as_u16();
+            let headers: Vec<(String, String)> = response
+                .headers()
+                .iter()
+                .filter(|(name, _)| KEPT_HEADERS.contains(&name.as_str()))
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+            let bytes = response.bytes().await?;
+            let body = serde_json::from_slice(&bytes)
+                .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+
+            let response = CassetteResponse { status, headers, body };
+            self.save(Interaction {
+                request: recorded,
+                response: response.clone(),
+            })?;
+            into_response(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::AnimeSearchParams;
+    use crate::transport::{MockTransport, mock_builder};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path = std::env::temp_dir().join(format!("shikicrate-vcr-{}.json", std::process::id()));
+        let params = || AnimeSearchParams {
+            search: Some("bebop".to_string()),
+            ..Default::default()
+        };
+
+        let transport = MockTransport::new()
+            .graphql("SearchAnimes", json!({ "animes": [{ "id": "1", "name": "Cowboy Bebop" }] }));
+        let client = mock_builder(&transport)
+            .access_token("secret".to_string())
+            .middleware(Cassette::record(&path))
+            .build()
+            .unwrap();
+        client.animes(params()).await.unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("Cowboy Bebop") && !saved.contains("secret"));
+
+        // Воспроизведение не обращается к транспорту
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = mock_builder(&transport)
+            .middleware(cassette)
+            .build()
+            .unwrap();
+        let animes = client.animes(params()).await.unwrap();
+        assert_eq!(animes[0].name, "Cowboy Bebop");
+        assert_eq!(transport.requests().len(), 1);
+
+        let missing = client
+            .animes(AnimeSearchParams {
+                search: Some("trigun".to_string()),
+                ..Default::default()
+            })
+            .await;
+        assert!(matches!(missing, Err(ShikicrateError::Validation(e)) if e.message.contains("нет ответа")));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,142 @@
+//! Хелперы для работы с видео аниме (опенинги, эндинги, промо).
+//!
+//! Поле `Video::kind` в API заполняется непоследовательно (`"op"`, `"ed"`,
+//! `"op_ed_clip"`, `"pv"` и т.д.), а номер опенинга/эндинга есть только в названии
+//! (`"OP 2"`, `"Ending 1"`, `"ED1"`). Хелперы нормализуют оба поля.
+
+use crate::types::{Anime, Video};
+
+/// Нормализованный тип видео.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoKind {
+    /// Опенинг.
+    Opening,
+    /// Эндинг.
+    Ending,
+    /// Промо-ролик, трейлер или реклама.
+    Promo,
+    /// Прочие видео.
+    Other,
+}
+
+const OPENING_KEYWORDS: [&str; 2] = ["opening", "op"];
+const ENDING_KEYWORDS: [&str; 2] = ["ending", "ed"];
+
+/// Ищет ключевое слово как отдельное слово и возвращает позицию сразу после него.
+fn find_keyword(text: &str, keyword: &str) -> Option<usize> {
+    text.match_indices(keyword).find_map(|(start, _)| {
+        let end = start + keyword.len();
+        let before_ok = text[..start].chars().next_back().is_none_or(|c| !c.is_alphabetic());
+        let after_ok = text[end..].chars().next().is_none_or(|c| !c.is_alphabetic());
+        (before_ok && after_ok).then_some(end)
+    })
+}
+
+fn parse_number_after(text: &str, pos: usize) -> Option<u32> {
+    let rest = text[pos..].trim_start_matches([' ', '#', '.', ':', '-', '_']);
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+impl Video {
+    /// Определяет нормализованный тип видео по полю `kind` и названию.
+    pub fn video_kind(&self) -> VideoKind {
+        let kind = self.kind.as_deref().unwrap_or_default().to_lowercase();
+        match kind.as_str() {
+            "op" | "opening" => return VideoKind::Opening,
+            "ed" | "ending" => return VideoKind::Ending,
+            "pv" | "promo" | "cm" | "trailer" | "character_trailer" | "episode_preview" => {
+                return VideoKind::Promo;
+            }
+            _ => {}
+        }
+
+        let name = self.name.as_deref().unwrap_or_default().to_lowercase();
+        if OPENING_KEYWORDS.iter().any(|k| find_keyword(&name, k).is_some()) {
+            VideoKind::Opening
+        } else if ENDING_KEYWORDS.iter().any(|k| find_keyword(&name, k).is_some()) {
+            VideoKind::Ending
+        } else {
+            VideoKind::Other
+        }
+    }
+
+    /// Номер опенинга/эндинга из названия (`"OP 2"` → `2`).
+    ///
+    /// Возвращает `None`, если номер в названии не указан.
+    pub fn number(&self) -> Option<u32> {
+        let name = self.name.as_deref()?.to_lowercase();
+        let keywords: &[&str] = match self.video_kind() {
+            VideoKind::Opening => &OPENING_KEYWORDS,
+            VideoKind::Ending => &ENDING_KEYWORDS,
+            _ => &[],
+        };
+
+        keywords
+            .iter()
+            .filter_map(|k| find_keyword(&name, k))
+            .find_map(|pos| parse_number_after(&name, pos))
+    }
+}
+
+impl Anime {
+    /// Видео указанного типа, отсортированные по номеру (видео без номера — в конце).
+    pub fn videos_of_kind(&self, kind: VideoKind) -> Vec<&Video> {
+        let mut videos: Vec<&Video> = self
+            .videos
+            .iter()
+            .flatten()
+            .filter(|v| v.video_kind() == kind)
+            .collect();
+        videos.sort_by_key(|v| v.number().unwrap_or(u32::MAX));
+        videos
+    }
+
+    /// Опенинги, отсортированные по номеру.
+    pub fn openings(&self) -> Vec<&Video> {
+        self.videos_of_kind(VideoKind::Opening)
+    }
+
+    /// Эндинги, отсортированные по номеру.
+    pub fn endings(&self) -> Vec<&Video> {
+        self.videos_of_kind(VideoKind::Ending)
+    }
+
+    /// Промо-ролики и трейлеры.
+    pub fn promos(&self) -> Vec<&Video> {
+        self.videos_of_kind(VideoKind::Promo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(kind: Option<&str>, name: Option<&str>) -> Video {
+        Video {
+            id: 1,
+            url: None,
+            name: name.map(str::to_string),
+            kind: kind.map(str::to_string),
+            player_url: None,
+            image_url: None,
+        }
+    }
+
+    #[test]
+    fn test_video_kind() {
+        assert_eq!(video(Some("op"), None).video_kind(), VideoKind::Opening);
+        assert_eq!(video(Some("ed"), None).video_kind(), VideoKind::Ending);
+        assert_eq!(video(Some("pv"), Some("PV 1")).video_kind(), VideoKind::Promo);
+        assert_eq!(video(Some("op_ed_clip"), Some("Ending 2")).video_kind(), VideoKind::Ending);
+        assert_eq!(video(None, Some("Special Edition")).video_kind(), VideoKind::Other);
+    }
+
+    #[test]
+    fn test_video_number() {
+        assert_eq!(video(Some("op"), Some("OP 2")).number(), Some(2));
+        assert_eq!(video(Some("ed"), Some("ED1")).number(), Some(1));
+        assert_eq!(video(Some("op"), Some("Opening #3 \"Song\"")).number(), Some(3));
+        assert_eq!(video(Some("op"), Some("Opening")).number(), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Нефатальные предупреждения клиента.
+//!
+//! Некоторые ситуации не являются ошибками, но меняют результат запроса:
+//! лимит уменьшен до максимально допустимого, часть фильтров проигнорирована,
+//! в ответе не оказалось ожидаемого поля. Такие события передаются в обработчик,
+//! заданный через `ShikicrateClientBuilder::on_warning()`, чтобы молчаливые
+//! изменения поведения можно было логировать и отслеживать.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Максимальный `limit`, который принимает GraphQL API Shikimori.
+pub const MAX_LIMIT: i32 = 50;
+
+/// Обработчик предупреждений.
+pub type WarningHandler = Arc<dyn Fn(&Warning) + Send + Sync>;
+
+/// Нефатальное предупреждение, возникшее при выполнении запроса.
+///
+/// # Примеры
+///
+/// ```no_run
+/// use shikicrate::ShikicrateClientBuilder;
+///
+/// # fn example() -> shikicrate::Result<()> {
+/// let client = ShikicrateClientBuilder::new()
+///     .on_warning(|warning| eprintln!("shikicrate: {}", warning))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// Запрошенный `limit` превышает максимум API и был уменьшен.
+    LimitClamped {
+        /// Запрошенное значение.
+        requested: i32,
+        /// Фактически отправленное значение.
+        applied: i32,
+    },
+
+    /// Параметр запроса был проигнорирован.
+    FilterIgnored {
+        /// Имя параметра.
+        filter: &'static str,
+        /// Причина.
+        reason: &'static str,
+    },
+
+    /// В ответе отсутствует ожидаемое поле, вместо него использовано пустое значение.
+    MissingResponseField {
+        /// Имя поля.
+        field: String,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::LimitClamped { requested, applied } => {
+                write!(f, "limit {} clamped to {}", requested, applied)
+            }
+            Warning::FilterIgnored { filter, reason } => {
+                write!(f, "filter `{}` ignored: {}", filter, reason)
+            }
+            Warning::MissingResponseField { field } => {
+                write!(f, "field `{}` missing in response, treated as empty", field)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShikicrateClientBuilder;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_warning_handler_receives_warnings() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let client = ShikicrateClientBuilder::new()
+            .on_warning(move |w| sink.lock().unwrap().push(w.clone()))
+            .build()
+            .unwrap();
+
+        client.clone().warn(Warning::LimitClamped {
+            requested: 100,
+            applied: MAX_LIMIT,
+        });
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].to_string(), "limit 100 clamped to 50");
+    }
+}
@@ -4,12 +4,10 @@ use shikicrate::{Result, ShikicrateClient, queries::*};
 async fn test_search_animes() -> Result<()> {
     let client = ShikicrateClient::new()?;
 
-    let params = AnimeSearchParams {
-        search: Some("bakemono".to_string()),
-        limit: Some(1),
-        kind: Some("!special".to_string()),
-        ..Default::default()
-    };
+    let params = AnimeSearchParams::builder()
+        .search("bakemono")
+        .limit(1)
+        .exclude_kind(AnimeKind::Special);
 
     let animes = client.animes(params).await?;
 
@@ -30,10 +28,7 @@ async fn test_search_animes() -> Result<()> {
 async fn test_search_mangas() -> Result<()> {
     let client = ShikicrateClient::new()?;
 
-    let params = MangaSearchParams {
-        limit: Some(5),
-        ..Default::default()
-    };
+    let params = MangaSearchParams::builder().limit(5);
 
     let mangas = client.mangas(params).await?;
 
@@ -46,10 +41,7 @@ async fn test_search_mangas() -> Result<()> {
 async fn test_search_people() -> Result<()> {
     let client = ShikicrateClient::new()?;
 
-    let params = PeopleSearchParams {
-        limit: Some(1),
-        ..Default::default()
-    };
+    let params = PeopleSearchParams::builder().limit(1);
 
     let people = client.people(params).await?;
 
@@ -62,11 +54,7 @@ async fn test_search_people() -> Result<()> {
 async fn test_search_characters() -> Result<()> {
     let client = ShikicrateClient::new()?;
 
-    let params = CharacterSearchParams {
-        page: Some(1),
-        limit: Some(1),
-        ..Default::default()
-    };
+    let params = CharacterSearchParams::builder().page(1).limit(1);
 
     let characters = client.characters(params).await?;
 
@@ -79,10 +67,7 @@ async fn test_search_characters() -> Result<()> {
 async fn test_search_characters_by_ids() -> Result<()> {
     let client = ShikicrateClient::new()?;
 
-    let params = CharacterSearchParams {
-        ids: Some(vec!["1".to_string(), "2".to_string()]),
-        ..Default::default()
-    };
+    let params = CharacterSearchParams::builder().ids([1, 2]);
 
     let characters = client.characters(params).await?;
 